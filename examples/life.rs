@@ -0,0 +1,69 @@
+use std::collections::VecDeque;
+use std::path::PathBuf;
+
+use wave::plot::cells;
+use wave::{Color, Stage};
+
+const COLS: usize = 160;
+const ROWS: usize = 120;
+const SCALE: usize = 5;
+const FRAMES: usize = 120;
+const IN_FLIGHT: usize = 8;
+
+/// Advances Conway's Game of Life one generation on a torus.
+fn step(grid: &[bool], next: &mut [bool]) {
+    for y in 0..ROWS {
+        for x in 0..COLS {
+            let mut alive = 0;
+            for (dx, dy) in [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)] {
+                let nx = (x as isize + dx).rem_euclid(COLS as isize) as usize;
+                let ny = (y as isize + dy).rem_euclid(ROWS as isize) as usize;
+                alive += grid[ny * COLS + nx] as u8;
+            }
+            let on = grid[y * COLS + x];
+            next[y * COLS + x] = matches!((on, alive), (true, 2) | (_, 3));
+        }
+    }
+}
+
+fn main() {
+    let out_dir = std::env::args()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| std::env::temp_dir().join("wave_life"));
+    std::fs::create_dir_all(&out_dir).expect("Failed to create output directory");
+
+    // deterministic soup
+    let mut seed: u32 = 0x2545_F491;
+    let mut grid: Vec<bool> = (0..COLS * ROWS)
+        .map(|_| {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            seed.is_multiple_of(3)
+        })
+        .collect();
+    let mut next = vec![false; COLS * ROWS];
+
+    let mut stage = Stage::new(COLS * SCALE, ROWS * SCALE);
+    let rect = stage.bounds();
+    let (on, off) = (Color::new([240, 200, 80, 255]), Color::new([20, 24, 32, 255]));
+
+    // encode frames on background threads, keeping a few in flight
+    let mut pending = VecDeque::new();
+    for frame in 0..FRAMES {
+        cells(&mut stage, &grid, (COLS, ROWS), rect, on, off);
+        pending.push_back(stage.save_png_async(out_dir.join(format!("frame_{frame:04}.png"))));
+        if pending.len() > IN_FLIGHT && let Some(handle) = pending.pop_front() {
+            handle.join().expect("Encoder thread panicked").expect("Render Failed");
+        }
+
+        step(&grid, &mut next);
+        std::mem::swap(&mut grid, &mut next);
+    }
+
+    for handle in pending {
+        handle.join().expect("Encoder thread panicked").expect("Render Failed");
+    }
+    println!("wrote {FRAMES} frames to {}", out_dir.display());
+}
@@ -0,0 +1,27 @@
+//! Framebuffer views as `ndarray` arrays for post-processing with existing
+//! array tooling. Requires the `ndarray` feature.
+
+use ndarray::{ArrayView3, ArrayViewMut3, ShapeBuilder};
+
+use crate::Stage;
+
+impl Stage {
+    /// Returns the framebuffer as a `height` x `width` x 4 RGBA view.
+    ///
+    /// Row padding from [`Stage::with_stride`] is skipped through the view's
+    /// strides, so no pixels are copied.
+    pub fn as_array(&self) -> ArrayView3<'_, u8> {
+        let shape = (self.height(), self.width(), 4).strides((self.stride() * 4, 4, 1));
+        ArrayView3::from_shape(shape, self.raw_bytes())
+            .expect("framebuffer holds stride * height pixels")
+    }
+
+    /// Returns the framebuffer as a mutable `height` x `width` x 4 RGBA view.
+    ///
+    /// Writes through the view ignore clips and scissors.
+    pub fn as_array_mut(&mut self) -> ArrayViewMut3<'_, u8> {
+        let shape = (self.height(), self.width(), 4).strides((self.stride() * 4, 4, 1));
+        ArrayViewMut3::from_shape(shape, self.raw_pixels_mut().as_flattened_mut())
+            .expect("framebuffer holds stride * height pixels")
+    }
+}
@@ -0,0 +1,278 @@
+//! Finishing touches applied directly to a [`Stage`]: background gradients,
+//! mesh gradients, vignettes, and film grain.
+//!
+//! Gradients have `_dithered` variants that spread the 8-bit rounding error
+//! spatially, hiding the banding that large smooth gradients otherwise show.
+
+use crate::{Color, Colormap, Path, Stage};
+use crate::noise::hash;
+
+/// How smooth fills are quantized to 8-bit channels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Dither {
+    /// Round to the nearest value. Cheapest, but shows banding.
+    #[default]
+    None,
+    /// 8x8 Bayer matrix. Regular, cross-hatch like pattern.
+    Ordered,
+    /// R2 low-discrepancy sequence, a blue-noise approximation without
+    /// visible structure.
+    BlueNoise,
+}
+
+const BAYER_8: [[u8; 8]; 8] = [
+    [0, 32, 8, 40, 2, 34, 10, 42],
+    [48, 16, 56, 24, 50, 18, 58, 26],
+    [12, 44, 4, 36, 14, 46, 6, 38],
+    [60, 28, 52, 20, 62, 30, 54, 22],
+    [3, 35, 11, 43, 1, 33, 9, 41],
+    [51, 19, 59, 27, 49, 17, 57, 25],
+    [15, 47, 7, 39, 13, 45, 5, 37],
+    [63, 31, 55, 23, 61, 29, 53, 21],
+];
+
+impl Dither {
+    /// Threshold offset in `[-0.5, 0.5)` added before rounding pixel `(x, y)`.
+    fn offset(self, x: usize, y: usize) -> f32 {
+        match self {
+            Dither::None => 0.0,
+            Dither::Ordered => (BAYER_8[y % 8][x % 8] as f32 + 0.5) / 64.0 - 0.5,
+            Dither::BlueNoise => {
+                let v = 0.754_877_7 * x as f32 + 0.569_840_3 * y as f32;
+                v.fract() - 0.5
+            }
+        }
+    }
+
+    /// Samples `colormap` at `t` for pixel `(x, y)`, quantized with `self`.
+    fn sample(self, colormap: &Colormap, t: f32, x: usize, y: usize) -> Color {
+        let Some(c) = colormap.sample_unquantized(t) else { return Color::TRANSPARENT };
+        self.quantize(c, x, y)
+    }
+
+    /// Rounds channels in `[0.0, 255.0]` for pixel `(x, y)`.
+    fn quantize(self, c: [f32; 4], x: usize, y: usize) -> Color {
+        let offset = self.offset(x, y);
+        Color::new(c.map(|v| (v + offset).round().clamp(0.0, 255.0) as u8))
+    }
+}
+
+/// Fills the whole `stage` with a multi-stop linear gradient.
+///
+/// Arguments:
+/// - stage: &mut [`Stage`] - stage to draw onto.
+/// - angle: [f32] - gradient direction in degrees, counter-clockwise from `+x`.
+/// - colormap: &[`Colormap`] - stops from `0.0` at the start edge to `1.0` at the end edge.
+pub fn linear_gradient(stage: &mut Stage, angle: f32, colormap: &Colormap) {
+    linear_gradient_dithered(stage, angle, colormap, Dither::None);
+}
+
+/// Like [`linear_gradient`] but quantizes channels with `dither`.
+pub fn linear_gradient_dithered(stage: &mut Stage, angle: f32, colormap: &Colormap, dither: Dither) {
+    if !angle.is_finite() { return; }
+
+    let (dy, dx) = angle.to_radians().sin_cos();
+    let ((xmin, ymin), (xmax, ymax)) = stage.world_bounds();
+
+    // project the stage corners to find the gradient extent
+    let corners = [(xmin, ymin), (xmax, ymin), (xmax, ymax), (xmin, ymax)];
+    let proj = |(x, y): (f32, f32)| x * dx + y * dy;
+    let lo = corners.iter().map(|&c| proj(c)).fold(f32::INFINITY, f32::min);
+    let hi = corners.iter().map(|&c| proj(c)).fold(f32::NEG_INFINITY, f32::max);
+    let span = (hi - lo).max(f32::EPSILON);
+
+    let (w, h) = stage.dimensions();
+    for py in 0..h as isize {
+        let (wx0, wy) = stage.pxl_to_world((0, py));
+        stage.fill_span_with(py, 0, w as isize - 1, |px| {
+            let t = (proj((wx0 + px as f32, wy)) - lo) / span;
+            dither.sample(colormap, t, px as usize, py as usize)
+        });
+    }
+}
+
+/// Fills the whole `stage` with a multi-stop radial gradient.
+///
+/// Arguments:
+/// - stage: &mut [`Stage`] - stage to draw onto.
+/// - center: ([f32], [f32]) - world coord of the gradient center.
+/// - radius: [f32] - distance at which `colormap` reaches `1.0`.
+/// - colormap: &[`Colormap`] - stops from the center outwards.
+pub fn radial_gradient(stage: &mut Stage, center: (f32, f32), radius: f32, colormap: &Colormap) {
+    radial_gradient_dithered(stage, center, radius, colormap, Dither::None);
+}
+
+/// Like [`radial_gradient`] but quantizes channels with `dither`.
+pub fn radial_gradient_dithered(
+    stage: &mut Stage,
+    center: (f32, f32),
+    radius: f32,
+    colormap: &Colormap,
+    dither: Dither,
+) {
+    if !radius.is_finite() || radius <= 0.0 { return; }
+
+    let (w, h) = stage.dimensions();
+    for py in 0..h as isize {
+        let (wx0, y) = stage.pxl_to_world((0, py));
+        stage.fill_span_with(py, 0, w as isize - 1, |px| {
+            let x = wx0 + px as f32;
+            let d = ((x - center.0).powi(2) + (y - center.1).powi(2)).sqrt();
+            dither.sample(colormap, d / radius, px as usize, py as usize)
+        });
+    }
+}
+
+/// Fills the quadrilateral `quad` with colors bilinearly interpolated from
+/// its corners, the raster analog of a single mesh gradient patch.
+///
+/// Arguments:
+/// - stage: &mut [`Stage`] - stage to draw onto.
+/// - quad: [([f32], [f32]); 4] - corners in world coords, in order around the patch.
+/// - colors: [[`Color`]; 4] - color at each corner of `quad`.
+/// - dither: [`Dither`] - channel quantization.
+pub fn bilinear_patch(stage: &mut Stage, quad: [(f32, f32); 4], colors: [Color; 4], dither: Dither) {
+    let [a, b, c, d] = quad;
+    let channels = colors.map(|c| c.rgba().map(f32::from));
+    let (wx0, wy0) = stage.pxl_to_world((0, 0));
+
+    Path::new(quad.to_vec(), true).fill_with(stage, |p| {
+        let (u, v) = inverse_bilinear(p, a, b, c, d);
+        // shaders get world coords; recover the pixel for the dither pattern
+        let (px, py) = ((p.0 - wx0).round() as usize, (wy0 - p.1).round() as usize);
+        dither.quantize(bilerp(&channels, u, v), px, py)
+    });
+}
+
+/// Fills the whole `stage` with an `N x M` mesh gradient: a grid of colors
+/// stretched over the stage and bilinearly interpolated within each cell.
+///
+/// Arguments:
+/// - stage: &mut [`Stage`] - stage to draw onto.
+/// - dims: ([usize], [usize]) - grid `(cols, rows)`, each at least 2.
+/// - colors: &[[`Color`]] - row major grid colors, first row at the top.
+/// - dither: [`Dither`] - channel quantization.
+///
+/// Does nothing if `colors.len() != cols * rows`.
+pub fn mesh_gradient(stage: &mut Stage, dims: (usize, usize), colors: &[Color], dither: Dither) {
+    let (cols, rows) = dims;
+    if cols < 2 || rows < 2 || colors.len() != cols * rows { return; }
+
+    let channels: Vec<[f32; 4]> = colors.iter().map(|c| c.rgba().map(f32::from)).collect();
+    let (w, h) = stage.dimensions();
+    let sx = (cols - 1) as f32 / (w as f32 - 1.0).max(1.0);
+    let sy = (rows - 1) as f32 / (h as f32 - 1.0).max(1.0);
+
+    for py in 0..h {
+        let gy = py as f32 * sy;
+        let cy = (gy.floor() as usize).min(rows - 2);
+        let v = gy - cy as f32;
+
+        stage.fill_span_with(py as isize, 0, w as isize - 1, |px| {
+            let gx = px as f32 * sx;
+            let cx = (gx.floor() as usize).min(cols - 2);
+            let u = gx - cx as f32;
+
+            let i = cy * cols + cx;
+            let cell = [channels[i], channels[i + 1], channels[i + cols + 1], channels[i + cols]];
+            dither.quantize(bilerp(&cell, u, v), px as usize, py)
+        });
+    }
+}
+
+/// Interpolates corner values ordered around a cell, `c[0]` at `(0, 0)`,
+/// `c[1]` at `(1, 0)`, `c[2]` at `(1, 1)` and `c[3]` at `(0, 1)`.
+#[inline(always)]
+fn bilerp(c: &[[f32; 4]; 4], u: f32, v: f32) -> [f32; 4] {
+    std::array::from_fn(|k| {
+        let top = c[0][k] + (c[1][k] - c[0][k]) * u;
+        let bot = c[3][k] + (c[2][k] - c[3][k]) * u;
+        top + (bot - top) * v
+    })
+}
+
+/// Finds `(u, v)` in `[0, 1]^2` with `p = bilerp(a, b, c, d)(u, v)`, where the
+/// corners are ordered as in [`bilerp`].
+fn inverse_bilinear(p: (f32, f32), a: (f32, f32), b: (f32, f32), c: (f32, f32), d: (f32, f32)) -> (f32, f32) {
+    let cross = |u: (f32, f32), v: (f32, f32)| u.0 * v.1 - u.1 * v.0;
+    let sub = |u: (f32, f32), v: (f32, f32)| (u.0 - v.0, u.1 - v.1);
+
+    let e = sub(b, a);
+    let f = sub(d, a);
+    let g = (a.0 - b.0 + c.0 - d.0, a.1 - b.1 + c.1 - d.1);
+    let h = sub(p, a);
+
+    let k2 = cross(g, f);
+    let k1 = cross(e, f) + cross(h, g);
+    let k0 = cross(h, e);
+
+    let v = if k2.abs() < 1e-6 {
+        if k1.abs() < 1e-12 { 0.0 } else { -k0 / k1 }
+    } else {
+        let disc = (k1 * k1 - 4.0 * k0 * k2).max(0.0).sqrt();
+        let v1 = (-k1 - disc) / (2.0 * k2);
+        let v2 = (-k1 + disc) / (2.0 * k2);
+        if (-0.01..=1.01).contains(&v1) { v1 } else { v2 }
+    };
+
+    let den = (e.0 + g.0 * v, e.1 + g.1 * v);
+    let num = (h.0 - f.0 * v, h.1 - f.1 * v);
+    let u = if den.0.abs() > den.1.abs() { num.0 / den.0 } else if den.1.abs() > 1e-12 { num.1 / den.1 } else { 0.0 };
+
+    (u.clamp(0.0, 1.0), v.clamp(0.0, 1.0))
+}
+
+/// Darkens (or tints) the edges of `stage` towards `color`.
+///
+/// Arguments:
+/// - stage: &mut [`Stage`] - stage to draw onto.
+/// - color: [`Color`] - vignette color, usually [`Color::BLACK`].
+/// - strength: [f32] - opacity of `color` in the corners, in `[0.0, 1.0]`.
+/// - falloff: [f32] - fraction of the half-diagonal, in `[0.0, 1.0]`, left untouched around the center.
+pub fn vignette(stage: &mut Stage, color: Color, strength: f32, falloff: f32) {
+    if !strength.is_finite() || !falloff.is_finite() { return; }
+    let strength = strength.clamp(0.0, 1.0);
+    let inner = falloff.clamp(0.0, 1.0);
+
+    let (w, h) = stage.dimensions();
+    let (cx, cy) = ((w as f32 - 1.0) * 0.5, (h as f32 - 1.0) * 0.5);
+    let half_diag = (cx * cx + cy * cy).sqrt().max(1.0);
+
+    for py in 0..h {
+        for px in 0..w {
+            let d = ((px as f32 - cx).powi(2) + (py as f32 - cy).powi(2)).sqrt() / half_diag;
+            let t = smoothstep(inner, 1.0, d);
+            if t > 0.0 {
+                stage.blend_pxl(px as isize, py as isize, color, t * strength);
+            }
+        }
+    }
+}
+
+/// Adds monochrome film grain to every pixel of `stage`.
+///
+/// Arguments:
+/// - stage: &mut [`Stage`] - stage to modify.
+/// - amount: [f32] - largest channel offset in `[0.0, 1.0]` of full range.
+/// - seed: [u32] - identical seeds give identical grain.
+pub fn grain(stage: &mut Stage, amount: f32, seed: u32) {
+    if !amount.is_finite() || amount <= 0.0 { return; }
+    let amp = amount.min(1.0) * 255.0;
+
+    for y in 0..stage.height() {
+        for (x, px) in stage.row_mut(y).iter_mut().enumerate() {
+            let r = hash(x as i32, y as i32, seed) as f32 / u32::MAX as f32 * 2.0 - 1.0;
+            let offset = r * amp;
+            for c in &mut px[..3] {
+                *c = (*c as f32 + offset).round().clamp(0.0, 255.0) as u8;
+            }
+        }
+    }
+}
+
+#[inline(always)]
+fn smoothstep(e0: f32, e1: f32, x: f32) -> f32 {
+    if e1 <= e0 { return if x >= e1 { 1.0 } else { 0.0 }; }
+    let t = ((x - e0) / (e1 - e0)).clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
@@ -0,0 +1,176 @@
+//! Headless benchmark workloads for measuring rasterizer performance.
+//!
+//! Each [`Workload`] builds its geometry deterministically from a seed before
+//! timing starts, so runs are comparable across machines and commits.
+//!
+//! ```ignore
+//! for report in wave::bench::run_standard(20) {
+//!     println!("{report}");
+//! }
+//! ```
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::{Color, Path, Stage, Style};
+use crate::noise::hash;
+
+/// A standardized drawing workload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Workload {
+    /// `count` filled triangles with random vertices across the stage.
+    Triangles { count: usize },
+    /// One open polyline of `nodes` random vertices with a `width` pixel stroke.
+    Polyline { nodes: usize, width: u32 },
+    /// `count` filled circles with random centers and radii.
+    Circles { count: usize },
+    /// `count` filled rectangles covering most of the stage.
+    LargeFills { count: usize },
+    /// `count` full stage clears, measuring raw framebuffer write throughput.
+    Clears { count: usize },
+}
+
+impl Workload {
+    /// Returns a short name for reports.
+    pub fn name(&self) -> String {
+        match *self {
+            Workload::Triangles { count } => format!("triangles x{count}"),
+            Workload::Polyline { nodes, width } => format!("polyline {nodes} nodes {width}px"),
+            Workload::Circles { count } => format!("circles x{count}"),
+            Workload::LargeFills { count } => format!("large fills x{count}"),
+            Workload::Clears { count } => format!("clears x{count}"),
+        }
+    }
+
+    /// Builds the `(path, style)` draw calls of `self` for a `width` x `height` stage.
+    fn build(&self, width: usize, height: usize, seed: u32) -> Vec<(Path, Style)> {
+        let (hw, hh) = (width as f32 * 0.5, height as f32 * 0.5);
+        let mut rng = Sequence { n: 0, seed };
+        let point = |rng: &mut Sequence| ((rng.next() * 2.0 - 1.0) * hw, (rng.next() * 2.0 - 1.0) * hh);
+        let fill = Style::fill_only(Color::new([40, 120, 220, 255]));
+
+        match *self {
+            Workload::Triangles { count } => (0..count)
+                .map(|_| (Path::new(Vec::from([point(&mut rng), point(&mut rng), point(&mut rng)]), true), fill))
+                .collect(),
+            Workload::Polyline { nodes, width } => {
+                let mut style = Style::stroke_only(Color::BLACK);
+                style.set_stroke_width(width.max(1) as f32);
+                Vec::from([(Path::new((0..nodes).map(|_| point(&mut rng)).collect(), false), style)])
+            }
+            Workload::Circles { count } => (0..count)
+                .map(|_| {
+                    let c = point(&mut rng);
+                    (Path::circle(c, 2.0 + rng.next() * hw.min(hh) * 0.25), fill)
+                })
+                .collect(),
+            Workload::LargeFills { count } => (0..count)
+                .map(|_| {
+                    let (x, y) = (hw * 0.95, hh * 0.95);
+                    (Path::new(Vec::from([(-x, y), (x, y), (x, -y), (-x, -y)]), true), fill)
+                })
+                .collect(),
+            // no geometry, `run` clears the stage `count` times instead
+            Workload::Clears { .. } => Vec::new(),
+        }
+    }
+}
+
+/// Deterministic hash sequence in `[0, 1]`.
+struct Sequence {
+    n: i32,
+    seed: u32,
+}
+
+impl Sequence {
+    fn next(&mut self) -> f32 {
+        self.n += 1;
+        hash(self.n, 0, self.seed) as f32 / u32::MAX as f32
+    }
+}
+
+/// Timing of one [`Workload`] over several iterations.
+///
+/// Fields:
+/// - name: [String] - workload name.
+/// - dimensions: ([usize], [usize]) - stage size.
+/// - iterations: [usize] - timed iterations.
+/// - min, mean, max: `Duration` - per iteration wall time.
+#[derive(Debug, Clone)]
+pub struct BenchReport {
+    pub name: String,
+    pub dimensions: (usize, usize),
+    pub iterations: usize,
+    pub min: Duration,
+    pub mean: Duration,
+    pub max: Duration,
+}
+
+impl fmt::Display for BenchReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (w, h) = self.dimensions;
+        write!(
+            f,
+            "{:<28} {w}x{h} n={:<4} min {:>10.3?}  mean {:>10.3?}  max {:>10.3?}",
+            self.name, self.iterations, self.min, self.mean, self.max,
+        )
+    }
+}
+
+/// Times `workload` drawn onto a cleared `width` x `height` stage for
+/// `iterations` runs after one untimed warm-up run.
+///
+/// Arguments:
+/// - workload: [`Workload`] - what to draw.
+/// - dimensions: ([usize], [usize]) - stage size.
+/// - iterations: [usize] - timed runs, at least one.
+pub fn run(workload: Workload, dimensions: (usize, usize), iterations: usize) -> BenchReport {
+    let (w, h) = dimensions;
+    let calls = workload.build(w, h, 0x5EED);
+    let mut stage = Stage::new(w, h);
+
+    let clears = if let Workload::Clears { count } = workload { count } else { 0 };
+    let draw = |stage: &mut Stage| {
+        stage.clear(Color::WHITE);
+        for _ in 0..clears {
+            stage.clear(Color::BLACK);
+        }
+        for (path, style) in &calls {
+            path.render(stage, *style);
+        }
+    };
+    draw(&mut stage);
+
+    let iterations = iterations.max(1);
+    let mut times = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        draw(&mut stage);
+        times.push(start.elapsed());
+    }
+
+    let total: Duration = times.iter().sum();
+    BenchReport {
+        name: workload.name(),
+        dimensions,
+        iterations,
+        min: times.iter().copied().min().unwrap_or_default(),
+        mean: total / iterations as u32,
+        max: times.iter().copied().max().unwrap_or_default(),
+    }
+}
+
+/// The standard workload suite on a 1024x768 stage.
+pub const STANDARD: [Workload; 6] = [
+    Workload::Triangles { count: 10_000 },
+    Workload::Polyline { nodes: 10_000, width: 1 },
+    Workload::Polyline { nodes: 2_000, width: 6 },
+    Workload::Circles { count: 5_000 },
+    Workload::LargeFills { count: 50 },
+    Workload::Clears { count: 50 },
+];
+
+/// Runs every [`STANDARD`] workload on a 1024x768 stage for `iterations` runs each.
+pub fn run_standard(iterations: usize) -> Vec<BenchReport> {
+    STANDARD.iter().map(|&w| run(w, (1024, 768), iterations)).collect()
+}
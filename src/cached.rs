@@ -0,0 +1,198 @@
+//! Paths whose rasterized spans are reused across frames.
+
+use crate::{Color, LineCap, Opacity, Path, Stage, Stroke, Style, Transform};
+use crate::path::antialiased;
+use crate::style::Dash;
+
+/// Everything the rasterized spans of a [`CachedPath`] depend on.
+#[derive(Clone, Copy, PartialEq)]
+struct CacheKey {
+    dimensions: (usize, usize),
+    origin: (f32, f32),
+    transform: Transform,
+    fill: bool,
+    stroke_width: Option<f32>,
+    cap: Option<LineCap>,
+    hairline: Option<bool>,
+    dash: Option<Dash>,
+}
+
+/// A [`Path`] that keeps its rasterized scanline spans and only rasterizes
+/// again when the stage size, world origin, transform, or stroke geometry
+/// change.
+///
+/// Colors and opacities are applied when the spans are replayed, so a cached
+/// path can change color every frame for free. Scissors and clips active at
+/// render time still apply. Static geometry in animations can be drawn each
+/// frame at the cost of a span fill.
+///
+/// Spans only hold crisp coverage, so anti-aliased draws, i.e. onto a stage
+/// with [`Stage::path_aa`] above one or with an anti-aliased 1px stroke, skip
+/// the cache and render the path directly.
+///
+/// Fields:
+/// - path: [`Path`] - source geometry in world coords.
+/// - transform: [`Transform`] - applied to `path` nodes before rasterizing.
+/// - key: `Option<CacheKey>` - inputs of the cached spans, if any.
+/// - fill_spans, stroke_spans: Vec<([isize], [isize], [isize])> - inclusive `(y, x0, x1)` runs.
+pub struct CachedPath {
+    path: Path,
+    transform: Transform,
+    key: Option<CacheKey>,
+    fill_spans: Vec<(isize, isize, isize)>,
+    stroke_spans: Vec<(isize, isize, isize)>,
+}
+
+impl CachedPath {
+    /// Wraps `path` with an empty cache and the identity transform.
+    pub fn new(path: Path) -> Self {
+        Self {
+            path,
+            transform: Transform::IDENTITY,
+            key: None,
+            fill_spans: Vec::new(),
+            stroke_spans: Vec::new(),
+        }
+    }
+
+    /// Returns the source [`Path`].
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the current [`Transform`].
+    pub fn transform(&self) -> Transform {
+        self.transform
+    }
+
+    /// Sets the transform applied to the path nodes. The cache is only
+    /// invalidated if `transform` differs from the current one.
+    pub fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+
+    /// Replaces the source path and drops the cache.
+    pub fn set_path(&mut self, path: Path) {
+        self.path = path;
+        self.invalidate();
+    }
+
+    /// Drops the cached spans so the next render rasterizes again.
+    pub fn invalidate(&mut self) {
+        self.key = None;
+    }
+
+    /// Returns `true` if rendering onto `stage` with `style` would reuse the
+    /// cached spans.
+    pub fn is_cached(&self, stage: &Stage, style: Style) -> bool {
+        self.key == Some(self.key_for(stage, style))
+    }
+
+    /// Renders the path onto `stage` with `style`, rasterizing only if the
+    /// cached spans are missing or stale. Output matches [`Path::render`].
+    pub fn render(&mut self, stage: &mut Stage, style: Style) {
+        if !style.fill_or_stroke_exists() { return; }
+
+        // partial coverage cannot be replayed from spans
+        let smooth_stroke = style.resolve_hairline().stroke.is_some_and(|s| antialiased(s, stage));
+        if stage.path_aa() > 1 || smooth_stroke {
+            return self.with_transformed(|path| path.render(stage, style));
+        }
+
+        let key = self.key_for(stage, style);
+        if self.key != Some(key) {
+            self.rasterize(stage, style);
+            self.key = Some(key);
+        }
+
+        stage.with_blend(style.blend, |stage| {
+            if let Some(fill) = style.fill {
+                for &(y, x0, x1) in &self.fill_spans {
+                    stage.fill_span_paint(y, x0, x1, fill);
+                }
+            }
+            if let Some(stroke) = style.stroke {
+                replay(stage, &self.stroke_spans, stroke.rgba());
+            }
+        });
+    }
+
+    fn key_for(&self, stage: &Stage, style: Style) -> CacheKey {
+        let style = style.resolve_hairline();
+        CacheKey {
+            dimensions: stage.dimensions(),
+            origin: stage.origin_pxl(),
+            transform: self.transform,
+            fill: style.fill.is_some(),
+            stroke_width: style.stroke.map(|s| s.width),
+            cap: style.stroke.map(|s| s.cap),
+            hairline: style.stroke.map(|s| s.hairline),
+            dash: style.stroke.map(|s| s.dash),
+        }
+    }
+
+    /// Rasterizes fill and stroke separately on scratch stages matching
+    /// `stage` and records their runs.
+    fn rasterize(&mut self, stage: &Stage, style: Style) {
+        self.fill_spans.clear();
+        self.stroke_spans.clear();
+
+        let (w, h) = stage.dimensions();
+        let mut scratch = Stage::new(w, h);
+        scratch.set_origin_pxl(stage.origin_pxl());
+
+        let (mut fill_spans, mut stroke_spans) = (Vec::new(), Vec::new());
+        self.with_transformed(|path| {
+            if style.fill.is_some() {
+                path.render(&mut scratch, Style::fill_only(Color::WHITE));
+                collect_runs(&scratch, &mut fill_spans);
+            }
+            if let Some(stroke) = style.stroke {
+                scratch.clear(Color::TRANSPARENT);
+                let stroke = Stroke { color: Color::WHITE, opacity: Opacity::OPAQUE, antialias: false, ..stroke };
+                path.render(&mut scratch, Style { fill: None, stroke: Some(stroke), blend: None });
+                collect_runs(&scratch, &mut stroke_spans);
+            }
+        });
+        self.fill_spans = fill_spans;
+        self.stroke_spans = stroke_spans;
+    }
+
+    /// Calls `f` with the source path under the current transform. Does
+    /// nothing if a transformed node is not finite.
+    fn with_transformed<F: FnOnce(&Path)>(&self, f: F) {
+        if self.transform == Transform::IDENTITY {
+            return f(&self.path);
+        }
+        if let Some(path) = self.path.map_nodes(|p| self.transform.apply(p)) {
+            f(&path);
+        }
+    }
+}
+
+/// Appends the inclusive runs of non-transparent pixels of `scratch` to `out`.
+fn collect_runs(scratch: &Stage, out: &mut Vec<(isize, isize, isize)>) {
+    for y in 0..scratch.height() {
+        let mut start = None;
+        let row = scratch.row(y);
+        for (x, px) in row.iter().enumerate() {
+            match (px[3] != 0, start) {
+                (true, None) => start = Some(x),
+                (false, Some(s)) => {
+                    out.push((y as isize, s as isize, x as isize - 1));
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+        if let Some(s) = start {
+            out.push((y as isize, s as isize, row.len() as isize - 1));
+        }
+    }
+}
+
+fn replay(stage: &mut Stage, spans: &[(isize, isize, isize)], color: Color) {
+    for &(y, x0, x1) in spans {
+        stage.fill_span_pxl(y, x0, x1, color);
+    }
+}
@@ -0,0 +1,103 @@
+//! Pixel clip masks restricting which [`Stage`] pixels may be written.
+
+use crate::{Color, Path, Stage};
+
+/// Clip region of a [`Stage`] stored as sorted, disjoint runs of writable
+/// pixels per row, so spans can be cut against it without per-pixel tests.
+#[derive(Clone)]
+pub(crate) struct ClipMask {
+    /// Half-open `[start, end)` pixel columns, per row.
+    rows: Vec<Vec<(usize, usize)>>,
+}
+
+impl ClipMask {
+    /// Rasterizes the interior of each subpath of `path` (treated as closed) into a mask for a
+    /// stage of `width` x `height` whose world origin sits at pixel `origin`.
+    pub(crate) fn from_path(path: &Path, width: usize, height: usize, origin: (f32, f32)) -> Self {
+        let mut scratch = Stage::new(width, height);
+        scratch.set_origin_pxl(origin);
+        path.interior_spans(&mut scratch, true, |scratch, y, x0, x1| {
+            scratch.fill_span_pxl(y, x0, x1, Color::WHITE);
+        });
+
+        let rows = (0..height)
+            .map(|y| runs(scratch.row(y).iter().map(|px| px[3] != 0)))
+            .collect();
+
+        Self { rows }
+    }
+
+    /// Builds a mask from row major `coverage` of `width` x `height`, keeping
+    /// pixels whose coverage is at least `threshold`.
+    pub(crate) fn from_coverage(coverage: &[u8], width: usize, height: usize, threshold: u8) -> Self {
+        debug_assert_eq!(coverage.len(), width * height);
+
+        let rows = coverage
+            .chunks_exact(width.max(1))
+            .take(height)
+            .map(|row| runs(row.iter().map(|&c| c != 0 && c >= threshold)))
+            .collect();
+
+        Self { rows }
+    }
+
+    /// Keeps only pixels writable in both `self` and `other`.
+    pub(crate) fn intersect(&mut self, other: &ClipMask) {
+        debug_assert_eq!(self.rows.len(), other.rows.len());
+
+        for (a, b) in self.rows.iter_mut().zip(&other.rows) {
+            let mut out = Vec::with_capacity(a.len().min(b.len()));
+            let (mut i, mut j) = (0, 0);
+            while i < a.len() && j < b.len() {
+                let start = a[i].0.max(b[j].0);
+                let end = a[i].1.min(b[j].1);
+                if start < end {
+                    out.push((start, end));
+                }
+                if a[i].1 < b[j].1 { i += 1; } else { j += 1; }
+            }
+            *a = out;
+        }
+    }
+
+    /// Returns `true` if pixel `(x, y)` is writable.
+    #[inline(always)]
+    pub(crate) fn contains(&self, x: usize, y: usize) -> bool {
+        let runs = &self.rows[y];
+        let i = runs.partition_point(|&(start, _)| start <= x);
+        i > 0 && x < runs[i - 1].1
+    }
+
+    /// Returns the writable inclusive sub-spans of `x0..=x1` on row `y`.
+    pub(crate) fn spans(&self, y: usize, x0: usize, x1: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let runs = &self.rows[y];
+        let first = runs.partition_point(|&(_, end)| end <= x0);
+
+        runs[first..]
+            .iter()
+            .take_while(move |&&(start, _)| start <= x1)
+            .map(move |&(start, end)| (start.max(x0), (end - 1).min(x1)))
+    }
+}
+
+/// Collects the half-open runs of `true` in `writable`.
+fn runs<I: Iterator<Item = bool>>(writable: I) -> Vec<(usize, usize)> {
+    let mut out = Vec::new();
+    let mut start = None;
+    let mut len = 0;
+    for (x, on) in writable.enumerate() {
+        match (on, start) {
+            (true, None) => start = Some(x),
+            (false, Some(s)) => {
+                out.push((s, x));
+                start = None;
+            }
+            _ => {}
+        }
+        len = x + 1;
+    }
+    if let Some(s) = start {
+        out.push((s, len));
+    }
+    out
+}
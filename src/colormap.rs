@@ -0,0 +1,110 @@
+//! Colormaps mapping scalars in `[0.0, 1.0]` to [`Color`]s.
+
+use crate::Color;
+
+/// Piecewise linear colormap defined by sorted `(position, color)` stops.
+#[derive(Debug, Clone)]
+pub struct Colormap {
+    stops: Vec<(f32, Color)>,
+}
+
+impl Colormap {
+    /// Creates a [`Colormap`] from `(position, color)` stops. Positions are
+    /// clamped to `[0.0, 1.0]` and sorted. Non-finite positions are dropped.
+    ///
+    /// Arguments:
+    /// - stops: Vec<([f32], [`Color`])> - color stops.
+    pub fn new(stops: Vec<(f32, Color)>) -> Self {
+        let mut stops: Vec<(f32, Color)> = stops
+            .into_iter()
+            .filter(|(t, _)| t.is_finite())
+            .map(|(t, c)| (t.clamp(0.0, 1.0), c))
+            .collect();
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self { stops }
+    }
+
+    /// Creates a [`Colormap`] spreading `colors` evenly over `[0.0, 1.0]`.
+    pub fn from_colors(colors: &[Color]) -> Self {
+        let n = colors.len();
+        let stops = colors
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| (if n > 1 { i as f32 / (n - 1) as f32 } else { 0.0 }, c))
+            .collect();
+        Self::new(stops)
+    }
+
+    /// Black to white.
+    pub fn grayscale() -> Self {
+        Self::from_colors(&[Color::BLACK, Color::WHITE])
+    }
+
+    /// Perceptually uniform dark-purple to yellow map.
+    pub fn viridis() -> Self {
+        Self::from_colors(&[
+            Color::new([68, 1, 84, 255]),
+            Color::new([59, 82, 139, 255]),
+            Color::new([33, 145, 140, 255]),
+            Color::new([94, 201, 98, 255]),
+            Color::new([253, 231, 37, 255]),
+        ])
+    }
+
+    /// Perceptually uniform black to pale-yellow map through purple and orange.
+    pub fn magma() -> Self {
+        Self::from_colors(&[
+            Color::new([0, 0, 4, 255]),
+            Color::new([81, 18, 124, 255]),
+            Color::new([183, 55, 121, 255]),
+            Color::new([252, 137, 97, 255]),
+            Color::new([252, 253, 191, 255]),
+        ])
+    }
+
+    /// Diverging blue to white to red map.
+    pub fn coolwarm() -> Self {
+        Self::from_colors(&[
+            Color::new([59, 76, 192, 255]),
+            Color::new([221, 221, 221, 255]),
+            Color::new([180, 4, 38, 255]),
+        ])
+    }
+
+    /// Returns the stops of `self`.
+    pub fn stops(&self) -> &[(f32, Color)] {
+        &self.stops
+    }
+
+    /// Samples `self` at `t`, clamped to `[0.0, 1.0]`. Non-finite `t` maps to
+    /// [`Color::TRANSPARENT`].
+    pub fn sample(&self, t: f32) -> Color {
+        match self.sample_unquantized(t) {
+            Some(c) => Color::new(c.map(|v| v.round().clamp(0.0, 255.0) as u8)),
+            None => Color::TRANSPARENT,
+        }
+    }
+
+    /// Samples `self` at `t` without rounding, with channels in `[0.0, 255.0]`.
+    /// Returns `None` for non-finite `t` or an empty colormap.
+    pub(crate) fn sample_unquantized(&self, t: f32) -> Option<[f32; 4]> {
+        if !t.is_finite() || self.stops.is_empty() { return None; }
+        let t = t.clamp(0.0, 1.0);
+        let channels = |c: Color| c.rgba().map(f32::from);
+
+        let first = self.stops[0];
+        if t <= first.0 { return Some(channels(first.1)); }
+
+        for w in self.stops.windows(2) {
+            let (t0, c0) = w[0];
+            let (t1, c1) = w[1];
+            if t <= t1 {
+                let f = if t1 > t0 { (t - t0) / (t1 - t0) } else { 1.0 };
+                let (a, b) = (channels(c0), channels(c1));
+                return Some(std::array::from_fn(|c| a[c] + (b[c] - a[c]) * f));
+            }
+        }
+
+        Some(channels(self.stops[self.stops.len() - 1].1))
+    }
+}
@@ -0,0 +1,144 @@
+use crate::geometry::CubicBezier;
+use crate::shapes::arrows::render_arrow;
+use crate::shapes::ArrowStyle;
+use crate::{Path, Stage, Style};
+
+type Point = (f32, f32);
+
+/// How a connector travels between two shapes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Route {
+    /// Straight line between the shape centers.
+    #[default]
+    Straight,
+    /// Axis aligned "Manhattan" route with two bends at the midpoint,
+    /// leaving along the axis of greater separation.
+    Orthogonal,
+    /// Smooth S-curve leaving and entering along the axis of greater
+    /// separation.
+    Curved,
+}
+
+/// Returns the open [`Path`] joining the shapes `from` and `to` along `route`,
+/// trimmed so it starts on the border of `from` and ends on the border of
+/// `to`.
+///
+/// The route is laid out between the centers of the shapes' bounding boxes.
+/// It is trimmed at its first crossing of the `from` outline and its last
+/// crossing of the `to` outline, which is exact for convex and star-shaped
+/// outlines. Returns `None` if either shape has no nodes or the shapes
+/// overlap so much that nothing is left between them.
+///
+/// Arguments:
+/// - from, to: &[`Path`] - shape outlines, treated as closed.
+/// - route: [`Route`] - path style between them.
+pub fn connector(from: &Path, to: &Path, route: Route) -> Option<Path> {
+    let a = center(from)?;
+    let b = center(to)?;
+    let nodes = route_nodes(a, b, route);
+
+    let start = crossings(&nodes, from).into_iter().reduce(f32::min).unwrap_or(0.0);
+    let end = crossings(&nodes, to).into_iter().reduce(f32::max).unwrap_or((nodes.len() - 1) as f32);
+    if end <= start { return None; }
+
+    Some(Path::new(slice(&nodes, start, end), false))
+}
+
+/// Draws a [`connector`] from `from` to `to` with the stroke of `style`,
+/// decorated at its ends by `arrow`.
+///
+/// Arguments:
+/// - stage: &mut [`Stage`] - stage to draw onto.
+/// - from, to: &[`Path`] - shape outlines, treated as closed.
+/// - route: [`Route`] - path style between them.
+/// - arrow: [`ArrowStyle`] - end decorations, e.g. [`ArrowStyle::NONE`] for a plain line.
+/// - style: [`Style`] - only the stroke is used.
+pub fn connect(stage: &mut Stage, from: &Path, to: &Path, route: Route, arrow: ArrowStyle, style: Style) {
+    let Some(line) = connector(from, to, route) else { return; };
+    render_arrow(stage, line.nodes(), arrow, style);
+}
+
+/// Returns the center of the bounding box of `shape`.
+fn center(shape: &Path) -> Option<Point> {
+    let ((x0, y0), (x1, y1)) = shape.bounds()?;
+    let c = (0.5 * (x0 + x1), 0.5 * (y0 + y1));
+    (c.0.is_finite() && c.1.is_finite()).then_some(c)
+}
+
+/// Returns the untrimmed polyline of `route` from `a` to `b`.
+fn route_nodes(a: Point, b: Point, route: Route) -> Vec<Point> {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let horizontal = dx.abs() >= dy.abs();
+    match route {
+        Route::Straight => vec![a, b],
+        Route::Orthogonal => {
+            if horizontal {
+                let mx = a.0 + 0.5 * dx;
+                vec![a, (mx, a.1), (mx, b.1), b]
+            } else {
+                let my = a.1 + 0.5 * dy;
+                vec![a, (a.0, my), (b.0, my), b]
+            }
+        }
+        Route::Curved => {
+            let (p1, p2) = if horizontal {
+                ((a.0 + 0.5 * dx, a.1), (b.0 - 0.5 * dx, b.1))
+            } else {
+                ((a.0, a.1 + 0.5 * dy), (b.0, b.1 - 0.5 * dy))
+            };
+            let mut out = vec![a];
+            CubicBezier { p0: a, p1, p2, p3: b }.flatten_into(2.0, &mut out);
+            out
+        }
+    }
+}
+
+/// Returns where the polyline `nodes` crosses the outline of `shape`, as
+/// fractional node indices `i + t` for a crossing at `t` along segment `i`.
+fn crossings(nodes: &[Point], shape: &Path) -> Vec<f32> {
+    let mut out = Vec::new();
+    for (nodes_s, _) in shape.subpaths() {
+        let n = nodes_s.len();
+        if n < 2 { continue; }
+        for j in 0..n {
+            let (c, d) = (nodes_s[j], nodes_s[(j + 1) % n]);
+            for (i, w) in nodes.windows(2).enumerate() {
+                if let Some(t) = intersect(w[0], w[1], c, d) {
+                    out.push(i as f32 + t);
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Returns `t` in `[0, 1]` along `a -> b` where it crosses segment `c -> d`.
+fn intersect(a: Point, b: Point, c: Point, d: Point) -> Option<f32> {
+    let r = (b.0 - a.0, b.1 - a.1);
+    let s = (d.0 - c.0, d.1 - c.1);
+    let denom = r.0 * s.1 - r.1 * s.0;
+    if denom == 0.0 { return None; }
+
+    let q = (c.0 - a.0, c.1 - a.1);
+    let t = (q.0 * s.1 - q.1 * s.0) / denom;
+    let u = (q.0 * r.1 - q.1 * r.0) / denom;
+    ((0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u)).then_some(t)
+}
+
+/// Returns the part of the polyline `nodes` between fractional node indices
+/// `start` and `end`.
+fn slice(nodes: &[Point], start: f32, end: f32) -> Vec<Point> {
+    let at = |f: f32| {
+        let i = (f.floor() as usize).min(nodes.len() - 2);
+        let t = f - i as f32;
+        let (a, b) = (nodes[i], nodes[i + 1]);
+        (a.0 + t * (b.0 - a.0), a.1 + t * (b.1 - a.1))
+    };
+
+    let mut out = vec![at(start)];
+    let first = start.floor() as usize + 1;
+    let last = end.ceil() as usize;
+    out.extend(nodes.iter().take(last).skip(first).copied());
+    out.push(at(end));
+    out
+}
@@ -0,0 +1,159 @@
+use crate::noise::hash;
+
+use super::Graph;
+
+/// World coord bounds `((xmin, ymin), (xmax, ymax))`.
+type Bounds = ((f32, f32), (f32, f32));
+
+/// Places `graph` as a layered tree inside `bounds`, roots on the top layer
+/// and every other node one layer below its shallowest parent.
+///
+/// Roots are nodes without incoming edges, or node `0` if every node has one.
+/// Nodes unreachable from the roots start further trees. Leaves are spread
+/// evenly across the width in depth first order and every parent is centered
+/// over its children, so subtrees never interleave.
+///
+/// Returns one world coord per node.
+pub fn layered(graph: &Graph, bounds: Bounds) -> Vec<(f32, f32)> {
+    let n = graph.len();
+    if n == 0 { return Vec::new(); }
+
+    let mut children: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut has_parent = vec![false; n];
+    for &(from, to) in graph.edges() {
+        if from != to {
+            children[from].push(to);
+            has_parent[to] = true;
+        }
+    }
+
+    // breadth first spanning forest, each node kept under its first parent
+    let mut depth: Vec<Option<usize>> = vec![None; n];
+    let mut tree: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut roots = Vec::new();
+    let mut queue = std::collections::VecDeque::new();
+    for root in (0..n).filter(|&i| !has_parent[i]).chain(0..n) {
+        if depth[root].is_some() { continue; }
+        depth[root] = Some(0);
+        roots.push(root);
+        queue.push_back(root);
+        while let Some(node) = queue.pop_front() {
+            let d = depth[node].unwrap_or(0);
+            for &child in &children[node] {
+                if depth[child].is_none() {
+                    depth[child] = Some(d + 1);
+                    tree[node].push(child);
+                    queue.push_back(child);
+                }
+            }
+        }
+    }
+
+    // leaves take consecutive slots, parents the mean slot of their children
+    let mut slot = vec![0.0f32; n];
+    let mut next = 0.0f32;
+    let mut stack: Vec<(usize, bool)> = roots.iter().rev().map(|&r| (r, false)).collect();
+    while let Some((node, visited)) = stack.pop() {
+        if tree[node].is_empty() {
+            slot[node] = next;
+            next += 1.0;
+        } else if visited {
+            slot[node] = tree[node].iter().map(|&c| slot[c]).sum::<f32>() / tree[node].len() as f32;
+        } else {
+            stack.push((node, true));
+            stack.extend(tree[node].iter().rev().map(|&c| (c, false)));
+        }
+    }
+
+    let ((x0, y0), (x1, y1)) = bounds;
+    let rows = depth.iter().flatten().max().map_or(1, |d| d + 1) as f32;
+    (0..n)
+        .map(|i| {
+            // top layer at `y1`, world y points up
+            let d = depth[i].unwrap_or(0) as f32;
+            let x = x0 + (slot[i] + 0.5) * (x1 - x0) / next;
+            let y = y1 - (d + 0.5) * (y1 - y0) / rows;
+            (x, y)
+        })
+        .collect()
+}
+
+/// Options for [`force_directed`].
+///
+/// Fields:
+/// - iterations: [usize] - simulation steps.
+/// - seed: [u32] - seed of the initial placement, identical seeds give
+///   identical layouts.
+#[derive(Debug, Clone, Copy)]
+pub struct ForceOptions {
+    pub iterations: usize,
+    pub seed: u32,
+}
+
+impl Default for ForceOptions {
+    fn default() -> Self {
+        Self { iterations: 300, seed: 0 }
+    }
+}
+
+/// Places `graph` inside `bounds` with a Fruchterman–Reingold force
+/// simulation: all nodes repel, edges pull their ends together, and the step
+/// size cools linearly to zero.
+///
+/// Edge direction is ignored. Returns one world coord per node.
+pub fn force_directed(graph: &Graph, bounds: Bounds, options: ForceOptions) -> Vec<(f32, f32)> {
+    let n = graph.len();
+    let ((x0, y0), (x1, y1)) = bounds;
+    let (w, h) = (x1 - x0, y1 - y0);
+    if n == 0 || !(w.is_finite() && h.is_finite()) { return Vec::new(); }
+
+    let center = (x0 + 0.5 * w, y0 + 0.5 * h);
+    if n == 1 { return vec![center]; }
+
+    // ideal edge length for `n` nodes sharing the area
+    let k = (w * h / n as f32).abs().sqrt().max(f32::EPSILON);
+    let jitter = |i: usize, axis: i32| hash(i as i32, axis, options.seed) as f32 / u32::MAX as f32 - 0.5;
+    let mut pos: Vec<(f32, f32)> = (0..n)
+        .map(|i| (center.0 + jitter(i, 0) * w, center.1 + jitter(i, 1) * h))
+        .collect();
+
+    let mut disp = vec![(0.0f32, 0.0f32); n];
+    let t0 = 0.1 * w.abs().max(h.abs());
+    for step in 0..options.iterations {
+        disp.fill((0.0, 0.0));
+
+        for i in 0..n {
+            for j in i + 1..n {
+                let (dx, dy) = (pos[i].0 - pos[j].0, pos[i].1 - pos[j].1);
+                let d2 = (dx * dx + dy * dy).max(1e-4);
+                let f = k * k / d2;
+                disp[i].0 += dx * f;
+                disp[i].1 += dy * f;
+                disp[j].0 -= dx * f;
+                disp[j].1 -= dy * f;
+            }
+        }
+
+        for &(a, b) in graph.edges() {
+            if a == b { continue; }
+            let (dx, dy) = (pos[a].0 - pos[b].0, pos[a].1 - pos[b].1);
+            let d = (dx * dx + dy * dy).sqrt();
+            let f = d / k;
+            disp[a].0 -= dx * f;
+            disp[a].1 -= dy * f;
+            disp[b].0 += dx * f;
+            disp[b].1 += dy * f;
+        }
+
+        let t = t0 * (1.0 - step as f32 / options.iterations as f32);
+        for (p, &(dx, dy)) in pos.iter_mut().zip(&disp) {
+            let d = (dx * dx + dy * dy).sqrt();
+            if d > 0.0 {
+                let s = d.min(t) / d;
+                p.0 = (p.0 + dx * s).clamp(x0.min(x1), x0.max(x1));
+                p.1 = (p.1 + dy * s).clamp(y0.min(y1), y0.max(y1));
+            }
+        }
+    }
+    pos
+}
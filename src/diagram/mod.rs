@@ -0,0 +1,120 @@
+//! Node-link diagrams: graph layouts and rendering of nodes, edges and
+//! arrowheads.
+//!
+//! A [`Graph`] only holds connectivity. Layouts such as [`layered`] and
+//! [`force_directed`] place its nodes inside world coord bounds, and
+//! [`render`] draws the result.
+//!
+//! ```ignore
+//! let graph = Graph::new(4).edge(0, 1).edge(0, 2).edge(2, 3);
+//! let positions = layered(&graph, stage.world_bounds());
+//! render(&mut stage, &graph, &positions, &DiagramStyle::default());
+//! ```
+
+pub mod connector;
+pub use connector::{connect, connector, Route};
+pub mod layout;
+pub use layout::{force_directed, layered, ForceOptions};
+
+use crate::shapes::ArrowStyle;
+use crate::{Color, Path, Stage, Style};
+
+/// Directed graph of `len` nodes indexed `0..len`, with edges between them.
+#[derive(Debug, Clone, Default)]
+pub struct Graph {
+    len: usize,
+    edges: Vec<(usize, usize)>,
+}
+
+impl Graph {
+    /// Creates a [`Graph`] of `len` nodes and no edges.
+    pub fn new(len: usize) -> Self {
+        Self { len, edges: Vec::new() }
+    }
+
+    /// Adds a directed edge `from -> to`. Edges naming a missing node are
+    /// ignored.
+    pub fn edge(mut self, from: usize, to: usize) -> Self {
+        self.add_edge(from, to);
+        self
+    }
+
+    /// In place form of [`Graph::edge`].
+    pub fn add_edge(&mut self, from: usize, to: usize) {
+        if from < self.len && to < self.len {
+            self.edges.push((from, to));
+        }
+    }
+
+    /// Returns the number of nodes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the graph has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the `(from, to)` edges in insertion order.
+    pub fn edges(&self) -> &[(usize, usize)] {
+        &self.edges
+    }
+}
+
+/// Appearance of a diagram drawn by [`render`].
+///
+/// Fields:
+/// - node_radius: [f32] - radius of the circular nodes in world units.
+/// - node: [`Style`] - node fill and outline.
+/// - edge: [`Style`] - edge stroke; its stroke color also fills arrowheads.
+/// - arrow: [`ArrowStyle`] - edge end decorations, [`ArrowStyle::NONE`] for
+///   undirected edges.
+/// - route: [`Route`] - how edges travel between nodes.
+#[derive(Clone, Copy)]
+pub struct DiagramStyle {
+    pub node_radius: f32,
+    pub node: Style,
+    pub edge: Style,
+    pub arrow: ArrowStyle,
+    pub route: Route,
+}
+
+impl Default for DiagramStyle {
+    fn default() -> Self {
+        Self {
+            node_radius: 8.0,
+            node: Style::new(Some(Color::WHITE), Some(Color::BLACK)),
+            edge: Style::stroke_only(Color::BLACK),
+            arrow: ArrowStyle::default(),
+            route: Route::Straight,
+        }
+    }
+}
+
+/// Draws the edges of `graph`, then its nodes, at `positions` (world coords,
+/// one per node).
+///
+/// Edges are drawn with [`connect`], so they run between node borders rather
+/// than centers and their decorations touch the node borders. Nodes without a
+/// position are skipped along with their edges.
+///
+/// Arguments:
+/// - stage: &mut [`Stage`] - stage to draw onto.
+/// - graph: &[`Graph`] - connectivity.
+/// - positions: &[([f32], [f32])] - node centers, e.g. from [`layered`].
+/// - style: &[`DiagramStyle`] - node and edge appearance.
+pub fn render(stage: &mut Stage, graph: &Graph, positions: &[(f32, f32)], style: &DiagramStyle) {
+    let r = style.node_radius.max(0.0);
+    let shape = |p: (f32, f32)| Path::circle(p, r);
+
+    for &(from, to) in graph.edges() {
+        let (Some(&a), Some(&b)) = (positions.get(from), positions.get(to)) else { continue; };
+        if from == to { continue; }
+        connect(stage, &shape(a), &shape(b), style.route, style.arrow, style.edge);
+    }
+
+    for &p in positions.iter().take(graph.len()) {
+        shape(p).render(stage, style.node);
+    }
+}
@@ -0,0 +1,51 @@
+use std::fmt;
+
+/// Errors returned by the checked `try_*` constructors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Error {
+    /// A [`Stage`](crate::Stage) dimension was zero.
+    ZeroDimension { width: usize, height: usize },
+    /// A [`Stage`](crate::Stage) row stride was smaller than its width.
+    StrideTooSmall { width: usize, stride: usize },
+    /// `width * height` overflowed `usize`.
+    DimensionsOverflow { width: usize, height: usize },
+    /// The framebuffer of a [`Stage`](crate::Stage) could not be allocated.
+    AllocationFailed { width: usize, height: usize },
+    /// A caller's pixel buffer did not hold the `required` pixels of a
+    /// [`Stage`](crate::Stage).
+    BufferLength { len: usize, required: usize },
+    /// An [`Opacity`](crate::Opacity) float was non-finite or outside `[0.0, 1.0]`.
+    OpacityOutOfRange(f32),
+    /// A [`Stroke`](crate::Stroke) width was non-finite or not strictly positive.
+    InvalidStrokeWidth(f32),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::ZeroDimension { width, height } => {
+                write!(f, "Stage must be strictly positive in size, got {width}x{height}")
+            }
+            Error::StrideTooSmall { width, stride } => {
+                write!(f, "Stage stride must be at least its width {width}, got {stride}")
+            }
+            Error::DimensionsOverflow { width, height } => {
+                write!(f, "Stage dimensions overflow, got {width}x{height}")
+            }
+            Error::AllocationFailed { width, height } => {
+                write!(f, "Stage framebuffer allocation failed for {width}x{height}")
+            }
+            Error::BufferLength { len, required } => {
+                write!(f, "Stage pixel buffer holds {len} pixels, expected {required}")
+            }
+            Error::OpacityOutOfRange(x) => {
+                write!(f, "opacity must be finite and in [0.0, 1.0], got {x}")
+            }
+            Error::InvalidStrokeWidth(w) => {
+                write!(f, "stroke width must be finite and strictly positive, got {w}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,159 @@
+//! Figure-level framing: margins, title and caption bands, and the plotting
+//! area left for panels.
+
+use std::path::Path as FsPath;
+
+use image::ImageResult;
+
+use crate::{Color, GridLayout, InsetStage, Rect, Stage};
+
+/// Height in pixels reserved for a title band.
+const TITLE_HEIGHT: usize = 24;
+/// Height in pixels reserved for a caption band.
+const CAPTION_HEIGHT: usize = 18;
+
+/// Pixel margins on each side of a [`Figure`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Margins {
+    pub top: usize,
+    pub right: usize,
+    pub bottom: usize,
+    pub left: usize,
+}
+
+impl Margins {
+    /// Creates [`Margins`] with the given `top`, `right`, `bottom`, and `left` sizes.
+    pub const fn new(top: usize, right: usize, bottom: usize, left: usize) -> Self {
+        Self { top, right, bottom, left }
+    }
+
+    /// Creates [`Margins`] of `size` pixels on every side.
+    pub const fn uniform(size: usize) -> Self {
+        Self::new(size, size, size, size)
+    }
+}
+
+/// A [`Stage`] framed by margins with optional title and caption bands.
+///
+/// The title band sits at the top inside the margins and the caption band at
+/// the bottom; whatever remains is the plotting area, exposed as a [`Rect`]
+/// or directly as an [`InsetStage`]. The crate has no text rendering yet, so
+/// titles and captions only reserve their bands; [`Figure::title_rect`] and
+/// [`Figure::caption_rect`] give where to place them.
+pub struct Figure {
+    stage: Stage,
+    margins: Margins,
+    title: Option<String>,
+    caption: Option<String>,
+}
+
+impl Figure {
+    /// Creates a `width` x `height` [`Figure`] with no margins, title, or caption.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self::from_stage(Stage::new(width, height))
+    }
+
+    /// Wraps an existing `stage` in a [`Figure`].
+    pub fn from_stage(stage: Stage) -> Self {
+        Self { stage, margins: Margins::default(), title: None, caption: None }
+    }
+
+    /// Sets the outer margins.
+    pub fn margins(mut self, margins: Margins) -> Self {
+        self.margins = margins;
+        self
+    }
+
+    /// Sets the title, reserving a band for it at the top of the figure.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the caption, reserving a band for it at the bottom of the figure.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Returns the title text, if set.
+    pub fn title_text(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// Returns the caption text, if set.
+    pub fn caption_text(&self) -> Option<&str> {
+        self.caption.as_deref()
+    }
+
+    /// Returns the area inside the margins, or `None` if the margins leave no room.
+    fn content_rect(&self) -> Option<Rect> {
+        let (w, h) = self.stage.dimensions();
+        let m = self.margins;
+        let width = w.checked_sub(m.left + m.right).filter(|&v| v > 0)?;
+        let height = h.checked_sub(m.top + m.bottom).filter(|&v| v > 0)?;
+        Some(Rect::new(m.left, m.top, width, height))
+    }
+
+    /// Returns the title band, or `None` if no title is set.
+    pub fn title_rect(&self) -> Option<Rect> {
+        self.title.as_ref()?;
+        let c = self.content_rect()?;
+        Some(Rect::new(c.x, c.y, c.width, TITLE_HEIGHT.min(c.height)))
+    }
+
+    /// Returns the caption band, or `None` if no caption is set.
+    pub fn caption_rect(&self) -> Option<Rect> {
+        self.caption.as_ref()?;
+        let c = self.content_rect()?;
+        let h = CAPTION_HEIGHT.min(c.height);
+        Some(Rect::new(c.x, c.bottom() - h, c.width, h))
+    }
+
+    /// Returns the plotting area left after margins, title, and caption.
+    /// Empty if they consume the whole figure.
+    pub fn plot_area(&self) -> Rect {
+        let Some(c) = self.content_rect() else { return Rect::new(0, 0, 0, 0); };
+
+        let top = self.title_rect().map_or(0, |r| r.height);
+        let bottom = self.caption_rect().map_or(0, |r| r.height);
+        let height = c.height.saturating_sub(top + bottom);
+        Rect::new(c.x, c.y + top, c.width, height)
+    }
+
+    /// Returns an [`InsetStage`] covering the plotting area.
+    pub fn axes(&mut self) -> InsetStage<'_> {
+        let area = self.plot_area();
+        self.stage.inset(area)
+    }
+
+    /// Returns the cells of `layout` laid over the plotting area.
+    pub fn panels(&self, layout: GridLayout) -> Vec<Rect> {
+        layout.cells(self.plot_area())
+    }
+
+    /// Fills the whole figure, margins included, with `color`.
+    pub fn clear(&mut self, color: Color) {
+        self.stage.clear(color);
+    }
+
+    /// Returns the underlying [`Stage`].
+    pub fn stage(&self) -> &Stage {
+        &self.stage
+    }
+
+    /// Returns the underlying [`Stage`] mutably, for drawing in the margins.
+    pub fn stage_mut(&mut self) -> &mut Stage {
+        &mut self.stage
+    }
+
+    /// Consumes `self`, returning the underlying [`Stage`].
+    pub fn into_stage(self) -> Stage {
+        self.stage
+    }
+
+    /// Saves the figure as a `png`.
+    pub fn save_png<P: AsRef<FsPath>>(&self, path: P) -> ImageResult<()> {
+        self.stage.save_png(path)
+    }
+}
@@ -0,0 +1,181 @@
+//! Color filters applied in place to a rectangular region of a [`Stage`].
+
+use crate::{Rect, Stage};
+use crate::resample::{linear_to_srgb, srgb_to_linear};
+
+/// Color filter operating on RGB channels. Alpha is preserved.
+///
+/// Variants follow the CSS filter definitions.
+#[derive(Debug, Clone, Copy)]
+pub enum Filter {
+    /// Converts to luminance.
+    Grayscale,
+    /// Inverts each channel.
+    Invert,
+    /// Warm brown tone.
+    Sepia,
+    /// Multiplies each channel by the factor. `1.0` is identity.
+    Brightness(f32),
+    /// Scales each channel away from mid-gray by the factor. `1.0` is identity.
+    Contrast(f32),
+    /// Rotates hue by the given angle in degrees.
+    HueRotate(f32),
+}
+
+impl Filter {
+    /// Returns the affine color transform `(matrix, offset)` of `self`
+    /// operating on channels in `[0, 255]`.
+    fn transform(self) -> ([[f32; 3]; 3], [f32; 3]) {
+        const IDENTITY: [[f32; 3]; 3] = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+        match self {
+            Filter::Grayscale => {
+                let row = [0.2126, 0.7152, 0.0722];
+                ([row, row, row], [0.0; 3])
+            }
+            Filter::Invert => {
+                let m = [[-1.0, 0.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, -1.0]];
+                (m, [255.0; 3])
+            }
+            Filter::Sepia => {
+                let m = [
+                    [0.393, 0.769, 0.189],
+                    [0.349, 0.686, 0.168],
+                    [0.272, 0.534, 0.131],
+                ];
+                (m, [0.0; 3])
+            }
+            Filter::Brightness(k) => {
+                let k = if k.is_finite() { k.max(0.0) } else { 1.0 };
+                let m = [[k, 0.0, 0.0], [0.0, k, 0.0], [0.0, 0.0, k]];
+                (m, [0.0; 3])
+            }
+            Filter::Contrast(k) => {
+                let k = if k.is_finite() { k.max(0.0) } else { 1.0 };
+                let m = [[k, 0.0, 0.0], [0.0, k, 0.0], [0.0, 0.0, k]];
+                let o = 127.5 * (1.0 - k);
+                (m, [o; 3])
+            }
+            Filter::HueRotate(deg) => {
+                if !deg.is_finite() { return (IDENTITY, [0.0; 3]); }
+
+                let (sin, cos) = deg.to_radians().sin_cos();
+                let m = [
+                    [
+                        0.213 + cos * 0.787 - sin * 0.213,
+                        0.715 - cos * 0.715 - sin * 0.715,
+                        0.072 - cos * 0.072 + sin * 0.928,
+                    ],
+                    [
+                        0.213 - cos * 0.213 + sin * 0.143,
+                        0.715 + cos * 0.285 + sin * 0.140,
+                        0.072 - cos * 0.072 - sin * 0.283,
+                    ],
+                    [
+                        0.213 - cos * 0.213 - sin * 0.787,
+                        0.715 - cos * 0.715 + sin * 0.715,
+                        0.072 + cos * 0.928 + sin * 0.072,
+                    ],
+                ];
+                (m, [0.0; 3])
+            }
+        }
+    }
+}
+
+/// Dichromatic color vision deficiency simulated by
+/// [`Stage::simulate_color_blindness`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorBlindness {
+    /// Missing long wavelength (red) cones.
+    Protanopia,
+    /// Missing medium wavelength (green) cones.
+    Deuteranopia,
+    /// Missing short wavelength (blue) cones.
+    Tritanopia,
+}
+
+impl ColorBlindness {
+    /// Returns the Machado et al. (2009) simulation matrix of `self` at full
+    /// severity, operating on linear RGB.
+    fn matrix(self) -> [[f32; 3]; 3] {
+        match self {
+            ColorBlindness::Protanopia => [
+                [0.152286, 1.052583, -0.204868],
+                [0.114503, 0.786281, 0.099216],
+                [-0.003882, -0.048116, 1.051998],
+            ],
+            ColorBlindness::Deuteranopia => [
+                [0.367322, 0.860646, -0.227968],
+                [0.280085, 0.672501, 0.047413],
+                [-0.011820, 0.042940, 0.968881],
+            ],
+            ColorBlindness::Tritanopia => [
+                [1.255528, -0.076749, -0.178779],
+                [-0.078411, 0.930809, 0.147602],
+                [0.004733, 0.691367, 0.303900],
+            ],
+        }
+    }
+}
+
+#[inline(always)]
+fn apply_transform(m: &[[f32; 3]; 3], o: &[f32; 3], px: [u8; 4]) -> [u8; 4] {
+    let (r, g, b) = (px[0] as f32, px[1] as f32, px[2] as f32);
+
+    let mut out = px;
+    for c in 0..3 {
+        let v = m[c][0] * r + m[c][1] * g + m[c][2] * b + o[c];
+        out[c] = v.round().clamp(0.0, 255.0) as u8;
+    }
+    out
+}
+
+impl Stage {
+    /// Applies `filter` in place to every pixel inside `rect`. The part of
+    /// `rect` outside the [`Stage`] is ignored.
+    ///
+    /// Arguments:
+    /// - rect: [`Rect`] - region in pixel coords.
+    /// - filter: [`Filter`] - color filter to apply.
+    pub fn apply_filter(&mut self, rect: Rect, filter: Filter) {
+        let Some(rect) = rect.intersect(self.bounds()) else { return; };
+        let (m, o) = filter.transform();
+
+        for y in rect.y..rect.bottom() {
+            let row = self.row_mut(y);
+            for px in &mut row[rect.x..rect.right()] {
+                *px = apply_transform(&m, &o, *px);
+            }
+        }
+    }
+
+    /// Returns a copy of `self` as seen with the color vision deficiency
+    /// `kind`, for checking that a palette stays distinguishable.
+    ///
+    /// Colors are transformed in linear light. Alpha is preserved. Like
+    /// [`Stage::resized`], only the pixels are copied.
+    ///
+    /// Arguments:
+    /// - kind: [`ColorBlindness`] - deficiency to simulate.
+    pub fn simulate_color_blindness(&self, kind: ColorBlindness) -> Stage {
+        let m = kind.matrix();
+        let decode: [f32; 256] = std::array::from_fn(|i| srgb_to_linear(i as f32 / 255.0));
+
+        let (w, h) = self.dimensions();
+        let mut out = Stage::new(w, h);
+        for y in 0..h {
+            for (dst, src) in out.row_mut(y).iter_mut().zip(self.row(y)) {
+                let rgb = [decode[src[0] as usize], decode[src[1] as usize], decode[src[2] as usize]];
+                let mut px = *src;
+                for c in 0..3 {
+                    let v = m[c][0] * rgb[0] + m[c][1] * rgb[1] + m[c][2] * rgb[2];
+                    px[c] = (linear_to_srgb(v.clamp(0.0, 1.0)) * 255.0).round() as u8;
+                }
+                *dst = px;
+            }
+        }
+
+        out
+    }
+}
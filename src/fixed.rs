@@ -0,0 +1,129 @@
+//! Compile time sized stages for tiny targets such as sprites, icons and
+//! LED matrices.
+
+use crate::{Color, Rect, Stage};
+
+/// `W` x `H` RGBA framebuffer stored inline, without heap allocation.
+///
+/// Dimensions are known at compile time, so row indexing folds to constants
+/// and small buffers can live on the stack or in a `static`. Pixel writes go
+/// straight to the buffer. Shapes and paths are drawn through
+/// [`FixedStage::draw`], which lends the pixels to a regular [`Stage`]
+/// without copying them.
+#[derive(Clone, PartialEq, Eq)]
+pub struct FixedStage<const W: usize, const H: usize> {
+    framebuf: [[[u8; 4]; W]; H],
+}
+
+impl<const W: usize, const H: usize> FixedStage<W, H> {
+    /// Width of the [`FixedStage`].
+    pub const WIDTH: usize = W;
+
+    /// Height of the [`FixedStage`].
+    pub const HEIGHT: usize = H;
+
+    /// Creates a [`FixedStage`] that is black and transparent.
+    pub const fn new() -> Self {
+        Self { framebuf: [[[0; 4]; W]; H] }
+    }
+
+    /// Returns the dimensions `(W, H)` of the [`FixedStage`].
+    pub const fn dimensions(&self) -> (usize, usize) {
+        (W, H)
+    }
+
+    /// Returns the [`Rect`] covering the whole [`FixedStage`].
+    pub const fn bounds(&self) -> Rect {
+        Rect::new(0, 0, W, H)
+    }
+
+    /// Returns the pixels in row major order, `W` per row.
+    pub fn pixels(&self) -> &[[u8; 4]] {
+        self.framebuf.as_flattened()
+    }
+
+    /// Returns the pixels in row major order, `W` per row.
+    pub fn pixels_mut(&mut self) -> &mut [[u8; 4]] {
+        self.framebuf.as_flattened_mut()
+    }
+
+    /// Returns the framebuffer as tightly packed RGBA bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.pixels().as_flattened()
+    }
+
+    /// Returns a reference to row `y`.
+    pub fn row(&self, y: usize) -> &[[u8; 4]; W] {
+        &self.framebuf[y]
+    }
+
+    /// Gets the color value of a pixel at `(x, y)`.
+    ///
+    /// Returns `None` if out-of-bounds, otherwise `Some([u8; 4])`.
+    pub fn get_pixel(&self, x: usize, y: usize) -> Option<[u8; 4]> {
+        self.framebuf.get(y)?.get(x).copied()
+    }
+
+    /// Sets the [`FixedStage`] background to the provided `color`.
+    pub fn clear(&mut self, color: Color) {
+        self.framebuf = [[color.rgba(); W]; H];
+    }
+
+    /// Sets the color value of a signed pixel at `(x, y)`.
+    /// If the pixel is out-of-bounds, silently does nothing.
+    #[inline(always)]
+    pub fn plot_pxl(&mut self, x: isize, y: isize, color: Color) {
+        if x < 0 || y < 0 {
+            return;
+        }
+        if let Some(px) = self.framebuf.get_mut(y as usize).and_then(|row| row.get_mut(x as usize)) {
+            *px = color.rgba();
+        }
+    }
+
+    /// Fills `rect` with `color`. The part of `rect` outside the
+    /// [`FixedStage`] is dropped.
+    pub fn fill_rect(&mut self, rect: Rect, color: Color) {
+        let Some(r) = rect.intersect(self.bounds()) else { return; };
+        let rgba = color.rgba();
+        for row in &mut self.framebuf[r.y..r.bottom()] {
+            row[r.x..r.right()].fill(rgba);
+        }
+    }
+
+    /// Runs `draw` against a [`Stage`] that draws straight into the pixels
+    /// of `self`, without allocating a framebuffer or copying.
+    ///
+    /// The lent [`Stage`] must stay where `draw` finds it: moving it out, e.g.
+    /// with [`std::mem::swap`], aborts the process once `draw` returns.
+    ///
+    /// Arguments:
+    /// - draw: `FnOnce(&mut Stage)` - draw calls, in world coords centered
+    ///   on the [`FixedStage`] like a regular [`Stage`].
+    pub fn draw<F: FnOnce(&mut Stage)>(&mut self, draw: F) {
+        Stage::lend(W, H, W, self.framebuf.as_flattened_mut(), draw);
+    }
+
+    /// Returns a heap [`Stage`] with the same dimensions and pixels.
+    pub fn to_stage(&self) -> Stage {
+        let mut stage = Stage::new(W, H);
+        stage.pixels_mut().copy_from_slice(self.pixels());
+        stage
+    }
+
+    /// Copies the top-left `W` x `H` pixels of `stage` into `self`. Pixels
+    /// `stage` does not cover are left unchanged.
+    pub fn copy_from(&mut self, stage: &Stage) {
+        let w = W.min(stage.width());
+        for (y, row) in self.framebuf.iter_mut().enumerate().take(stage.height()) {
+            let start = y * stage.stride();
+            row[..w].copy_from_slice(&stage.raw_pixels()[start..start + w]);
+        }
+    }
+}
+
+impl<const W: usize, const H: usize> Default for FixedStage<W, H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,261 @@
+use crate::Path;
+
+type Point = (f32, f32);
+
+/// Cubic Bézier segment in world coords.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CubicBezier {
+    pub p0: Point,
+    pub p1: Point,
+    pub p2: Point,
+    pub p3: Point,
+}
+
+impl CubicBezier {
+    /// Evaluates the curve at `t` in `[0, 1]`.
+    pub fn eval(&self, t: f32) -> Point {
+        let mt = 1.0 - t;
+        let a = mt * mt * mt;
+        let b = 3.0 * mt * mt * t;
+        let c = 3.0 * mt * t * t;
+        let d = t * t * t;
+        (
+            a * self.p0.0 + b * self.p1.0 + c * self.p2.0 + d * self.p3.0,
+            a * self.p0.1 + b * self.p1.1 + c * self.p2.1 + d * self.p3.1,
+        )
+    }
+
+    /// Appends points along the curve to `out`, excluding `p0`.
+    ///
+    /// The number of steps scales with the control polygon length so that
+    /// consecutive points are roughly `max_step` world units apart.
+    pub(crate) fn flatten_into(&self, max_step: f32, out: &mut Vec<Point>) {
+        let polygon = dist(self.p0, self.p1) + dist(self.p1, self.p2) + dist(self.p2, self.p3);
+        let steps = ((polygon / max_step.max(0.1)).ceil() as usize).clamp(1, 256);
+
+        for i in 1..=steps {
+            out.push(self.eval(i as f32 / steps as f32));
+        }
+    }
+
+    fn first_derivative(&self, t: f32) -> Point {
+        let mt = 1.0 - t;
+        let a = 3.0 * mt * mt;
+        let b = 6.0 * mt * t;
+        let c = 3.0 * t * t;
+        (
+            a * (self.p1.0 - self.p0.0) + b * (self.p2.0 - self.p1.0) + c * (self.p3.0 - self.p2.0),
+            a * (self.p1.1 - self.p0.1) + b * (self.p2.1 - self.p1.1) + c * (self.p3.1 - self.p2.1),
+        )
+    }
+
+    fn second_derivative(&self, t: f32) -> Point {
+        let mt = 1.0 - t;
+        (
+            6.0 * mt * (self.p2.0 - 2.0 * self.p1.0 + self.p0.0) + 6.0 * t * (self.p3.0 - 2.0 * self.p2.0 + self.p1.0),
+            6.0 * mt * (self.p2.1 - 2.0 * self.p1.1 + self.p0.1) + 6.0 * t * (self.p3.1 - 2.0 * self.p2.1 + self.p1.1),
+        )
+    }
+}
+
+/// Fits a smooth open [`Path`] through sampled `points` using Schneider's
+/// algorithm, then flattens the fitted curves.
+///
+/// Arguments:
+/// - points: &[([f32], [f32])] - ordered world coord samples.
+/// - tolerance: [f32] - largest allowed distance between a sample and the curve.
+pub fn fit_beziers(points: &[Point], tolerance: f32) -> Path {
+    let curves = fit_cubics(points, tolerance);
+
+    let mut nodes = Vec::new();
+    if let Some(first) = curves.first() {
+        nodes.push(first.p0);
+    }
+    for c in &curves {
+        c.flatten_into(2.0, &mut nodes);
+    }
+
+    Path::new(nodes, false)
+}
+
+/// Fits cubic Bézier segments through sampled `points` using Schneider's algorithm.
+///
+/// Consecutive segments share endpoints and tangent directions. Non-finite and
+/// repeated points are ignored.
+pub fn fit_cubics(points: &[Point], tolerance: f32) -> Vec<CubicBezier> {
+    let mut pts: Vec<Point> = Vec::with_capacity(points.len());
+    for &p in points {
+        if p.0.is_finite() && p.1.is_finite() && pts.last() != Some(&p) {
+            pts.push(p);
+        }
+    }
+    if pts.len() < 2 { return Vec::new(); }
+
+    let tolerance = if tolerance.is_finite() && tolerance > 0.0 { tolerance } else { 1.0 };
+    let n = pts.len();
+    let t1 = normalize(sub(pts[1], pts[0]));
+    let t2 = normalize(sub(pts[n - 2], pts[n - 1]));
+
+    let mut out = Vec::new();
+    fit_cubic(&pts, t1, t2, tolerance * tolerance, &mut out);
+    out
+}
+
+const MAX_ITERATIONS: usize = 4;
+
+fn fit_cubic(pts: &[Point], t1: Point, t2: Point, error2: f32, out: &mut Vec<CubicBezier>) {
+    let n = pts.len();
+    if n == 2 {
+        let d = dist(pts[0], pts[1]) / 3.0;
+        out.push(CubicBezier {
+            p0: pts[0],
+            p1: add(pts[0], scale(t1, d)),
+            p2: add(pts[1], scale(t2, d)),
+            p3: pts[1],
+        });
+        return;
+    }
+
+    let mut u = chord_length_params(pts);
+    let mut curve = generate_bezier(pts, &u, t1, t2);
+    let (mut max_err, mut split) = max_error(pts, &curve, &u);
+    if max_err < error2 {
+        out.push(curve);
+        return;
+    }
+
+    // close enough to improve by reparameterizing
+    if max_err < error2 * 4.0 {
+        for _ in 0..MAX_ITERATIONS {
+            u = reparameterize(pts, &u, &curve);
+            curve = generate_bezier(pts, &u, t1, t2);
+            (max_err, split) = max_error(pts, &curve, &u);
+            if max_err < error2 {
+                out.push(curve);
+                return;
+            }
+        }
+    }
+
+    let split = split.clamp(1, n - 2);
+    let center = normalize(sub(pts[split - 1], pts[split + 1]));
+    fit_cubic(&pts[..=split], t1, center, error2, out);
+    fit_cubic(&pts[split..], scale(center, -1.0), t2, error2, out);
+}
+
+/// Least squares control points for fixed end tangents `t1` and `t2`.
+fn generate_bezier(pts: &[Point], u: &[f32], t1: Point, t2: Point) -> CubicBezier {
+    let first = pts[0];
+    let last = pts[pts.len() - 1];
+
+    let mut c = [[0.0f32; 2]; 2];
+    let mut x = [0.0f32; 2];
+
+    for (i, &t) in u.iter().enumerate() {
+        let mt = 1.0 - t;
+        let a1 = scale(t1, 3.0 * mt * mt * t);
+        let a2 = scale(t2, 3.0 * mt * t * t);
+
+        c[0][0] += dot(a1, a1);
+        c[0][1] += dot(a1, a2);
+        c[1][1] += dot(a2, a2);
+
+        let b0 = mt * mt * mt + 3.0 * mt * mt * t;
+        let b3 = 3.0 * mt * t * t + t * t * t;
+        let tmp = sub(pts[i], add(scale(first, b0), scale(last, b3)));
+
+        x[0] += dot(a1, tmp);
+        x[1] += dot(a2, tmp);
+    }
+    c[1][0] = c[0][1];
+
+    let det = c[0][0] * c[1][1] - c[1][0] * c[0][1];
+    let (mut alpha1, mut alpha2) = if det.abs() > 1e-12 {
+        (
+            (x[0] * c[1][1] - x[1] * c[0][1]) / det,
+            (c[0][0] * x[1] - c[1][0] * x[0]) / det,
+        )
+    } else {
+        (0.0, 0.0)
+    };
+
+    // fall back to the Wu/Barsky heuristic for degenerate fits
+    let seg_len = dist(first, last);
+    let eps = 1e-6 * seg_len;
+    if alpha1 < eps || alpha2 < eps {
+        alpha1 = seg_len / 3.0;
+        alpha2 = seg_len / 3.0;
+    }
+
+    CubicBezier {
+        p0: first,
+        p1: add(first, scale(t1, alpha1)),
+        p2: add(last, scale(t2, alpha2)),
+        p3: last,
+    }
+}
+
+/// One Newton-Raphson step per sample towards its closest point on `curve`.
+fn reparameterize(pts: &[Point], u: &[f32], curve: &CubicBezier) -> Vec<f32> {
+    pts.iter()
+        .zip(u)
+        .map(|(&p, &t)| {
+            let d = sub(curve.eval(t), p);
+            let d1 = curve.first_derivative(t);
+            let d2 = curve.second_derivative(t);
+
+            let num = dot(d, d1);
+            let den = dot(d1, d1) + dot(d, d2);
+            if den.abs() < 1e-12 { t } else { (t - num / den).clamp(0.0, 1.0) }
+        })
+        .collect()
+}
+
+fn chord_length_params(pts: &[Point]) -> Vec<f32> {
+    let mut u = Vec::with_capacity(pts.len());
+    u.push(0.0);
+    for i in 1..pts.len() {
+        u.push(u[i - 1] + dist(pts[i - 1], pts[i]));
+    }
+
+    let total = u[u.len() - 1];
+    if total > 0.0 {
+        for v in &mut u { *v /= total; }
+    }
+    u
+}
+
+/// Returns the largest squared distance and the index where it occurs.
+fn max_error(pts: &[Point], curve: &CubicBezier, u: &[f32]) -> (f32, usize) {
+    let mut max = 0.0;
+    let mut split = pts.len() / 2;
+    for i in 1..pts.len() - 1 {
+        let d = sub(curve.eval(u[i]), pts[i]);
+        let d2 = dot(d, d);
+        if d2 >= max {
+            max = d2;
+            split = i;
+        }
+    }
+    (max, split)
+}
+
+#[inline(always)]
+fn add(a: Point, b: Point) -> Point { (a.0 + b.0, a.1 + b.1) }
+
+#[inline(always)]
+fn sub(a: Point, b: Point) -> Point { (a.0 - b.0, a.1 - b.1) }
+
+#[inline(always)]
+fn scale(a: Point, k: f32) -> Point { (a.0 * k, a.1 * k) }
+
+#[inline(always)]
+fn dot(a: Point, b: Point) -> f32 { a.0 * b.0 + a.1 * b.1 }
+
+#[inline(always)]
+fn dist(a: Point, b: Point) -> f32 { dot(sub(b, a), sub(b, a)).sqrt() }
+
+fn normalize(a: Point) -> Point {
+    let len = dot(a, a).sqrt();
+    if len == 0.0 { (0.0, 0.0) } else { (a.0 / len, a.1 / len) }
+}
@@ -0,0 +1,201 @@
+use crate::Path;
+
+/// Number of segments used when flattening circles into a [`Path`].
+const CIRCLE_SEGMENTS: usize = 64;
+
+/// Returns the convex hull of `points` as a closed counter-clockwise [`Path`].
+///
+/// Uses Andrew's monotone chain. Non-finite points are ignored.
+///
+/// Returns `None` if fewer than three non-collinear points are provided.
+pub fn convex_hull(points: &[(f32, f32)]) -> Option<Path> {
+    let hull = hull_nodes(points);
+    if hull.len() < 3 { return None; }
+
+    Some(Path::new(hull, true))
+}
+
+/// Returns the minimum-area bounding rectangle of `points` as a closed [`Path`].
+///
+/// The rectangle may be rotated. One of its sides is always collinear with an
+/// edge of the convex hull, so only hull edges are tested.
+pub fn min_area_rect(points: &[(f32, f32)]) -> Option<Path> {
+    let hull = hull_nodes(points);
+    if hull.len() < 3 { return None; }
+
+    let mut best: Option<(f32, [(f32, f32); 4])> = None;
+
+    for i in 0..hull.len() {
+        let (x1, y1) = hull[i];
+        let (x2, y2) = hull[(i + 1) % hull.len()];
+
+        let len = ((x2 - x1).powi(2) + (y2 - y1).powi(2)).sqrt();
+        if len == 0.0 { continue; }
+
+        // edge aligned basis
+        let (ux, uy) = ((x2 - x1) / len, (y2 - y1) / len);
+        let (vx, vy) = (-uy, ux);
+
+        let (mut umin, mut umax) = (f32::INFINITY, f32::NEG_INFINITY);
+        let (mut vmin, mut vmax) = (f32::INFINITY, f32::NEG_INFINITY);
+        for &(x, y) in &hull {
+            let u = x * ux + y * uy;
+            let v = x * vx + y * vy;
+            umin = umin.min(u);
+            umax = umax.max(u);
+            vmin = vmin.min(v);
+            vmax = vmax.max(v);
+        }
+
+        let area = (umax - umin) * (vmax - vmin);
+        if best.is_none_or(|(a, _)| area < a) {
+            let corner = |u: f32, v: f32| (u * ux + v * vx, u * uy + v * vy);
+            let corners = [
+                corner(umin, vmin),
+                corner(umax, vmin),
+                corner(umax, vmax),
+                corner(umin, vmax),
+            ];
+            best = Some((area, corners));
+        }
+    }
+
+    let (_, corners) = best?;
+    Some(Path::new(Vec::from(corners), true))
+}
+
+/// Returns the minimum bounding circle of `points` flattened into a closed [`Path`].
+///
+/// Uses Welzl's algorithm in its iterative form.
+pub fn bounding_circle(points: &[(f32, f32)]) -> Option<Path> {
+    let (center, radius) = min_enclosing_circle(points)?;
+    if radius <= 0.0 { return None; }
+
+    Some(Path::new(circle_nodes(center, radius, CIRCLE_SEGMENTS), true))
+}
+
+/// Returns the `(center, radius)` of the minimum circle enclosing `points`.
+pub(crate) fn min_enclosing_circle(points: &[(f32, f32)]) -> Option<((f32, f32), f32)> {
+    let pts: Vec<(f32, f32)> = points
+        .iter()
+        .copied()
+        .filter(|p| p.0.is_finite() && p.1.is_finite())
+        .collect();
+    if pts.is_empty() { return None; }
+
+    let contains = |(c, r): ((f32, f32), f32), p: (f32, f32)| {
+        dist(c, p) <= r * (1.0 + 1e-5) + 1e-4
+    };
+
+    let mut circle = (pts[0], 0.0);
+    for i in 1..pts.len() {
+        if contains(circle, pts[i]) { continue; }
+
+        circle = (pts[i], 0.0);
+        for j in 0..i {
+            if contains(circle, pts[j]) { continue; }
+
+            circle = circle_from_two(pts[i], pts[j]);
+            for k in 0..j {
+                if contains(circle, pts[k]) { continue; }
+
+                circle = circle_from_three(pts[i], pts[j], pts[k])
+                    .unwrap_or_else(|| widest_pair(pts[i], pts[j], pts[k]));
+            }
+        }
+    }
+
+    Some(circle)
+}
+
+/// Returns the vertices of the convex hull of `points` in counter-clockwise order.
+pub(crate) fn hull_nodes(points: &[(f32, f32)]) -> Vec<(f32, f32)> {
+    let mut pts: Vec<(f32, f32)> = points
+        .iter()
+        .copied()
+        .filter(|p| p.0.is_finite() && p.1.is_finite())
+        .collect();
+
+    pts.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+    pts.dedup();
+    if pts.len() < 3 { return pts; }
+
+    let mut hull: Vec<(f32, f32)> = Vec::with_capacity(2 * pts.len());
+
+    // lower hull
+    for &p in &pts {
+        while hull.len() >= 2 && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.0 {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+
+    // upper hull
+    let lower_len = hull.len() + 1;
+    for &p in pts.iter().rev().skip(1) {
+        while hull.len() >= lower_len && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.0 {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+
+    hull.pop();
+    hull
+}
+
+/// Returns `count` nodes evenly spaced around a circle.
+pub(crate) fn circle_nodes(center: (f32, f32), radius: f32, count: usize) -> Vec<(f32, f32)> {
+    (0..count)
+        .map(|i| {
+            let t = i as f32 / count as f32 * std::f32::consts::TAU;
+            (center.0 + radius * t.cos(), center.1 + radius * t.sin())
+        })
+        .collect()
+}
+
+/// z-component of `(b - a) x (c - a)`.
+#[inline(always)]
+pub(crate) fn cross(a: (f32, f32), b: (f32, f32), c: (f32, f32)) -> f32 {
+    (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)
+}
+
+#[inline(always)]
+pub(crate) fn dist(a: (f32, f32), b: (f32, f32)) -> f32 {
+    ((b.0 - a.0).powi(2) + (b.1 - a.1).powi(2)).sqrt()
+}
+
+fn circle_from_two(a: (f32, f32), b: (f32, f32)) -> ((f32, f32), f32) {
+    let c = ((a.0 + b.0) * 0.5, (a.1 + b.1) * 0.5);
+    (c, dist(a, b) * 0.5)
+}
+
+/// Circumcircle of three points. `None` if collinear.
+pub(crate) fn circle_from_three(
+    a: (f32, f32),
+    b: (f32, f32),
+    c: (f32, f32),
+) -> Option<((f32, f32), f32)> {
+    let (bx, by) = (b.0 - a.0, b.1 - a.1);
+    let (cx, cy) = (c.0 - a.0, c.1 - a.1);
+
+    let d = 2.0 * (bx * cy - by * cx);
+    if d.abs() < f32::EPSILON { return None; }
+
+    let b2 = bx * bx + by * by;
+    let c2 = cx * cx + cy * cy;
+    let ux = (cy * b2 - by * c2) / d;
+    let uy = (bx * c2 - cx * b2) / d;
+
+    let center = (a.0 + ux, a.1 + uy);
+    Some((center, dist(center, a)))
+}
+
+/// Circle spanning the farthest pair among three (collinear) points.
+fn widest_pair(a: (f32, f32), b: (f32, f32), c: (f32, f32)) -> ((f32, f32), f32) {
+    let pairs = [(a, b), (a, c), (b, c)];
+    let (p, q) = pairs
+        .into_iter()
+        .max_by(|x, y| dist(x.0, x.1).total_cmp(&dist(y.0, y.1)))
+        .unwrap_or((a, b));
+    circle_from_two(p, q)
+}
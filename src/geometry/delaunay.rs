@@ -0,0 +1,185 @@
+use crate::Path;
+
+/// Returns the Delaunay triangulation of `points` as closed triangle [`Path`]s.
+///
+/// Uses the Bowyer-Watson algorithm. Non-finite and duplicate points are ignored.
+pub fn delaunay(points: &[(f32, f32)]) -> Vec<Path> {
+    let pts = clean(points);
+
+    triangulate(&pts)
+        .into_iter()
+        .map(|[a, b, c]| Path::new(Vec::from([pts[a], pts[b], pts[c]]), true))
+        .collect()
+}
+
+/// Returns the Voronoi cell of every point in `points` as a closed [`Path`],
+/// clipped to the world coord rectangle `bounds = ((xmin, ymin), (xmax, ymax))`.
+///
+/// Entry `i` is the cell of `points[i]`, or `None` if that point is
+/// non-finite, repeats an earlier point, or its cell misses `bounds`.
+/// Every entry is `None` if `bounds` is empty.
+pub fn voronoi(points: &[(f32, f32)], bounds: ((f32, f32), (f32, f32))) -> Vec<Option<Path>> {
+    let ((xmin, ymin), (xmax, ymax)) = bounds;
+    let mut out: Vec<Option<Path>> = points.iter().map(|_| None).collect();
+    if !(xmin < xmax && ymin < ymax) { return out; }
+
+    let pts = clean(points);
+    let tris = triangulate(&pts);
+
+    // Delaunay neighbors fully determine each cell
+    let mut neighbors: Vec<Vec<usize>> = vec![Vec::new(); pts.len()];
+    for &[a, b, c] in &tris {
+        for (i, j) in [(a, b), (b, c), (c, a)] {
+            if !neighbors[i].contains(&j) { neighbors[i].push(j); }
+            if !neighbors[j].contains(&i) { neighbors[j].push(i); }
+        }
+    }
+
+    let mut cells: Vec<Option<Path>> = Vec::with_capacity(pts.len());
+    for (i, &site) in pts.iter().enumerate() {
+        let mut cell: Vec<(f32, f32)> = Vec::from([
+            (xmin, ymin), (xmax, ymin), (xmax, ymax), (xmin, ymax),
+        ]);
+
+        let others: Vec<usize> = if tris.is_empty() {
+            (0..pts.len()).filter(|&j| j != i).collect()
+        } else {
+            neighbors[i].clone()
+        };
+
+        for j in others {
+            cell = clip_half_plane(&cell, site, pts[j]);
+            if cell.is_empty() { break; }
+        }
+
+        cells.push((cell.len() >= 3).then(|| Path::new(cell, true)));
+    }
+
+    // `clean` keeps the first of each point in order, so walk them in step
+    let mut cells = cells.into_iter();
+    for (k, &p) in points.iter().enumerate() {
+        let first = p.0.is_finite() && p.1.is_finite() && !points[..k].contains(&p);
+        if first {
+            out[k] = cells.next().flatten();
+        }
+    }
+    out
+}
+
+/// Drops non-finite and duplicate points.
+fn clean(points: &[(f32, f32)]) -> Vec<(f32, f32)> {
+    let mut out: Vec<(f32, f32)> = Vec::with_capacity(points.len());
+    for &p in points {
+        if p.0.is_finite() && p.1.is_finite() && !out.contains(&p) {
+            out.push(p);
+        }
+    }
+    out
+}
+
+/// Triangle with its cached circumcircle `(cx, cy, r^2)`.
+struct Tri {
+    v: [usize; 3],
+    circle: (f64, f64, f64),
+}
+
+/// Bowyer-Watson triangulation. Returns index triples into `pts`.
+pub(crate) fn triangulate(pts: &[(f32, f32)]) -> Vec<[usize; 3]> {
+    let n = pts.len();
+    if n < 3 { return Vec::new(); }
+
+    let mut verts: Vec<(f64, f64)> = pts.iter().map(|&(x, y)| (x as f64, y as f64)).collect();
+
+    let (mut xmin, mut ymin) = (f64::INFINITY, f64::INFINITY);
+    let (mut xmax, mut ymax) = (f64::NEG_INFINITY, f64::NEG_INFINITY);
+    for &(x, y) in &verts {
+        xmin = xmin.min(x);
+        ymin = ymin.min(y);
+        xmax = xmax.max(x);
+        ymax = ymax.max(y);
+    }
+
+    // super triangle enclosing every point
+    let d = (xmax - xmin).max(ymax - ymin).max(1.0);
+    let (mx, my) = ((xmin + xmax) * 0.5, (ymin + ymax) * 0.5);
+    verts.push((mx - 20.0 * d, my - d));
+    verts.push((mx, my + 20.0 * d));
+    verts.push((mx + 20.0 * d, my - d));
+
+    let mut tris: Vec<Tri> = Vec::new();
+    if let Some(t) = make_tri(&verts, [n, n + 1, n + 2]) { tris.push(t); }
+
+    let mut edges: Vec<(usize, usize)> = Vec::new();
+    for i in 0..n {
+        let (px, py) = verts[i];
+
+        edges.clear();
+        tris.retain(|t| {
+            let (cx, cy, r2) = t.circle;
+            let inside = (px - cx).powi(2) + (py - cy).powi(2) < r2;
+            if inside {
+                let [a, b, c] = t.v;
+                edges.extend([(a, b), (b, c), (c, a)]);
+            }
+            !inside
+        });
+
+        // boundary of the cavity: edges owned by exactly one bad triangle
+        for k in 0..edges.len() {
+            let (a, b) = edges[k];
+            let shared = edges
+                .iter()
+                .enumerate()
+                .any(|(m, &(c, d))| m != k && ((a == c && b == d) || (a == d && b == c)));
+            if !shared && let Some(t) = make_tri(&verts, [a, b, i]) {
+                tris.push(t);
+            }
+        }
+    }
+
+    tris.into_iter()
+        .map(|t| t.v)
+        .filter(|v| v.iter().all(|&k| k < n))
+        .collect()
+}
+
+fn make_tri(verts: &[(f64, f64)], v: [usize; 3]) -> Option<Tri> {
+    let (ax, ay) = verts[v[0]];
+    let (bx, by) = (verts[v[1]].0 - ax, verts[v[1]].1 - ay);
+    let (cx, cy) = (verts[v[2]].0 - ax, verts[v[2]].1 - ay);
+
+    let d = 2.0 * (bx * cy - by * cx);
+    if d.abs() < 1e-12 { return None; }
+
+    let b2 = bx * bx + by * by;
+    let c2 = cx * cx + cy * cy;
+    let ux = (cy * b2 - by * c2) / d;
+    let uy = (bx * c2 - cx * b2) / d;
+
+    Some(Tri { v, circle: (ax + ux, ay + uy, ux * ux + uy * uy) })
+}
+
+/// Clips a convex polygon to the half-plane of points closer to `site` than `other`.
+fn clip_half_plane(
+    poly: &[(f32, f32)],
+    site: (f32, f32),
+    other: (f32, f32),
+) -> Vec<(f32, f32)> {
+    let (nx, ny) = (other.0 - site.0, other.1 - site.1);
+    let (mx, my) = ((site.0 + other.0) * 0.5, (site.1 + other.1) * 0.5);
+    let side = |p: (f32, f32)| (p.0 - mx) * nx + (p.1 - my) * ny;
+
+    let mut out = Vec::with_capacity(poly.len() + 1);
+    for k in 0..poly.len() {
+        let a = poly[k];
+        let b = poly[(k + 1) % poly.len()];
+        let (sa, sb) = (side(a), side(b));
+
+        if sa <= 0.0 { out.push(a); }
+        if (sa <= 0.0) != (sb <= 0.0) {
+            let t = sa / (sa - sb);
+            out.push((a.0 + t * (b.0 - a.0), a.1 + t * (b.1 - a.1)));
+        }
+    }
+    out
+}
@@ -0,0 +1,15 @@
+//! Geometry utilities over world coord point sets.
+//!
+//! Every function returns closed [`Path`](crate::Path)s ready to be rendered, or
+//! `None` if the input is degenerate.
+
+pub mod bounds;
+pub use bounds::{convex_hull, min_area_rect, bounding_circle};
+
+pub mod bezier;
+pub use bezier::{CubicBezier, fit_beziers, fit_cubics};
+
+#[cfg(feature = "delaunay")]
+pub mod delaunay;
+#[cfg(feature = "delaunay")]
+pub use delaunay::{delaunay, voronoi};
@@ -0,0 +1,141 @@
+//! Rendering of 2D cell grids such as roguelike maps, cellular automata and
+//! mazes.
+//!
+//! Grids are row major `&[u8]` slices of size `cols * rows` where
+//! `dims = (cols, rows)`, first row at the top. Each grid is stretched over a
+//! pixel [`Rect`] with cell edges rounded to whole pixels, so cells tile the
+//! rect without gaps. Everything is written as pixel spans: runs of equal
+//! cells in a row become a single fill per pixel row.
+
+use crate::{Color, Rect, Stage};
+
+/// Wall flag for the top edge of a cell.
+pub const NORTH: u8 = 1;
+/// Wall flag for the right edge of a cell.
+pub const EAST: u8 = 2;
+/// Wall flag for the bottom edge of a cell.
+pub const SOUTH: u8 = 4;
+/// Wall flag for the left edge of a cell.
+pub const WEST: u8 = 8;
+
+/// Fills every cell of `cells` with `palette[value]`, leaving cells whose
+/// value has no palette entry untouched.
+///
+/// Arguments:
+/// - stage: &mut [`Stage`] - stage to draw onto.
+/// - cells: &[[u8]] - row major cell values.
+/// - dims: ([usize], [usize]) - `(cols, rows)` of the grid.
+/// - rect: [`Rect`] - grid extent in pixel coords.
+/// - palette: &[[`Color`]] - color of each cell value.
+pub fn fill_cells(stage: &mut Stage, cells: &[u8], dims: (usize, usize), rect: Rect, palette: &[Color]) {
+    fill_runs(stage, cells, dims, rect, |v| palette.get(v as usize).copied());
+}
+
+/// Draws the walls flagged in `walls`, a row major grid of [`NORTH`],
+/// [`EAST`], [`SOUTH`] and [`WEST`] bit sets, as bands `thickness` pixels
+/// wide centered on the cell edges.
+///
+/// A wall shared by two cells only needs to be flagged in one of them.
+///
+/// Arguments:
+/// - stage: &mut [`Stage`] - stage to draw onto.
+/// - walls: &[[u8]] - row major wall flags.
+/// - dims: ([usize], [usize]) - `(cols, rows)` of the grid.
+/// - rect: [`Rect`] - grid extent in pixel coords.
+/// - thickness: [usize] - wall width in pixels.
+/// - color: [`Color`] - wall color.
+pub fn walls(stage: &mut Stage, walls: &[u8], dims: (usize, usize), rect: Rect, thickness: usize, color: Color) {
+    let (cols, rows) = dims;
+    if cols == 0 || rows == 0 || thickness == 0 || walls.len() < cols * rows { return; }
+
+    let xs = edges(rect.x, rect.width, cols);
+    let ys = edges(rect.y, rect.height, rows);
+    let before = (thickness / 2) as isize;
+    let after = thickness as isize - 1 - before;
+
+    // each edge drawn once across its whole run of flagged cells
+    let hband = |stage: &mut Stage, y: isize, x0: isize, x1: isize| {
+        for py in y - before..=y + after {
+            stage.fill_span_pxl(py, x0 - before, x1 + after, color);
+        }
+    };
+    for row in 0..=rows {
+        let flagged = |c: usize| {
+            (row < rows && walls[row * cols + c] & NORTH != 0)
+                || (row > 0 && walls[(row - 1) * cols + c] & SOUTH != 0)
+        };
+        for (c0, c1) in flag_runs(cols, flagged) {
+            hband(stage, ys[row], xs[c0], xs[c1 + 1]);
+        }
+    }
+
+    for col in 0..=cols {
+        let flagged = |r: usize| {
+            (col < cols && walls[r * cols + col] & WEST != 0)
+                || (col > 0 && walls[r * cols + col - 1] & EAST != 0)
+        };
+        for (r0, r1) in flag_runs(rows, flagged) {
+            for py in ys[r0] - before..=ys[r1 + 1] + after {
+                stage.fill_span_pxl(py, xs[col] - before, xs[col] + after, color);
+            }
+        }
+    }
+}
+
+/// Fills the cells of `cells` with `color_of(value)`, skipping `None`, one
+/// span per run of equal values per pixel row.
+pub(crate) fn fill_runs<T: Copy + PartialEq, F: Fn(T) -> Option<Color>>(
+    stage: &mut Stage,
+    cells: &[T],
+    dims: (usize, usize),
+    rect: Rect,
+    color_of: F,
+) {
+    let (cols, rows) = dims;
+    if cols == 0 || rows == 0 || rect.is_empty() || cells.len() < cols * rows { return; }
+
+    let xs = edges(rect.x, rect.width, cols);
+    let ys = edges(rect.y, rect.height, rows);
+
+    let mut runs: Vec<(isize, isize, Color)> = Vec::with_capacity(cols);
+    for (row, line) in cells.chunks_exact(cols).take(rows).enumerate() {
+        runs.clear();
+        let mut start = 0;
+        for c in 1..=cols {
+            if c < cols && line[c] == line[start] { continue; }
+            if let Some(color) = color_of(line[start]) {
+                runs.push((xs[start], xs[c] - 1, color));
+            }
+            start = c;
+        }
+
+        for y in ys[row]..ys[row + 1] {
+            for &(x0, x1, color) in &runs {
+                stage.fill_span_pxl(y, x0, x1, color);
+            }
+        }
+    }
+}
+
+/// Returns the `count + 1` pixel edges splitting `len` pixels from `start`
+/// into `count` near equal cells.
+fn edges(start: usize, len: usize, count: usize) -> Vec<isize> {
+    (0..=count).map(|i| (start + i * len / count) as isize).collect()
+}
+
+/// Returns the inclusive runs of indices in `0..len` where `flagged` holds.
+fn flag_runs<F: Fn(usize) -> bool>(len: usize, flagged: F) -> Vec<(usize, usize)> {
+    let mut out = Vec::new();
+    let mut start = None;
+    for i in 0..=len {
+        match (i < len && flagged(i), start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                out.push((s, i - 1));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    out
+}
@@ -0,0 +1,151 @@
+//! Deterministic identicons: small symmetric artwork derived from a byte
+//! hash, for avatars or telling build artifacts apart at a glance.
+//!
+//! Any byte string works as input. It is digested first, so a short prefix
+//! of a hash gives a different but equally well-mixed picture.
+
+use crate::noise::hash;
+use crate::{Color, Path, Rect, Stage, Style};
+
+/// Identicon pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IdenticonKind {
+    /// 5x5 grid of blocks mirrored left to right.
+    #[default]
+    Blocks,
+    /// 4x4 grid of squares, triangles, circles and diamonds with four-fold
+    /// rotational symmetry.
+    Shapes,
+}
+
+/// Background behind every identicon.
+const BACKGROUND: [u8; 4] = [240, 240, 240, 255];
+
+/// Returns the foreground color of the identicon of `bytes`: a hue picked by
+/// the hash at fixed saturation and lightness, so every identicon reads well
+/// on the light background.
+pub fn color(bytes: &[u8]) -> Color {
+    let seed = digest(bytes);
+    let hue = (hash(0, -1, seed) % 360) as f32;
+    Color::from_hsl(hue, 0.55, 0.5)
+}
+
+/// Draws the identicon of `bytes` filling the largest centered square of the
+/// drawable area: the whole stage, or the current scissor rect.
+///
+/// Identical `bytes` always give identical artwork.
+///
+/// Arguments:
+/// - stage: &mut [`Stage`] - stage to draw onto.
+/// - bytes: &[[u8]] - hash or any other identifying bytes.
+/// - kind: [`IdenticonKind`] - pattern.
+pub fn identicon(stage: &mut Stage, bytes: &[u8], kind: IdenticonKind) {
+    let r = stage.draw_rect();
+    let side = r.width.min(r.height);
+    if side == 0 { return; }
+    let square = Rect::new(r.x + (r.width - side) / 2, r.y + (r.height - side) / 2, side, side);
+
+    let (x0, x1) = (square.x as isize, square.right() as isize - 1);
+    for y in square.y..square.bottom() {
+        stage.fill_span_pxl(y as isize, x0, x1, Color::new(BACKGROUND));
+    }
+
+    let seed = digest(bytes);
+    let fg = color(bytes);
+    match kind {
+        IdenticonKind::Blocks => blocks(stage, square, seed, fg),
+        IdenticonKind::Shapes => shapes(stage, square, seed, fg),
+    }
+}
+
+/// FNV-1a digest of `bytes`.
+fn digest(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0x811C_9DC5u32, |h, &b| (h ^ b as u32).wrapping_mul(0x0100_0193))
+}
+
+/// Fills a mirrored 5x5 grid of pixel aligned cells with a half cell margin.
+fn blocks(stage: &mut Stage, square: Rect, seed: u32, fg: Color) {
+    const N: usize = 5;
+    let cell = square.width * 2 / (2 * N + 1);
+    if cell == 0 { return; }
+    let margin = (square.width - cell * N) / 2;
+
+    for row in 0..N {
+        for col in 0..N.div_ceil(2) {
+            if hash(col as i32, row as i32, seed) & 1 == 0 { continue; }
+
+            for c in [col, N - 1 - col] {
+                let x = (square.x + margin + c * cell) as isize;
+                let y = square.y + margin + row * cell;
+                for py in y..y + cell {
+                    stage.fill_span_pxl(py as isize, x, x + cell as isize - 1, fg);
+                }
+            }
+        }
+    }
+}
+
+/// Draws a 4x4 grid whose top-left quadrant is picked by the hash and
+/// rotated a quarter turn at a time into the other three.
+fn shapes(stage: &mut Stage, square: Rect, seed: u32, fg: Color) {
+    let side = square.width as f32;
+    let center = stage.pxl_to_world_f32((
+        square.x as f32 + 0.5 * (side - 1.0),
+        square.y as f32 + 0.5 * (side - 1.0),
+    ));
+    let cell = side / 4.0;
+    let style = Style::fill_only(fg);
+
+    // quadrant cell `(i, j)` spans `x in [-2 + i, -1 + i]`, `y in [1 - j, 2 - j]` in cells
+    for j in 0..2 {
+        for i in 0..2 {
+            let h = hash(i, j, seed);
+            let (lx, ty) = ((i - 2) as f32 * cell, (2 - j) as f32 * cell);
+            let quarter = (h >> 4) % 4;
+
+            // shape in the cell's own frame, corners `(0, 0)` to `(1, 1)` with y up
+            let local: Option<Vec<(f32, f32)>> = match h % 5 {
+                0 => None,
+                1 => Some(vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]),
+                2 => Some(turn_cell(&[(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)], quarter)),
+                3 => Some(vec![(0.5, 0.0), (1.0, 0.5), (0.5, 1.0), (0.0, 0.5)]),
+                _ => {
+                    let c = (lx + 0.5 * cell, ty - 0.5 * cell);
+                    for k in 0..4 {
+                        let (x, y) = rotate(c, k);
+                        Path::circle((center.0 + x, center.1 + y), 0.4 * cell).render(stage, style);
+                    }
+                    continue;
+                }
+            };
+            let Some(local) = local else { continue; };
+
+            let nodes: Vec<(f32, f32)> = local.iter().map(|&(u, v)| (lx + u * cell, ty - cell + v * cell)).collect();
+            for k in 0..4 {
+                let turned = nodes.iter().map(|&p| rotate(p, k)).map(|(x, y)| (center.0 + x, center.1 + y)).collect();
+                Path::new(turned, true).render(stage, style);
+            }
+        }
+    }
+}
+
+/// Rotates `(x, y)` counter-clockwise by `k` quarter turns about the origin.
+fn rotate((x, y): (f32, f32), k: u32) -> (f32, f32) {
+    match k % 4 {
+        0 => (x, y),
+        1 => (-y, x),
+        2 => (-x, -y),
+        _ => (y, -x),
+    }
+}
+
+/// Rotates unit cell coords by `k` quarter turns about the cell center.
+fn turn_cell(nodes: &[(f32, f32)], k: u32) -> Vec<(f32, f32)> {
+    nodes
+        .iter()
+        .map(|&(u, v)| {
+            let (x, y) = rotate((u - 0.5, v - 0.5), k);
+            (x + 0.5, y + 0.5)
+        })
+        .collect()
+}
@@ -0,0 +1,94 @@
+//! Sub-stage drawing contexts for multi-panel figures.
+
+use std::ops::{Deref, DerefMut};
+
+use crate::{Color, Rect, Stage};
+
+/// A view of a rectangular region of a [`Stage`] with its own world coords.
+///
+/// Dereferences to the underlying [`Stage`], so every drawing function that
+/// takes `&mut Stage` works unchanged. While the inset is alive, the world
+/// origin sits at the center of its rect and drawing is scissored to it.
+/// Dropping the inset restores the previous origin and scissor.
+///
+/// Plot helpers in [`plot`](crate::plot) stretch their grids over the inset
+/// rather than the whole stage.
+pub struct InsetStage<'a> {
+    stage: &'a mut Stage,
+    rect: Rect,
+    saved_origin: (f32, f32),
+}
+
+impl Stage {
+    /// Returns an [`InsetStage`] drawing into `rect` (pixel coords) of `self`.
+    ///
+    /// The inset rect is intersected with any scissor already in effect, so
+    /// insets can be nested.
+    ///
+    /// Arguments:
+    /// - rect: [`Rect`] - region of `self` to draw into.
+    pub fn inset(&mut self, rect: Rect) -> InsetStage<'_> {
+        let saved_origin = self.origin_pxl();
+        self.push_scissor(rect);
+        self.set_origin_pxl((
+            rect.x as f32 + (rect.width as f32 - 1.0) * 0.5,
+            rect.y as f32 + (rect.height as f32 - 1.0) * 0.5,
+        ));
+        InsetStage { stage: self, rect, saved_origin }
+    }
+}
+
+impl InsetStage<'_> {
+    /// Returns the inset rect in pixel coords of the underlying [`Stage`].
+    pub fn rect(&self) -> Rect {
+        self.rect
+    }
+
+    /// Returns the dimensions `(width, height)` of the inset.
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.rect.width, self.rect.height)
+    }
+
+    /// Returns the world coord corners `((xmin, ymin), (xmax, ymax))` of the
+    /// area covered by the inset. Unlike [`Stage::world_bounds`], the rest of
+    /// the underlying [`Stage`] is excluded.
+    pub fn world_bounds(&self) -> ((f32, f32), (f32, f32)) {
+        let r = self.rect;
+        let (x0, y1) = self.stage.pxl_to_world_f32((r.x as f32 - 0.5, r.y as f32 - 0.5));
+        let (x1, y0) = self.stage.pxl_to_world_f32((r.right() as f32 - 0.5, r.bottom() as f32 - 0.5));
+        ((x0, y0), (x1, y1))
+    }
+
+    /// Fills the visible part of the inset with `color`. Unlike
+    /// [`Stage::clear`], pixels outside the inset are left untouched.
+    pub fn clear(&mut self, color: Color) {
+        let r = self.stage.draw_rect();
+        if r.is_empty() { return; }
+
+        let (x0, x1) = (r.x as isize, r.right() as isize - 1);
+        for y in r.y..r.bottom() {
+            self.stage.replace_span_pxl(y as isize, x0, x1, color);
+        }
+    }
+}
+
+impl Deref for InsetStage<'_> {
+    type Target = Stage;
+
+    fn deref(&self) -> &Stage {
+        self.stage
+    }
+}
+
+impl DerefMut for InsetStage<'_> {
+    fn deref_mut(&mut self) -> &mut Stage {
+        self.stage
+    }
+}
+
+impl Drop for InsetStage<'_> {
+    fn drop(&mut self) {
+        self.stage.pop_scissor();
+        self.stage.set_origin_pxl(self.saved_origin);
+    }
+}
@@ -0,0 +1,95 @@
+//! Grid layouts splitting a region into evenly sized panels.
+
+use crate::Rect;
+
+/// Splits a [`Rect`] into `cols` x `rows` cells separated by `spacing` and
+/// inset from the region edges by `margin`, all in pixels.
+///
+/// Leftover pixels are spread across cells so every cell differs in size by
+/// at most one pixel. Pair with [`Stage::inset`](crate::Stage::inset) to draw
+/// each panel in its own world coords:
+///
+/// ```ignore
+/// let layout = GridLayout::new(2, 2).margin(10).spacing(8);
+/// for cell in layout.cells(stage.bounds()) {
+///     let mut panel = stage.inset(cell);
+///     // draw into panel
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GridLayout {
+    cols: usize,
+    rows: usize,
+    margin: usize,
+    spacing: usize,
+}
+
+impl GridLayout {
+    /// Creates a [`GridLayout`] of `cols` x `rows` cells with no margin or spacing.
+    /// Zero counts are raised to one.
+    pub fn new(cols: usize, rows: usize) -> Self {
+        Self { cols: cols.max(1), rows: rows.max(1), margin: 0, spacing: 0 }
+    }
+
+    /// Sets the gap in pixels between the region edges and the outer cells.
+    pub fn margin(mut self, margin: usize) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Sets the gap in pixels between neighbouring cells.
+    pub fn spacing(mut self, spacing: usize) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Returns the grid dimensions `(cols, rows)`.
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.cols, self.rows)
+    }
+
+    /// Returns the cell at `(col, row)` of `area`, or `None` if it is out of
+    /// range or the margins and spacing leave no room for it.
+    pub fn cell(&self, area: Rect, col: usize, row: usize) -> Option<Rect> {
+        self.span(area, (col, row), (1, 1))
+    }
+
+    /// Returns the region covering `size = (cols, rows)` cells starting at cell
+    /// `start = (col, row)`, including the spacing between them. Useful for
+    /// panels spanning several cells.
+    ///
+    /// Returns `None` if the span leaves the grid, is empty, or has no room.
+    pub fn span(&self, area: Rect, start: (usize, usize), size: (usize, usize)) -> Option<Rect> {
+        let (c0, r0) = start;
+        let (nc, nr) = size;
+        if nc == 0 || nr == 0 { return None; }
+        if c0.checked_add(nc)? > self.cols || r0.checked_add(nr)? > self.rows { return None; }
+
+        let (x0, _) = self.axis(area.x, area.width, self.cols, c0)?;
+        let (_, x1) = self.axis(area.x, area.width, self.cols, c0 + nc - 1)?;
+        let (y0, _) = self.axis(area.y, area.height, self.rows, r0)?;
+        let (_, y1) = self.axis(area.y, area.height, self.rows, r0 + nr - 1)?;
+
+        Some(Rect::new(x0, y0, x1 - x0, y1 - y0))
+    }
+
+    /// Returns every cell of `area` in row major order, skipping cells with
+    /// no room.
+    pub fn cells(&self, area: Rect) -> Vec<Rect> {
+        (0..self.rows)
+            .flat_map(|r| (0..self.cols).map(move |c| (c, r)))
+            .filter_map(|(c, r)| self.cell(area, c, r))
+            .collect()
+    }
+
+    /// Half-open pixel extent `[start, end)` of cell `i` of `n` along an axis
+    /// of `len` pixels starting at `origin`.
+    fn axis(&self, origin: usize, len: usize, n: usize, i: usize) -> Option<(usize, usize)> {
+        let gaps = self.margin.checked_mul(2)?.checked_add(self.spacing.checked_mul(n - 1)?)?;
+        let inner = len.checked_sub(gaps)?;
+
+        let start = origin + self.margin + i * self.spacing + i * inner / n;
+        let end = origin + self.margin + i * self.spacing + (i + 1) * inner / n;
+        (end > start).then_some((start, end))
+    }
+}
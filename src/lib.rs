@@ -1,15 +1,93 @@
 mod stage;
-pub use stage::Stage;
+pub use stage::{BlendMode, Stage};
+
+mod shared;
+pub use shared::SharedStage;
+
+mod fixed;
+pub use fixed::FixedStage;
+
+mod inset;
+pub use inset::InsetStage;
+mod layout;
+pub use layout::GridLayout;
+mod figure;
+pub use figure::{Figure, Margins};
+
+mod stamp;
+pub use stamp::{Stamp, StampLight, Tiling};
+
+mod snapshot;
+pub use snapshot::StageSnapshot;
+mod pattern;
+pub use pattern::Pattern;
+mod outline;
+
+mod resample;
+mod transform;
+pub use transform::Transform;
+mod warp;
+pub use warp::Sampling;
+mod png_stream;
+pub use png_stream::PngStream;
+pub mod pfm;
+mod preview;
+
+mod error;
+pub use error::Error;
 
 mod path; 
+mod clip;
+mod mask;
+pub use mask::Mask;
+mod rect;
+pub use rect::Rect;
 mod primitives;
-pub use path::Path; 
+pub use path::{FillRule, Path, PathBuilder};
+mod cached;
+pub use cached::CachedPath;
+mod scene;
+pub use scene::{ItemStats, ItemStatus, Scene, Viewport};
 
 pub mod shapes; 
+pub mod plot;
+pub mod diagram;
+pub mod grid;
+pub mod sdf;
+pub mod geometry;
+pub mod markers;
+pub mod testing;
+pub mod bench;
+
+mod colormap;
+pub use colormap::Colormap;
+pub mod noise;
+pub mod background;
+pub mod identicon;
+mod theme;
+pub use theme::Theme;
+pub mod turtle;
+pub use turtle::Turtle;
+pub mod wire3d;
+mod trail;
+pub use trail::Trail;
+#[cfg(feature = "rand")]
+pub mod random;
+#[cfg(feature = "ndarray")]
+mod array;
+#[cfg(feature = "ratatui")]
+mod tui;
+
+mod filter;
+mod trace;
+pub use filter::{ColorBlindness, Filter};
 
 mod style; 
 pub use style::Color; 
 pub use style::Style; 
 pub use style::Opacity; 
 pub use style::Fill; 
-pub use style::Stroke; 
+pub use style::{Gradient, Paint};
+pub use style::{LineCap, LineJoin, Stroke};
+pub use style::Quality;
+pub use style::RenderOptions; 
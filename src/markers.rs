@@ -0,0 +1,198 @@
+//! Markers placed along a [`Path`], similar to SVG `marker-start/mid/end`.
+//!
+//! A [`Marker`] is a small shape defined in its own local coords where `+x`
+//! points along the path tangent. It is rotated to the tangent and translated
+//! to each placement before rendering.
+
+use crate::{Stage, Style, Path};
+use crate::shapes::batch::{overlaps_draw_rect, stroke_pad};
+
+const MARKER_CIRCLE_SEGMENTS: usize = 16;
+
+/// A shape stamped along a [`Path`].
+///
+/// Fields:
+/// - path: [`Path`] - outline in local marker coords, `+x` along the tangent.
+/// - style: [`Style`] - style used to render every instance.
+pub struct Marker {
+    path: Path,
+    style: Style,
+}
+
+impl Marker {
+    /// Creates a [`Marker`] from a [`Path`] in local marker coords.
+    ///
+    /// Arguments:
+    /// - path: &[`Path`] - outline, where `+x` points along the tangent.
+    /// - style: [`Style`] - struct containing style args.
+    pub fn from_path(path: &Path, style: Style) -> Self {
+        let path = path.map_nodes(Some).expect("identity maps every node");
+        Self { path, style }
+    }
+
+    /// Creates a circular [`Marker`] of given `radius`.
+    pub fn circle(radius: f32, style: Style) -> Self {
+        let nodes = crate::geometry::bounds::circle_nodes((0.0, 0.0), radius, MARKER_CIRCLE_SEGMENTS);
+        Self { path: Path::new(nodes, true), style }
+    }
+
+    /// Creates a square [`Marker`] of given `side_length`.
+    pub fn square(side_length: f32, style: Style) -> Self {
+        let h = side_length * 0.5;
+        let nodes = Vec::from([(-h, h), (h, h), (h, -h), (-h, -h)]);
+        Self { path: Path::new(nodes, true), style }
+    }
+
+    /// Creates a triangular [`Marker`] of given `size` pointing along the tangent.
+    pub fn triangle(size: f32, style: Style) -> Self {
+        let h = size * 0.5;
+        let nodes = Vec::from([(h, 0.0), (-h, h), (-h, -h)]);
+        Self { path: Path::new(nodes, true), style }
+    }
+
+    /// Renders `self` at world coord `position` rotated by `angle` radians.
+    pub fn render_at(&self, stage: &mut Stage, position: (f32, f32), angle: f32) {
+        if !angle.is_finite() { return; }
+
+        let (sin, cos) = angle.sin_cos();
+        let placed = self
+            .path
+            .map_nodes(|(x, y)| Some((position.0 + x * cos - y * sin, position.1 + x * sin + y * cos)));
+        if let Some(placed) = placed {
+            placed.render(stage, self.style);
+        }
+    }
+
+    /// Renders `self` unrotated at every world coord in `positions`.
+    ///
+    /// Reuses one pixel buffer for all placements and skips those entirely
+    /// outside the drawable area, so stamping thousands of markers avoids the
+    /// per-call overhead of [`Marker::render_at`].
+    pub fn render_batch(&self, stage: &mut Stage, positions: &[(f32, f32)]) {
+        if !self.style.fill_or_stroke_exists() { return; }
+        let Some(((x0, y0), (x1, y1))) = self.path.bounds() else { return; };
+
+        let reach = x0.abs().max(x1.abs()).max(y0.abs()).max(y1.abs());
+        let half = reach.ceil() as isize + 1 + stroke_pad(self.style);
+
+        // several subpaths are filled together, so they take the full path
+        let single = self.path.subpaths().count() == 1;
+        let (nodes, closed) = (self.path.nodes(), self.path.is_closed());
+
+        let mut nodes_px = Vec::with_capacity(nodes.len());
+        for &(px, py) in positions {
+            let Some(center) = stage.world_to_pxl((px, py)) else { continue; };
+            if !overlaps_draw_rect(stage, center, (half, half)) { continue; }
+
+            if !single {
+                if let Some(placed) = self.path.map_nodes(|(x, y)| Some((px + x, py + y))) {
+                    placed.render(stage, self.style);
+                }
+                continue;
+            }
+
+            nodes_px.clear();
+            for &(x, y) in nodes {
+                let Some(p) = stage.world_to_pxl((px + x, py + y)) else { break; };
+                nodes_px.push(p);
+            }
+            if nodes_px.len() == nodes.len() {
+                Path::render_pxl(&nodes_px, closed, stage, self.style);
+            }
+        }
+    }
+}
+
+/// Renders `marker` at every vertex of `path`, aligned to the bisector of the
+/// incoming and outgoing tangents.
+///
+/// Arguments:
+/// - stage: &mut [`Stage`] - stage to draw onto.
+/// - path: &[`Path`] - path to decorate.
+/// - marker: &[`Marker`] - marker to place.
+pub fn at_vertices(stage: &mut Stage, path: &Path, marker: &Marker) {
+    for (nodes, closed) in path.subpaths() {
+        at_subpath_vertices(stage, nodes, closed, marker);
+    }
+}
+
+/// Renders `marker` at every vertex of one subpath of [`at_vertices`].
+fn at_subpath_vertices(stage: &mut Stage, nodes: &[(f32, f32)], closed: bool, marker: &Marker) {
+    let n = nodes.len();
+    if n == 0 { return; }
+    if n == 1 {
+        marker.render_at(stage, nodes[0], 0.0);
+        return;
+    }
+
+    let dir = |a: (f32, f32), b: (f32, f32)| {
+        let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+        let len = (dx * dx + dy * dy).sqrt();
+        if len == 0.0 { (0.0, 0.0) } else { (dx / len, dy / len) }
+    };
+
+    for i in 0..n {
+        let incoming = if i > 0 {
+            Some(dir(nodes[i - 1], nodes[i]))
+        } else if closed {
+            Some(dir(nodes[n - 1], nodes[0]))
+        } else {
+            None
+        };
+
+        let outgoing = if i + 1 < n {
+            Some(dir(nodes[i], nodes[i + 1]))
+        } else if closed {
+            Some(dir(nodes[n - 1], nodes[0]))
+        } else {
+            None
+        };
+
+        let (tx, ty) = match (incoming, outgoing) {
+            (Some(a), Some(b)) => (a.0 + b.0, a.1 + b.1),
+            (Some(a), None) | (None, Some(a)) => a,
+            (None, None) => (1.0, 0.0),
+        };
+
+        marker.render_at(stage, nodes[i], ty.atan2(tx));
+    }
+}
+
+/// Largest number of markers placed by one [`at_intervals`] call.
+pub const MAX_INTERVAL_MARKERS: usize = 1 << 16;
+
+/// Renders `marker` every `spacing` world units along `path`, starting at `offset`,
+/// aligned to the local tangent.
+///
+/// At most [`MAX_INTERVAL_MARKERS`] markers are placed; spacings finer than
+/// that allows stop short of the end of `path`.
+///
+/// Arguments:
+/// - stage: &mut [`Stage`] - stage to draw onto.
+/// - path: &[`Path`] - path to decorate.
+/// - spacing: [f32] - distance between consecutive markers in world units.
+/// - offset: [f32] - distance of the first marker from the start of `path`.
+/// - marker: &[`Marker`] - marker to place.
+pub fn at_intervals(
+    stage: &mut Stage,
+    path: &Path,
+    spacing: f32,
+    offset: f32,
+    marker: &Marker,
+) {
+    if !spacing.is_finite() || spacing <= 0.0 { return; }
+    if !offset.is_finite() || offset < 0.0 { return; }
+
+    let length = path.length();
+    if offset > length { return; }
+
+    // distances are computed per marker, so rounding does not accumulate
+    let steps = ((length - offset) as f64 / spacing as f64).floor() as usize;
+    let count = steps.saturating_add(1).min(MAX_INTERVAL_MARKERS);
+    for k in 0..count {
+        let d = (offset + k as f32 * spacing).min(length);
+        if let Some((position, angle)) = path.point_at_distance(d) {
+            marker.render_at(stage, position, angle);
+        }
+    }
+}
@@ -0,0 +1,215 @@
+//! Single-channel coverage targets.
+
+use std::path::Path as FsPath;
+
+use image::{ColorType, ImageFormat, ImageResult};
+
+use crate::{Color, Path, RenderOptions, Stage, Style};
+use crate::clip::ClipMask;
+
+/// An 8-bit coverage-only render target sharing the world coords of a
+/// [`Stage`] of the same size.
+///
+/// Shapes rendered into a [`Mask`] accumulate coverage as the union of what
+/// they touch. A mask can then restrict drawing with [`Stage::set_clip_mask`],
+/// be softened with [`Mask::blur`] as a shadow source, or be colorized and
+/// composited with [`Stage::fill_mask`].
+///
+/// Fields:
+/// - width, height: [usize] - mask dimensions.
+/// - data: Vec<[u8]> - row major coverage, `0` empty to `255` full.
+/// - origin: ([f32], [f32]) - pixel position of the world origin.
+#[derive(Debug, Clone)]
+pub struct Mask {
+    width: usize,
+    height: usize,
+    data: Vec<u8>,
+    origin: (f32, f32),
+}
+
+impl Mask {
+    /// Creates an empty `width` x `height` [`Mask`] with the world origin at
+    /// its center, matching [`Stage::new`].
+    pub fn new(width: usize, height: usize) -> Self {
+        let origin = ((width as f32 - 1.0) * 0.5, (height as f32 - 1.0) * 0.5);
+        Self { width, height, data: vec![0; width * height], origin }
+    }
+
+    /// Creates a [`Mask`] with the same size and world coords as `stage`.
+    pub fn for_stage(stage: &Stage) -> Self {
+        let (w, h) = stage.dimensions();
+        Self { width: w, height: h, data: vec![0; w * h], origin: stage.origin_pxl() }
+    }
+
+    /// Creates a [`Mask`] from the alpha channel of `stage`.
+    pub fn from_alpha(stage: &Stage) -> Self {
+        let mut mask = Self::for_stage(stage);
+        for y in 0..mask.height {
+            let row = &mut mask.data[y * mask.width..(y + 1) * mask.width];
+            for (c, px) in row.iter_mut().zip(stage.row(y)) {
+                *c = px[3];
+            }
+        }
+        mask
+    }
+
+    /// Returns the dimensions `(width, height)`.
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    /// Returns the row major coverage values.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Saves a [`Mask`] as an 8-bit grayscale `png`, coverage as brightness.
+    /// Load it back with [`Stage::load_mask`].
+    pub fn save_png<P: AsRef<FsPath>>(&self, path: P) -> ImageResult<()> {
+        save_gray_png(path, (self.width, self.height), &self.data)
+    }
+
+    /// Returns the coverage at pixel `(x, y)`, or `None` if out of bounds.
+    pub fn get(&self, x: usize, y: usize) -> Option<u8> {
+        (x < self.width && y < self.height).then(|| self.data[y * self.width + x])
+    }
+
+    /// Sets every pixel to `coverage`.
+    pub fn fill(&mut self, coverage: u8) {
+        self.data.fill(coverage);
+    }
+
+    /// Replaces each coverage value `c` with `255 - c`.
+    pub fn invert(&mut self) {
+        for c in &mut self.data {
+            *c = 255 - *c;
+        }
+    }
+
+    /// Adds the coverage of `path` drawn with `style`. Only the shape matters;
+    /// colors are ignored, though their alpha scales the coverage.
+    pub fn render(&mut self, path: &Path, style: Style) {
+        self.render_with(path, style, RenderOptions::default());
+    }
+
+    /// Adds the coverage of `path` drawn with `style` and per-draw `options`,
+    /// e.g. [`RenderOptions::SMOOTH`] for anti-aliased coverage.
+    pub fn render_with(&mut self, path: &Path, style: Style, options: RenderOptions) {
+        let mut scratch = Stage::new(self.width, self.height);
+        scratch.set_origin_pxl(self.origin);
+        path.render_with(&mut scratch, style, options);
+
+        for (c, px) in self.data.iter_mut().zip(scratch.pixels()) {
+            *c = (*c).max(px[3]);
+        }
+    }
+
+    /// Blurs `self` in place with three passes of a box blur of `radius`
+    /// pixels, approximating a gaussian. Useful for soft shadows.
+    pub fn blur(&mut self, radius: usize) {
+        if radius == 0 || self.data.is_empty() { return; }
+
+        let (w, h) = (self.width, self.height);
+        let mut tmp = vec![0u8; self.data.len()];
+        for _ in 0..3 {
+            box_blur_pass(&self.data, &mut tmp, w, h, radius, (1, w));
+            box_blur_pass(&tmp, &mut self.data, h, w, radius, (w, 1));
+        }
+    }
+}
+
+/// One 1D box blur along lines of `len` samples, `count` lines, where
+/// `stride = (step, line_step)` gives the index step along and between lines.
+fn box_blur_pass(src: &[u8], dst: &mut [u8], len: usize, count: usize, radius: usize, stride: (usize, usize)) {
+    let (step, line_step) = stride;
+    let window = (2 * radius + 1) as u32;
+
+    for line in 0..count {
+        let base = line * line_step;
+        let at = |i: isize| src[base + i.clamp(0, len as isize - 1) as usize * step] as u32;
+
+        let r = radius as isize;
+        let mut sum: u32 = (-r..=r).map(at).sum();
+        for i in 0..len as isize {
+            dst[base + i as usize * step] = ((sum + window / 2) / window) as u8;
+            sum += at(i + r + 1);
+            sum -= at(i - r);
+        }
+    }
+}
+
+impl Stage {
+    /// Saves the alpha channel of a [`Stage`] as an 8-bit grayscale `png`,
+    /// e.g. to edit a mask externally and load it back with [`Stage::load_mask`].
+    pub fn save_alpha_png<P: AsRef<FsPath>>(&self, path: P) -> ImageResult<()> {
+        let (w, h) = self.dimensions();
+        let alpha: Vec<u8> = (0..h).flat_map(|y| self.row(y).iter().map(|px| px[3])).collect();
+        save_gray_png(path, (w, h), &alpha)
+    }
+
+    /// Loads the image at `path` as a [`Mask`] in the world coords of `self`.
+    ///
+    /// Coverage is the alpha channel of images that have one, otherwise their
+    /// luminance, so both [`Stage::save_alpha_png`] output and grayscale masks
+    /// painted elsewhere load as expected. The mask takes the image size, which
+    /// should match `self` for [`Stage::set_clip_mask`].
+    ///
+    /// Arguments:
+    /// - path: image file to load.
+    pub fn load_mask<P: AsRef<FsPath>>(&self, path: P) -> ImageResult<Mask> {
+        let img = image::open(path)?;
+        let (width, height) = (img.width() as usize, img.height() as usize);
+        let data = if img.color().has_alpha() {
+            img.to_rgba8().pixels().map(|px| px.0[3]).collect()
+        } else {
+            img.to_luma8().into_raw()
+        };
+        Ok(Mask { width, height, data, origin: self.origin_pxl() })
+    }
+
+    /// Restricts all subsequent drawing to pixels where `mask` has coverage,
+    /// intersected with any clip already active.
+    ///
+    /// `mask` is aligned with the top-left corner of `self`. Pixels of `self`
+    /// that a smaller mask does not reach are clipped away, and the part of
+    /// a larger mask past the stage is ignored.
+    pub fn set_clip_mask(&mut self, mask: &Mask) {
+        let (width, height) = self.dimensions();
+        if mask.dimensions() == (width, height) {
+            let clip = ClipMask::from_coverage(&mask.data, width, height, 1);
+            return self.intersect_clip(clip);
+        }
+
+        let mut coverage = vec![0u8; width * height];
+        let w = width.min(mask.width);
+        for y in 0..height.min(mask.height) {
+            let src = &mask.data[y * mask.width..y * mask.width + w];
+            coverage[y * width..y * width + w].copy_from_slice(src);
+        }
+        self.intersect_clip(ClipMask::from_coverage(&coverage, width, height, 1));
+    }
+
+    /// Composites `color` over `self` wherever `mask` has coverage, scaled by
+    /// that coverage, with the mask shifted by `offset` pixels. Pass a blurred
+    /// mask and a small offset for a drop shadow.
+    ///
+    /// Arguments:
+    /// - mask: &[`Mask`] - coverage to colorize.
+    /// - color: [`Color`] - color to composite.
+    /// - offset: ([isize], [isize]) - pixel shift applied to `mask`.
+    pub fn fill_mask(&mut self, mask: &Mask, color: Color, offset: (isize, isize)) {
+        for y in 0..mask.height {
+            for x in 0..mask.width {
+                let c = mask.data[y * mask.width + x];
+                if c == 0 { continue; }
+                let (px, py) = (x as isize + offset.0, y as isize + offset.1);
+                self.blend_pxl(px, py, color, c as f32 / 255.0);
+            }
+        }
+    }
+}
+
+/// Saves row major 8-bit samples as a grayscale `png`.
+fn save_gray_png<P: AsRef<FsPath>>(path: P, (w, h): (usize, usize), data: &[u8]) -> ImageResult<()> {
+    image::save_buffer_with_format(path, data, w as u32, h as u32, ColorType::L8, ImageFormat::Png)
+}
@@ -0,0 +1,175 @@
+//! Procedural noise generators for backgrounds and textures.
+//!
+//! A [`Noise`] is sampled at world coords and returns values in `[0.0, 1.0]`,
+//! which are mapped to colors through a [`Colormap`].
+
+use crate::{Colormap, Rect, Stage};
+
+/// Base noise function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoiseKind {
+    /// Interpolated random values on the integer lattice.
+    Value,
+    /// Gradient noise on the integer lattice.
+    Perlin,
+    /// Gradient noise on a triangular lattice, fewer directional artifacts.
+    Simplex,
+}
+
+/// Configurable noise source. Octaves above one produce fractal Brownian motion (fBm).
+///
+/// Fields:
+/// - kind: [`NoiseKind`] - base noise function.
+/// - seed: [u32] - seed, identical seeds give identical output.
+/// - scale: [f32] - world units per noise cycle at the first octave.
+/// - octaves: [u32] - number of summed layers.
+/// - lacunarity: [f32] - frequency multiplier between octaves.
+/// - gain: [f32] - amplitude multiplier between octaves.
+#[derive(Debug, Clone, Copy)]
+pub struct Noise {
+    pub kind: NoiseKind,
+    pub seed: u32,
+    pub scale: f32,
+    pub octaves: u32,
+    pub lacunarity: f32,
+    pub gain: f32,
+}
+
+impl Noise {
+    /// Creates a single-octave [`Noise`] with a scale of 64 world units.
+    pub fn new(kind: NoiseKind, seed: u32) -> Self {
+        Self { kind, seed, scale: 64.0, octaves: 1, lacunarity: 2.0, gain: 0.5 }
+    }
+
+    /// Creates an fBm [`Noise`] summing `octaves` layers of `kind`.
+    pub fn fbm(kind: NoiseKind, seed: u32, octaves: u32) -> Self {
+        Self { octaves, ..Self::new(kind, seed) }
+    }
+
+    /// Samples `self` at world coord `(x, y)`. Returns a value in `[0.0, 1.0]`.
+    pub fn sample(&self, (x, y): (f32, f32)) -> f32 {
+        let scale = if self.scale.is_finite() && self.scale > 0.0 { self.scale } else { 1.0 };
+        let (mut fx, mut fy) = (x / scale, y / scale);
+
+        let mut amp = 1.0;
+        let mut sum = 0.0;
+        let mut norm = 0.0;
+        for octave in 0..self.octaves.max(1) {
+            let seed = self.seed.wrapping_add(octave.wrapping_mul(0x9E37_79B9));
+            let n = match self.kind {
+                NoiseKind::Value => value(fx, fy, seed),
+                NoiseKind::Perlin => perlin(fx, fy, seed),
+                NoiseKind::Simplex => simplex(fx, fy, seed),
+            };
+
+            sum += amp * n;
+            norm += amp;
+            amp *= self.gain;
+            fx *= self.lacunarity;
+            fy *= self.lacunarity;
+        }
+
+        ((sum / norm) * 0.5 + 0.5).clamp(0.0, 1.0)
+    }
+}
+
+/// Fills the whole `stage` with `noise` mapped through `colormap`.
+pub fn fill(stage: &mut Stage, noise: &Noise, colormap: &Colormap) {
+    fill_region(stage, stage.bounds(), noise, colormap);
+}
+
+/// Fills `rect` (pixel coords) of `stage` with `noise` mapped through `colormap`.
+/// Noise is sampled at world coords so adjacent regions tile seamlessly.
+pub fn fill_region(stage: &mut Stage, rect: Rect, noise: &Noise, colormap: &Colormap) {
+    let Some(rect) = rect.intersect(stage.bounds()) else { return; };
+
+    for y in rect.y..rect.bottom() {
+        let py = y as isize;
+        let (wx0, wy) = stage.pxl_to_world((0, py));
+        stage.fill_span_with(py, rect.x as isize, rect.right() as isize - 1, |x| {
+            colormap.sample(noise.sample((wx0 + x as f32, wy)))
+        });
+    }
+}
+
+/// Integer lattice hash.
+#[inline(always)]
+pub(crate) fn hash(x: i32, y: i32, seed: u32) -> u32 {
+    let mut h = seed ^ (x as u32).wrapping_mul(0x27D4_EB2D) ^ (y as u32).wrapping_mul(0x1656_67B1);
+    h ^= h >> 15;
+    h = h.wrapping_mul(0x85EB_CA6B);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xC2B2_AE35);
+    h ^ (h >> 16)
+}
+
+#[inline(always)]
+fn fade(t: f32) -> f32 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+#[inline(always)]
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+/// Value noise in `[-1, 1]`.
+fn value(x: f32, y: f32, seed: u32) -> f32 {
+    let (x0, y0) = (x.floor(), y.floor());
+    let (ix, iy) = (x0 as i32, y0 as i32);
+    let (u, v) = (fade(x - x0), fade(y - y0));
+
+    let r = |i: i32, j: i32| hash(ix.wrapping_add(i), iy.wrapping_add(j), seed) as f32 / u32::MAX as f32 * 2.0 - 1.0;
+    lerp(lerp(r(0, 0), r(1, 0), u), lerp(r(0, 1), r(1, 1), u), v)
+}
+
+/// Dot product of a hashed lattice gradient with `(dx, dy)`.
+#[inline(always)]
+fn grad(h: u32, dx: f32, dy: f32) -> f32 {
+    match h & 7 {
+        0 => dx + dy,
+        1 => dx - dy,
+        2 => -dx + dy,
+        3 => -dx - dy,
+        4 => dx,
+        5 => -dx,
+        6 => dy,
+        _ => -dy,
+    }
+}
+
+/// Perlin noise in roughly `[-1, 1]`.
+fn perlin(x: f32, y: f32, seed: u32) -> f32 {
+    let (x0, y0) = (x.floor(), y.floor());
+    let (ix, iy) = (x0 as i32, y0 as i32);
+    let (fx, fy) = (x - x0, y - y0);
+    let (u, v) = (fade(fx), fade(fy));
+
+    let g = |i: i32, j: i32| grad(hash(ix.wrapping_add(i), iy.wrapping_add(j), seed), fx - i as f32, fy - j as f32);
+    let n = lerp(lerp(g(0, 0), g(1, 0), u), lerp(g(0, 1), g(1, 1), u), v);
+    (n * std::f32::consts::FRAC_1_SQRT_2 * 1.4).clamp(-1.0, 1.0)
+}
+
+/// 2D simplex noise in roughly `[-1, 1]`.
+fn simplex(x: f32, y: f32, seed: u32) -> f32 {
+    const F2: f32 = 0.366_025_42; // (sqrt(3) - 1) / 2
+    const G2: f32 = 0.211_324_87; // (3 - sqrt(3)) / 6
+
+    let s = (x + y) * F2;
+    let (i, j) = ((x + s).floor(), (y + s).floor());
+    let t = (i + j) * G2;
+    let (x0, y0) = (x - (i - t), y - (j - t));
+
+    let (i1, j1) = if x0 > y0 { (1, 0) } else { (0, 1) };
+    let (x1, y1) = (x0 - i1 as f32 + G2, y0 - j1 as f32 + G2);
+    let (x2, y2) = (x0 - 1.0 + 2.0 * G2, y0 - 1.0 + 2.0 * G2);
+
+    let (ii, jj) = (i as i32, j as i32);
+    let corner = |dx: f32, dy: f32, ci: i32, cj: i32| {
+        let t = 0.5 - dx * dx - dy * dy;
+        if t < 0.0 { 0.0 } else { t.powi(4) * grad(hash(ii.wrapping_add(ci), jj.wrapping_add(cj), seed), dx, dy) }
+    };
+
+    let n = corner(x0, y0, 0, 0) + corner(x1, y1, i1, j1) + corner(x2, y2, 1, 1);
+    (70.0 * n).clamp(-1.0, 1.0)
+}
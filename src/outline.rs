@@ -0,0 +1,260 @@
+//! Conversion of strokes into fillable outlines.
+
+use std::f32::consts::PI;
+
+use crate::{FillRule, LineCap, LineJoin, Path, PathBuilder};
+
+type Point = (f32, f32);
+
+/// Longest miter allowed, in half stroke widths, before it is beveled.
+const MITER_LIMIT: f32 = 4.0;
+
+impl Path {
+    /// Returns the closed outline of the stroke of `self` `width` world units
+    /// wide, with `cap` on the ends of open subpaths and `join` at corners.
+    ///
+    /// Filling the outline covers what stroking `self` would, so it can be
+    /// transformed, clipped, or filled with a gradient. An open subpath gives
+    /// one contour and a closed one gives an outer and an inner contour. The
+    /// contours all wind the same way around the stroke and may loop over
+    /// themselves at sharp bends, so the outline is filled under
+    /// [`FillRule::NonZero`]; keep that rule when rebuilding it from its
+    /// nodes. Non-finite or non-positive `width` gives an empty path.
+    ///
+    /// Arguments:
+    /// - width: [f32] - stroke width in world units.
+    /// - cap: [`LineCap`] - shape of open ends.
+    /// - join: [`LineJoin`] - shape of corners.
+    pub fn stroke_outline(&self, width: f32, cap: LineCap, join: LineJoin) -> Path {
+        let mut builder = PathBuilder::new();
+        if !width.is_finite() || width <= 0.0 { return builder.build().with_fill_rule(FillRule::NonZero); }
+        let hw = 0.5 * width;
+
+        for (nodes, closed) in self.subpaths() {
+            let mut nodes: Vec<Point> = nodes.iter().copied().filter(|p| p.0.is_finite() && p.1.is_finite()).collect();
+            nodes.dedup();
+            if closed && nodes.len() > 1 && nodes.first() == nodes.last() { nodes.pop(); }
+
+            let contours = match nodes.len() {
+                0 => Vec::new(),
+                1 => dot_outline(nodes[0], hw, cap).into_iter().collect(),
+                // a closed pair of nodes strokes like its quads, squared off
+                2 if closed => vec![open_outline(&nodes, hw, LineCap::Square, join)],
+                _ if closed => closed_outline(&nodes, hw, join),
+                _ => vec![open_outline(&nodes, hw, cap, join)],
+            };
+            for contour in contours {
+                builder = add_contour(builder, &contour);
+            }
+        }
+        builder.build().with_fill_rule(FillRule::NonZero)
+    }
+}
+
+/// Appends `contour` to `builder` as a closed subpath.
+fn add_contour(builder: PathBuilder, contour: &[Point]) -> PathBuilder {
+    let Some((&first, rest)) = contour.split_first() else { return builder; };
+    rest.iter().fold(builder.move_to(first), |b, &p| b.line_to(p)).close()
+}
+
+/// Returns the outline of a zero length stroke at `p`: a disc for round
+/// caps, a square for square caps and nothing for butt caps. Like every
+/// contour here it runs clockwise.
+fn dot_outline(p: Point, hw: f32, cap: LineCap) -> Option<Vec<Point>> {
+    match cap {
+        LineCap::Butt => None,
+        LineCap::Square => Some(vec![(p.0 - hw, p.1 - hw), (p.0 - hw, p.1 + hw), (p.0 + hw, p.1 + hw), (p.0 + hw, p.1 - hw)]),
+        LineCap::Round => {
+            let mut out = vec![(p.0 + hw, p.1)];
+            arc(&mut out, p, hw, 0.0, -2.0 * PI);
+            out.pop();
+            Some(out)
+        }
+    }
+}
+
+/// Returns the single contour around the open polyline `nodes`: its left
+/// side forward, the end cap, its right side backward and the start cap,
+/// which runs clockwise around every segment.
+fn open_outline(nodes: &[Point], hw: f32, cap: LineCap, join: LineJoin) -> Vec<Point> {
+    let n = nodes.len();
+    let mut left = side(nodes, false, hw, join, 1.0);
+    let mut right = side(nodes, false, hw, join, -1.0);
+    right.reverse();
+
+    let (d_end, d_start) = (direction(nodes[n - 2], nodes[n - 1]), direction(nodes[0], nodes[1]));
+    cap_to(&mut left, nodes[n - 1], d_end, hw, cap);
+    left.append(&mut right);
+    cap_to(&mut left, nodes[0], (-d_start.0, -d_start.1), hw, cap);
+    left
+}
+
+/// Returns the left side forward and the right side backward around the
+/// closed polygon `nodes`, winding clockwise around the stroke like
+/// [`open_outline`].
+fn closed_outline(nodes: &[Point], hw: f32, join: LineJoin) -> Vec<Vec<Point>> {
+    let mut right = side(nodes, true, hw, join, -1.0);
+    right.reverse();
+    vec![side(nodes, true, hw, join, 1.0), right]
+}
+
+/// Returns the edge of the stroke of `nodes` offset `hw` to the left for
+/// `sign = 1` or to the right for `sign = -1`, with joins at interior nodes
+/// and every node if `closed`.
+fn side(nodes: &[Point], closed: bool, hw: f32, join: LineJoin, sign: f32) -> Vec<Point> {
+    let n = nodes.len();
+    let segments = if closed { n } else { n - 1 };
+    let seg = |i: usize| (nodes[i % n], nodes[(i + 1) % n]);
+    let offset = |p: Point, d: Point| (p.0 - sign * d.1 * hw, p.1 + sign * d.0 * hw);
+
+    let mut out = Vec::new();
+    if !closed {
+        let (a, b) = seg(0);
+        out.push(offset(a, direction(a, b)));
+    }
+    for i in 0..segments {
+        let (a, b) = seg(i);
+        let d = direction(a, b);
+        if closed || i + 1 < segments {
+            let (c, e) = seg(i + 1);
+            let reach = (b.0 - a.0).hypot(b.1 - a.1).min((e.0 - c.0).hypot(e.1 - c.1));
+            join_to(&mut out, b, (d, direction(c, e)), reach, hw, join, sign);
+        } else {
+            out.push(offset(b, d));
+        }
+    }
+    out
+}
+
+/// Appends the corner at `p` between the incoming direction `d0` and the
+/// outgoing `d1` on the side `sign`, where `reach` is the length of the
+/// shorter of the two segments.
+fn join_to(out: &mut Vec<Point>, p: Point, (d0, d1): (Point, Point), reach: f32, hw: f32, join: LineJoin, sign: f32) {
+    let n0 = (-sign * d0.1, sign * d0.0);
+    let n1 = (-sign * d1.1, sign * d1.0);
+    let (a, b) = ((p.0 + n0.0 * hw, p.1 + n0.1 * hw), (p.0 + n1.0 * hw, p.1 + n1.1 * hw));
+    let cross = d0.0 * d1.1 - d0.1 * d1.0;
+    let cos = d0.0 * d1.0 + d0.1 * d1.1;
+
+    // offset edges meet where the two normals' sum reaches the edge line
+    let miter = |k: f32| (p.0 + (n0.0 + n1.0) * hw / k, p.1 + (n0.1 + n1.1) * hw / k);
+
+    if cross.abs() <= 1e-6 && cos > 0.0 {
+        out.push(a);
+        return;
+    }
+
+    if sign * cross > 0.0 {
+        // inside of the bend: cut at the crossing of the edges if it lies on
+        // both segments, else pivot through the node
+        let back = hw * cross.abs() / (1.0 + cos);
+        if back <= reach {
+            out.push(miter(1.0 + cos));
+        } else {
+            out.extend([a, p, b]);
+        }
+        return;
+    }
+
+    match join {
+        LineJoin::Bevel => out.extend([a, b]),
+        LineJoin::Miter if (2.0 / (1.0 + cos)).sqrt() <= MITER_LIMIT => out.push(miter(1.0 + cos)),
+        LineJoin::Miter => out.extend([a, b]),
+        LineJoin::Round => {
+            out.push(a);
+            let start = n0.1.atan2(n0.0);
+            let sweep = (n0.0 * n1.1 - n0.1 * n1.0).atan2(n0.0 * n1.0 + n0.1 * n1.1);
+            arc(out, p, hw, start, sweep);
+        }
+    }
+}
+
+/// Appends the cap at the end `p` of a stroke heading along `d`, going from
+/// its left edge around to its right edge.
+fn cap_to(out: &mut Vec<Point>, p: Point, d: Point, hw: f32, cap: LineCap) {
+    let left = (p.0 - d.1 * hw, p.1 + d.0 * hw);
+    let right = (p.0 + d.1 * hw, p.1 - d.0 * hw);
+    match cap {
+        LineCap::Butt => {}
+        LineCap::Square => out.extend([(left.0 + d.0 * hw, left.1 + d.1 * hw), (right.0 + d.0 * hw, right.1 + d.1 * hw)]),
+        LineCap::Round => {
+            arc(out, p, hw, d.0.atan2(-d.1), -PI);
+            out.pop();
+        }
+    }
+}
+
+/// Appends points along the arc of radius `r` around `c` from angle `start`
+/// sweeping `sweep` radians, excluding the start point.
+fn arc(out: &mut Vec<Point>, c: Point, r: f32, start: f32, sweep: f32) {
+    // same density as the circle nodes of Path::ellipse
+    let full = (PI * r).ceil().clamp(16.0, 512.0);
+    let steps = (full * sweep.abs() / (2.0 * PI)).ceil().max(1.0) as usize;
+    for k in 1..=steps {
+        let t = start + sweep * k as f32 / steps as f32;
+        out.push((c.0 + r * t.cos(), c.1 + r * t.sin()));
+    }
+}
+
+/// Returns the unit direction from `a` to distinct `b`.
+fn direction(a: Point, b: Point) -> Point {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let len = dx.hypot(dy);
+    (dx / len, dy / len)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Color, LineCap, LineJoin, Path, Stage, Style};
+
+    const SIZE: usize = 160;
+
+    /// Returns which pixels of a fresh stage `path` paints with `style`.
+    fn coverage(path: &Path, style: Style) -> Vec<bool> {
+        let mut stage = Stage::new(SIZE, SIZE);
+        path.render(&mut stage, style);
+        stage.pixels().iter().map(|px| px[3] != 0).collect()
+    }
+
+    /// Counts pixels set in `a` with no pixel set in `b` within one pixel,
+    /// which tolerates the half pixel edge bias between the two rasterizers.
+    fn far_from(a: &[bool], b: &[bool]) -> usize {
+        let n = SIZE as isize;
+        let near = |x: isize, y: isize| {
+            (-1..=1).any(|dy| (-1..=1).any(|dx| {
+                let (u, v) = (x + dx, y + dy);
+                (0..n).contains(&u) && (0..n).contains(&v) && b[(v * n + u) as usize]
+            }))
+        };
+        (0..a.len()).filter(|&i| a[i] && !near(i as isize % n, i as isize / n)).count()
+    }
+
+    #[test]
+    fn filled_outline_matches_stroke() {
+        let cases = [
+            // hairpin whose inner edges overlap
+            (Path::new(vec![(-30.0, 0.0), (30.0, 0.0), (-30.0, 4.0)], false), 9.0),
+            (Path::new(vec![(-60.0, -20.0), (-20.0, 40.0), (10.0, -30.0), (60.0, 30.0)], false), 12.0),
+            // crossing itself
+            (Path::new(vec![(-50.0, 0.0), (50.0, 0.0), (0.0, 50.0), (0.0, -50.0)], false), 7.0),
+            (Path::new(vec![(-40.0, -40.0), (40.0, -40.0), (40.0, 40.0), (-40.0, 40.0)], true), 10.0),
+        ];
+        for cap in [LineCap::Butt, LineCap::Round, LineCap::Square] {
+            for (path, width) in &cases {
+                let mut style = Style::stroke_only(Color::BLACK);
+                style.set_stroke_width(*width);
+                style.set_stroke_cap(cap);
+                let stroke = coverage(path, style);
+                let outline = path.stroke_outline(*width, cap, LineJoin::Round);
+                let fill = coverage(&outline, Style::fill_only(Color::BLACK));
+
+                let painted = stroke.iter().filter(|&&p| p).count();
+                let (missed, extra) = (far_from(&stroke, &fill), far_from(&fill, &stroke));
+                assert!(
+                    missed * 50 <= painted && extra * 50 <= painted,
+                    "{cap:?} width {width}: {missed} missed and {extra} extra of {painted} stroke pixels",
+                );
+            }
+        }
+    }
+}
@@ -2,10 +2,16 @@
 //!
 //! Every primitive polygon object is built using a [Path].
 
-use crate::{Color, Stage, Style};
+use crate::{Color, Fill, LineCap, Quality, RenderOptions, Stage, Stroke, Style};
+use crate::style::Dash;
+use crate::geometry::bounds::circle_nodes;
+use crate::shapes::arcs::sample_conic;
 use crate::primitives::{
-    line::draw_line_pxl,
-    triangle::draw_triangle_pxl, 
+    circle::{circle_spans, draw_circle_pxl},
+    ellipse::{draw_ellipse_pxl, ellipse_spans},
+    coverage::{Disc, SpanCoverage},
+    line::{draw_polyline_aa_pxl, draw_polyline_pxl},
+    triangle::triangle_spans,
 }; 
 
 /// A general Path object.
@@ -16,6 +22,26 @@ use crate::primitives::{
 pub struct Path {
     nodes:  Vec<(f32, f32)>,
     closed: bool,
+    /// `(center, (rx, ry))` if `self` was built by [`Path::circle`] or
+    /// [`Path::ellipse`], so it can be rasterized exactly rather than as a polygon.
+    ellipse: Option<((f32, f32), (f32, f32))>,
+    /// Further subpaths added by [`PathBuilder`] or [`Path::with_holes`].
+    /// Closed subpaths are filled together with `self` under `fill_rule`;
+    /// strokes are drawn per subpath.
+    rest: Vec<Path>,
+    fill_rule: FillRule,
+}
+
+/// Which pixels count as inside a [`Path`] whose contours overlap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FillRule {
+    /// Inside an odd number of contours, so inner contours cut holes
+    /// whatever their direction.
+    #[default]
+    EvenOdd,
+    /// Wound around a nonzero number of times, so overlapping contours
+    /// traced the same way stay filled and holes must wind the other way.
+    NonZero,
 }
 
 impl Path {
@@ -25,102 +51,327 @@ impl Path {
     /// - nodes: Vec<([f32], [f32])> - ordered collection of world coords.
     /// - closed: [bool] - whether to connect the last point with the first.
     pub fn new(nodes: Vec<(f32, f32)>, closed: bool) -> Self {
-        Self { nodes, closed }
+        Self { nodes, closed, ellipse: None, rest: Vec::new(), fill_rule: FillRule::EvenOdd }
+    }
+
+    /// Generates a closed [Path] filling `outer` minus the `holes` inside it.
+    ///
+    /// Contours are filled together under the even-odd rule, so the
+    /// winding direction of each contour does not matter. Every contour is
+    /// stroked on its own.
+    ///
+    /// Arguments:
+    /// - outer: Vec<([f32], [f32])> - world coords of the outer contour.
+    /// - holes: Vec<Vec<([f32], [f32])>> - world coords of each inner contour.
+    pub fn with_holes(outer: Vec<(f32, f32)>, holes: Vec<Vec<(f32, f32)>>) -> Self {
+        let mut path = Self::new(outer, true);
+        path.rest = holes.into_iter().map(|hole| Self::new(hole, true)).collect();
+        path
     }
 
-    /// Converts `nodes` from cartesian `Vec<(f32, f32)>` to pixel `Option<Vec<(isize, isize)>>`.
+    /// Generates a closed circular [Path] centered at `center`.
+    ///
+    /// The nodes approximate the circle for measuring and clipping, but
+    /// [`Path::render`] draws it with the exact circle rasterizer, so thick
+    /// strokes are true annuli. Non-finite or non-positive `radius` gives an
+    /// empty path.
+    ///
+    /// Arguments:
+    /// - center: ([f32], [f32]) - world coord of the center.
+    /// - radius: [f32] - radius in world units.
+    pub fn circle(center: (f32, f32), radius: f32) -> Self {
+        Self::ellipse(center, radius, radius)
+    }
+
+    /// Generates a closed axis-aligned elliptical [Path] centered at `center`.
+    ///
+    /// Like [`Path::circle`], [`Path::render`] draws it with an exact ellipse
+    /// rasterizer whose thick strokes are the ring between two concentric
+    /// ellipses. Non-finite or non-positive radii give an empty path.
     ///
-    /// If any cartesian node is unrepresentable, bails and returns `None`.
-    pub(crate) fn to_pxls(&self, stage: &Stage) -> Option<Vec<(isize, isize)>> {
-        let mut out: Vec<(isize, isize)> = Vec::with_capacity(self.nodes.len());
-        for &xy in &self.nodes {
-            out.push(stage.world_to_pxl(xy)?);
+    /// Arguments:
+    /// - center: ([f32], [f32]) - world coord of the center.
+    /// - rx, ry: [f32] - horizontal and vertical radii in world units.
+    pub fn ellipse(center: (f32, f32), rx: f32, ry: f32) -> Self {
+        let valid = |r: f32| r.is_finite() && r > 0.0;
+        if !valid(rx) || !valid(ry) || !center.0.is_finite() || !center.1.is_finite() {
+            return Self::new(Vec::new(), true);
         }
+
+        let count = ((std::f32::consts::PI * rx.max(ry)).ceil() as usize).clamp(16, 512);
+        let nodes = circle_nodes((0.0, 0.0), 1.0, count)
+            .into_iter()
+            .map(|(x, y)| (center.0 + rx * x, center.1 + ry * y))
+            .collect();
+
+        Self { nodes, closed: true, ellipse: Some((center, (rx, ry))), rest: Vec::new(), fill_rule: FillRule::EvenOdd }
+    }
+
+    /// Returns the world coord nodes of `self`, or of its first subpath if it
+    /// was built with several by [`PathBuilder`].
+    pub fn nodes(&self) -> &[(f32, f32)] {
+        &self.nodes
+    }
+
+    /// Returns `true` if the last node connects back to the first.
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    /// Returns `self` filled under `rule` instead of the default
+    /// [`FillRule::EvenOdd`].
+    pub fn with_fill_rule(mut self, rule: FillRule) -> Self {
+        self.fill_rule = rule;
+        self
+    }
+
+    /// Returns the [`FillRule`] used to fill `self`.
+    pub fn fill_rule(&self) -> FillRule {
+        self.fill_rule
+    }
+
+    /// Returns the `(nodes, closed)` of every subpath of `self`, in order.
+    /// Paths not built by [`PathBuilder`] have exactly one.
+    pub fn subpaths(&self) -> impl Iterator<Item = (&[(f32, f32)], bool)> {
+        std::iter::once(self)
+            .chain(&self.rest)
+            .map(|p| (p.nodes.as_slice(), p.closed))
+    }
+
+    /// Returns `self` with every node of every subpath mapped by `f`, keeping
+    /// the fill rule, or `None` if `f` rejects a node. Circles and ellipses
+    /// become polygons.
+    pub(crate) fn map_nodes<F: FnMut((f32, f32)) -> Option<(f32, f32)>>(&self, mut f: F) -> Option<Path> {
+        let mut map = |p: &Path| {
+            let nodes = p.nodes.iter().map(|&q| f(q)).collect::<Option<Vec<_>>>()?;
+            Some(Path::new(nodes, p.closed))
+        };
+        let mut out = map(self)?;
+        out.rest = self.rest.iter().map(&mut map).collect::<Option<Vec<_>>>()?;
+        out.fill_rule = self.fill_rule;
         Some(out)
-    } 
+    }
+
+    /// Returns `self` mapped by `p -> (p - offset) * zoom`, keeping the exact
+    /// circle and ellipse rasterizers for paths built by [`Path::ellipse`].
+    pub(crate) fn zoomed(&self, offset: (f32, f32), zoom: f32) -> Path {
+        let map = |(x, y): (f32, f32)| ((x - offset.0) * zoom, (y - offset.1) * zoom);
+        match self.ellipse {
+            Some((c, (rx, ry))) => Path::ellipse(map(c), rx * zoom, ry * zoom),
+            None => Path {
+                rest: self.rest.iter().map(|p| p.zoomed(offset, zoom)).collect(),
+                fill_rule: self.fill_rule,
+                ..Path::new(self.nodes.iter().map(|&p| map(p)).collect(), self.closed)
+            },
+        }
+    }
+
+    /// Returns the world coord bounding box `((xmin, ymin), (xmax, ymax))` of
+    /// the nodes of `self`, or `None` if it has none.
+    pub(crate) fn bounds(&self) -> Option<((f32, f32), (f32, f32))> {
+        let mut nodes = self.subpaths().flat_map(|(nodes, _)| nodes.iter().copied());
+        let first = nodes.next()?;
+        let mut lo = first;
+        let mut hi = first;
+        for (x, y) in nodes {
+            lo = (lo.0.min(x), lo.1.min(y));
+            hi = (hi.0.max(x), hi.1.max(y));
+        }
+        Some((lo, hi))
+    }
+
+    /// Returns the world coord segments of every subpath of `self`, including
+    /// the closing segment of closed ones.
+    pub(crate) fn segments(&self) -> Vec<((f32, f32), (f32, f32))> {
+        let mut out: Vec<((f32, f32), (f32, f32))> = Vec::new();
+        for (nodes, closed) in self.subpaths() {
+            let n = nodes.len();
+            if n < 2 { continue; }
+
+            out.extend(nodes.windows(2).map(|w| (w[0], w[1])));
+            if closed {
+                out.push((nodes[n - 1], nodes[0]));
+            }
+        }
+        out
+    }
+
+    /// Returns the total length of `self` in world units.
+    pub fn length(&self) -> f32 {
+        self.segments()
+            .iter()
+            .map(|&(a, b)| ((b.0 - a.0).powi(2) + (b.1 - a.1).powi(2)).sqrt())
+            .sum()
+    }
+
+    /// Returns the point `distance` world units along `self` and the tangent
+    /// angle (radians, counter-clockwise from `+x`) at that point.
+    ///
+    /// Returns `None` if `distance` is outside `[0, length]` or `self` has no length.
+    pub fn point_at_distance(&self, distance: f32) -> Option<((f32, f32), f32)> {
+        if !distance.is_finite() || distance < 0.0 { return None; }
+
+        let mut remaining = distance;
+        for (a, b) in self.segments() {
+            let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+            let len = (dx * dx + dy * dy).sqrt();
+            if len == 0.0 { continue; }
+
+            if remaining <= len {
+                let t = remaining / len;
+                return Some(((a.0 + t * dx, a.1 + t * dy), dy.atan2(dx)));
+            }
+            remaining -= len;
+        }
+        None
+    }
 
     pub(crate) fn make_stroke_pxl(
         nodes_px: &[(isize, isize)],
         closed: bool,
         width: f32,
+        cap: LineCap,
         stage: &mut Stage,
         stroke_color: Color,
     ) {
         if nodes_px.len() < 2 { return; }
         if !width.is_finite() || width <= 0.0 { return; }
 
-        // 1px stroke, Bresenham line
+        // 1px stroke, one continuous Bresenham scan
         if width <= 1.0 {
-            let mut i = 0;
-            while i + 1 < nodes_px.len() {
-                draw_line_pxl(stage, nodes_px[i], nodes_px[i + 1], stroke_color);
-                i += 1;
-            }
-            if closed {
-                draw_line_pxl(stage, nodes_px[nodes_px.len() - 1], nodes_px[0], stroke_color);
-            }
+            draw_polyline_pxl(stage, nodes_px, closed, stroke_color);
             return;
         }
 
-        // thick stroke 
-        let style = Style::fill_only(stroke_color);
-        let mut i = 0;
-        while i + 1 < nodes_px.len() {
-            let xy1 = nodes_px[i];
-            let xy2 = nodes_px[i + 1];
-
-            if let Some([a, b, c, d]) = stroke_corners(xy1, xy2, width) {
-                draw_triangle_pxl(stage, a, b, c, style);
-                draw_triangle_pxl(stage, a, c, d, style);
+        // every stage pixel already lies within `reach` of each node, so a
+        // wider stroke covers the same pixels; clamping keeps the arithmetic
+        // below in range for huge widths
+        let (w, h) = (stage.width() as f64, stage.height() as f64);
+        let reach = nodes_px.iter().fold(0.0_f64, |acc, &(x, y)| {
+            let (x, y) = (x as f64, y as f64);
+            acc.max(x.abs().max((x - w).abs()).hypot(y.abs().max((y - h).abs())))
+        });
+        let width = width.min((2.0 * reach + 2.0) as f32);
+
+        // thick stroke, segment quads are unioned first so joins where
+        // neighbouring quads overlap are written once
+        let (ymin, ymax) = y_bound(nodes_px);
+        let pad = (width.ceil() as isize).min(stage.height() as isize) + 1;
+        let y0 = ymin.saturating_sub(pad).max(0);
+        let y1 = ymax.saturating_add(pad).min(stage.height() as isize - 1);
+        let mut coverage = SpanCoverage::new(y0, y1);
+
+        let n = nodes_px.len();
+        let segments = if closed { n } else { n - 1 };
+        let square = closed || cap == LineCap::Square;
+        for i in 0..segments {
+            let (xy1, xy2) = (nodes_px[i], nodes_px[(i + 1) % n]);
+
+            // quads overlap past interior nodes; open ends follow the cap
+            let extend = (i > 0 || square, i + 1 < segments || square);
+            if let Some([a, b, c, d]) = stroke_corners(xy1, xy2, width, extend) {
+                triangle_spans(a, b, c, |y, x0, x1| coverage.add(y, x0, x1));
+                triangle_spans(a, c, d, |y, x0, x1| coverage.add(y, x0, x1));
             }
+        }
 
-            i += 1;
+        // round joins fill the wedge the quads leave open on the outside of
+        // each bend, stamped from one disc rasterized for this width
+        let joins = if closed { 0..n } else { 1..n - 1 };
+        let disc = Disc::new(width);
+        for i in joins {
+            coverage.add_disc(nodes_px[i], &disc);
+        }
+        if !closed && cap == LineCap::Round {
+            for end in [nodes_px[0], nodes_px[n - 1]] {
+                coverage.add_disc(end, &disc);
+            }
         }
 
-        if closed {
-            let xy1 = nodes_px[nodes_px.len() - 1];
-            let xy2 = nodes_px[0];
+        coverage.fill(stage, stroke_color);
+    }
 
-            if let Some([a, b, c, d]) = stroke_corners(xy1, xy2, width) {
-                draw_triangle_pxl(stage, a, b, c, style);
-                draw_triangle_pxl(stage, a, c, d, style);
+    /// Strokes the polyline `nodes_px` in fractional pixel coords, split into
+    /// the dashes of `stroke` and anti-aliased if it is a smooth hairline.
+    fn make_outline_pxl(nodes_px: &[(f32, f32)], closed: bool, stage: &mut Stage, stroke: Stroke) {
+        let color = stroke.rgba();
+        let draw = |stage: &mut Stage, run: &[(f32, f32)], closed: bool| {
+            if antialiased(stroke, stage) {
+                draw_polyline_aa_pxl(stage, run, closed, color);
+            } else {
+                let mut run_px: Vec<(isize, isize)> =
+                    run.iter().map(|&(x, y)| (x.round() as isize, y.round() as isize)).collect();
+                run_px.dedup();
+                if run_px.len() == 1 { run_px.push(run_px[0]); }
+                Self::make_stroke_pxl(&run_px, closed, stroke.width, stroke.cap, stage, color);
             }
+        };
+
+        if !stroke.dash.is_dashed() {
+            return draw(stage, nodes_px, closed);
         }
+
+        // dashes are only generated where they can touch the draw rect
+        let r = stage.draw_rect();
+        let pad = if stroke.width.is_finite() { stroke.width.ceil() + 2.0 } else { 2.0 };
+        let bounds = ((r.x as f32 - pad, r.y as f32 - pad), (r.right() as f32 + pad, r.bottom() as f32 + pad));
+        for_each_dash(nodes_px, closed, stroke.dash, bounds, |run, closed| draw(stage, run, closed));
     }
 
-    /// Fills the interior of `self` in pixel coords.
+    /// Fills the interior of the `contours` in pixel coords under the
+    /// even-odd rule, so inner contours cut holes.
     pub(crate) fn make_fill_pxl(
-        nodes_px: &[(isize, isize)],
+        contours: &[&[(isize, isize)]],
         stage: &mut Stage,
-        fill_color: Color,
+        fill: Fill,
     ) {
-        if nodes_px.len() < 3 {
-            return;
-        }
+        let h = stage.height();
+        Self::fill_spans(contours, FillRule::EvenOdd, h, |y, l, r| stage.fill_span_paint(y, l, r, fill));
+    }
 
-        let (ymin, ymax) = y_bound(nodes_px);
+    /// Walks the interior scanline spans of the polygon made of the closed
+    /// `contours` on a stage `height` pixels tall, calling `span(y, x0, x1)`
+    /// for each inclusive run of pixels inside under `rule`.
+    pub(crate) fn fill_spans<F: FnMut(isize, isize, isize)>(
+        contours: &[&[(isize, isize)]],
+        rule: FillRule,
+        height: usize,
+        mut span: F,
+    ) {
+        let mut ymin = isize::MAX;
+        let mut ymax = isize::MIN;
+        for nodes_px in contours.iter().filter(|c| c.len() >= 3) {
+            let (lo, hi) = y_bound(nodes_px);
+            ymin = ymin.min(lo);
+            ymax = ymax.max(hi);
+        }
         if ymin >= ymax {
             return;
         }
 
-        let h = stage.height() as isize;
+        let h = height as isize;
         let y0 = ymin.max(0);
         let y1 = ymax.min(h - 1);
         if y0 > y1 {
             return;
         }
 
-        let mut crossings: Vec<isize> = Vec::new();
+        // crossing columns with the winding of their edge, +1 downwards
+        let mut crossings: Vec<(isize, i32)> = Vec::new();
 
         for y in y0..=y1 {
             crossings.clear();
 
-            let mut i = 0;
-            while i + 1 < nodes_px.len() {
-                let (x1, y1e) = nodes_px[i];
-                let (x2, y2e) = nodes_px[i + 1];
+            for nodes_px in contours.iter().filter(|c| c.len() >= 3) {
+                let n = nodes_px.len();
+                for i in 0..n {
+                    let (x1, y1e) = nodes_px[i];
+                    let (x2, y2e) = nodes_px[(i + 1) % n];
+
+                    if y1e == y2e {
+                        continue;
+                    }
 
-                if y1e != y2e {
                     let ylo = y1e.min(y2e);
                     let yhi = y1e.max(y2e);
 
@@ -131,78 +382,578 @@ impl Path {
                         let y2f = y2e as f32;
 
                         let x = x1f + (y as f32 - y1f) * (x2f - x1f) / (y2f - y1f);
-                        crossings.push(x.floor() as isize);
+                        crossings.push((x.ceil() as isize, if y2e > y1e { 1 } else { -1 }));
                     }
                 }
-
-                i += 1;
             }
 
-            let (x1, y1e) = nodes_px[nodes_px.len() - 1];
-            let (x2, y2e) = nodes_px[0];
-
-            if y1e != y2e {
-                let ylo = y1e.min(y2e);
-                let yhi = y1e.max(y2e);
-
-                if y >= ylo && y < yhi {
-                    let x1f = x1 as f32;
-                    let x2f = x2 as f32;
-                    let y1f = y1e as f32;
-                    let y2f = y2e as f32;
-
-                    let x = x1f + (y as f32 - y1f) * (x2f - x1f) / (y2f - y1f);
-                    crossings.push(x.floor() as isize);
+            crossings.sort_unstable();
+            debug_assert!(crossings.len().is_multiple_of(2));
+
+            // pixels whose centers lie in [x1, x2) between an entering and a
+            // leaving crossing, so polygons sharing an edge tile without gaps
+            // or overlap
+            let mut winding = 0;
+            let mut start = 0;
+            for &(x, dir) in &crossings {
+                let inside = |w: i32| match rule {
+                    FillRule::EvenOdd => w % 2 != 0,
+                    FillRule::NonZero => w != 0,
+                };
+                let was = inside(winding);
+                winding += dir;
+                match (was, inside(winding)) {
+                    (false, true) => start = x,
+                    (true, false) if start < x => span(y, start, x - 1),
+                    _ => {}
                 }
             }
+        }
+    }
 
-            crossings.sort_unstable();
-            debug_assert!(crossings.len() % 2 == 0);
-
-            let mut j = 0;
-            while j + 1 < crossings.len() {
-                let x1 = crossings[j];
-                let x2 = crossings[j + 1];
-
-                let l = x1 + 1;
-                let r = x2 - 1;
+    /// Converts the subpaths of `self` to pixel coords, skipping any with an
+    /// unrepresentable node. Returns the `(nodes, closed)` of each.
+    fn subpaths_pxl(&self, stage: &Stage) -> Vec<(Vec<(isize, isize)>, bool)> {
+        self.subpaths()
+            .filter_map(|(nodes, closed)| Some((nodes_to_pxls(nodes, stage)?, closed)))
+            .collect()
+    }
 
-                if l <= r {
-                    stage.fill_span_pxl(y, l, r, fill_color);
-                }
+    /// Walks the interior spans of `self` on `stage`, filling every subpath
+    /// as if closed when `close_all`, otherwise only the closed ones.
+    pub(crate) fn interior_spans<F: FnMut(&mut Stage, isize, isize, isize)>(
+        &self,
+        stage: &mut Stage,
+        close_all: bool,
+        mut span: F,
+    ) {
+        let subpaths = self.subpaths_pxl(stage);
+        let contours: Vec<&[(isize, isize)]> = subpaths
+            .iter()
+            .filter(|(_, closed)| close_all || *closed)
+            .map(|(nodes_px, _)| nodes_px.as_slice())
+            .collect();
+        let h = stage.height();
+        Self::fill_spans(&contours, self.fill_rule, h, |y, x0, x1| span(stage, y, x0, x1));
+    }
 
-                j += 2;
+    /// Fills the interior of `self` with a per-pixel color from `shader`,
+    /// e.g. a gradient or pattern, ignoring strokes. `shader` receives the world
+    /// coord of each pixel. Closed subpaths are filled together under the
+    /// even-odd rule; open ones are skipped.
+    ///
+    /// ```ignore
+    /// let cmap = Colormap::viridis();
+    /// Path::circle((0.0, 0.0), 80.0).fill_with(&mut stage, |(x, y)| {
+    ///     cmap.sample((x * x + y * y).sqrt() / 80.0)
+    /// });
+    /// ```
+    pub fn fill_with<F: FnMut((f32, f32)) -> Color>(&self, stage: &mut Stage, mut shader: F) {
+        let mut shade_span = |stage: &mut Stage, y: isize, x0: isize, x1: isize| {
+            let (wx0, wy) = stage.pxl_to_world((0, y));
+            stage.fill_span_with(y, x0, x1, |x| shader((wx0 + x as f32, wy)));
+        };
+
+        if let Some((center, radii)) = self.ellipse {
+            let Some(center_pxl) = stage.world_to_pxl(center) else { return; };
+            let (rx, ry) = radii_pxl(radii);
+            let mut span = |_, y, x0, x1| shade_span(stage, y, x0, x1);
+            if rx == ry {
+                circle_spans(center_pxl, rx, true, None, span);
+            } else {
+                ellipse_spans(center_pxl, (rx, ry), true, None, &mut span);
             }
+            return;
         }
+
+        self.interior_spans(stage, false, shade_span);
     }
 
-    /// Renders `self` on a `stage` using `style`. Filling only occurs if `self` is closed. 
+    /// Renders `self` on a `stage` using `style`. Filling only occurs if `self` is closed.
+    /// Closed subpaths are filled together under its [`FillRule`], by default
+    /// even-odd so inner contours cut holes, then every subpath is stroked.
+    ///
+    /// On a stage created by [`Stage::new_with_path_aa`] edges are anti-aliased
+    /// at its factor, otherwise they are crisp.
     ///
     /// Arguments: 
     /// - stage: &mut [Stage] - stage to draw onto. 
     /// - style: [Style] - struct containing style args.
     pub fn render(&self, stage: &mut Stage, style: Style) {
-        let Some(nodes_px) = self.to_pxls(stage) else { return; };
+        match stage.path_aa() {
+            1 => self.render_crisp(stage, style),
+            factor => self.render_smooth_with_hairline(stage, style, factor),
+        }
+    }
+
+    /// Renders `self` with hard, aliased edges.
+    fn render_crisp(&self, stage: &mut Stage, style: Style) {
+        let style = style.resolve_hairline();
+        if style.blend.is_some() {
+            return stage.with_blend(style.blend, |stage| self.render_crisp(stage, Style { blend: None, ..style }));
+        }
+
+        if let Some((center, radii)) = self.ellipse {
+            let Some(center_pxl) = stage.world_to_pxl(center) else { return; };
+            let (rx, ry) = radii_pxl(radii);
+
+            // anti-aliased and dashed outlines are stroked along the polygon nodes
+            let outline = style.stroke.filter(|s| antialiased(*s, stage) || s.dash.is_dashed());
+            let exact = if outline.is_some() { Style { stroke: None, ..style } } else { style };
+            if rx == ry {
+                draw_circle_pxl(stage, center_pxl, rx, exact);
+            } else {
+                draw_ellipse_pxl(stage, center_pxl, (rx, ry), exact);
+            }
+            if let Some(stroke) = outline {
+                let nodes: Vec<_> = self.nodes.iter().map(|&p| stage.world_to_pxl_f32(p)).collect();
+                Self::make_outline_pxl(&nodes, true, stage, stroke);
+            }
+            return;
+        }
+
         if !style.fill_or_stroke_exists() { return; };
 
-        if self.closed {
-            if let Some(fill) = style.fill {
-                let fill_color = fill.rgba();
-                Self::make_fill_pxl(&nodes_px, stage, fill_color);
+        if let Some(fill) = style.fill {
+            self.interior_spans(stage, false, |stage, y, x0, x1| {
+                stage.fill_span_paint(y, x0, x1, fill);
+            });
+        }
+
+        if let Some(stroke) = style.stroke {
+            let stroke_color = stroke.rgba();
+            if antialiased(stroke, stage) || stroke.dash.is_dashed() {
+                for (nodes, closed) in self.subpaths() {
+                    let nodes: Vec<_> = nodes.iter().map(|&p| stage.world_to_pxl_f32(p)).collect();
+                    Self::make_outline_pxl(&nodes, closed, stage, stroke);
+                }
+                return;
+            }
+            for (nodes_px, closed) in self.subpaths_pxl(stage) {
+                Self::make_stroke_pxl(&nodes_px, closed, stroke.width, stroke.cap, stage, stroke_color);
             }
         }
+    }
+
+    /// Fills (if `closed`) and strokes the polygon `nodes_px` in pixel coords.
+    pub(crate) fn render_pxl(nodes_px: &[(isize, isize)], closed: bool, stage: &mut Stage, style: Style) {
+        if !style.fill_or_stroke_exists() { return; };
+        let style = style.resolve_hairline();
+        if style.blend.is_some() {
+            return stage.with_blend(style.blend, |stage| {
+                Self::render_pxl(nodes_px, closed, stage, Style { blend: None, ..style })
+            });
+        }
+
+        if closed && let Some(fill) = style.fill {
+            Self::make_fill_pxl(&[nodes_px], stage, fill);
+        }
 
         if let Some(stroke) = style.stroke {
             let stroke_color = stroke.rgba();
+            if antialiased(stroke, stage) || stroke.dash.is_dashed() {
+                let nodes: Vec<_> = nodes_px.iter().map(|&(x, y)| (x as f32, y as f32)).collect();
+                Self::make_outline_pxl(&nodes, closed, stage, stroke);
+                return;
+            }
             Self::make_stroke_pxl(
-                &nodes_px,
-                self.closed,
+                nodes_px,
+                closed,
                 stroke.width,
+                stroke.cap,
                 stage,
                 stroke_color,
             );
         }
     }
+
+    /// Renders `self` on a `stage` using `style` with per-draw `options`, e.g.
+    /// smooth data curves over crisp grid lines in the same figure.
+    ///
+    /// Arguments: 
+    /// - stage: &mut [Stage] - stage to draw onto. 
+    /// - style: [Style] - struct containing style args.
+    /// - options: [RenderOptions] - per-draw overrides.
+    pub fn render_with(&self, stage: &mut Stage, style: Style, options: RenderOptions) {
+        match options.quality {
+            Quality::Crisp => self.render_crisp(stage, style),
+            Quality::Smooth => self.render_smooth_with_hairline(stage, style, SUPERSAMPLE.max(stage.path_aa())),
+        }
+    }
+
+    /// Renders `self` supersampled at `factor`, except for a hairline stroke,
+    /// which is drawn crisp on top.
+    fn render_smooth_with_hairline(&self, stage: &mut Stage, style: Style, factor: usize) {
+        match style.stroke {
+            Some(stroke) if stroke.hairline => {
+                self.render_supersampled(stage, Style { stroke: None, ..style }, factor);
+                self.render_crisp(stage, Style { fill: None, ..style });
+            }
+            _ => self.render_supersampled(stage, style, factor),
+        }
+    }
+
+    /// Renders `self` at `factor`x resolution into a scratch tile covering
+    /// its pixel bounds, then averages each block of subpixels down onto `stage`.
+    fn render_supersampled(&self, stage: &mut Stage, style: Style, factor: usize) {
+        if self.bounds().is_none() || !style.fill_or_stroke_exists() { return; }
+        if style.blend.is_some() {
+            // the tile is drawn plainly, the blend applies when resolving it
+            return stage.with_blend(style.blend, |stage| {
+                self.render_supersampled(stage, Style { blend: None, ..style }, factor)
+            });
+        }
+        let s = factor;
+        let sf = s as f32;
+
+        // pixel bounds, padded for the stroke and rounding
+        let pad = style.stroke.map_or(0.0, |s| 0.5 * s.width) + 2.0;
+        let (mut x0, mut y0, mut x1, mut y1) = (f32::MAX, f32::MAX, f32::MIN, f32::MIN);
+        for p in self.subpaths().flat_map(|(nodes, _)| nodes.iter().copied()) {
+            let (px, py) = stage.world_to_pxl_f32(p);
+            if !px.is_finite() || !py.is_finite() { return; }
+            x0 = x0.min(px);
+            y0 = y0.min(py);
+            x1 = x1.max(px);
+            y1 = y1.max(py);
+        }
+
+        let r = stage.draw_rect();
+        let bx = ((x0 - pad).floor() as isize).max(r.x as isize);
+        let by = ((y0 - pad).floor() as isize).max(r.y as isize);
+        let ex = ((x1 + pad).ceil() as isize).min(r.right() as isize - 1);
+        let ey = ((y1 + pad).ceil() as isize).min(r.bottom() as isize - 1);
+        if bx > ex || by > ey { return; }
+        let (w, h) = ((ex - bx + 1) as usize, (ey - by + 1) as usize);
+
+        // subpixel `X` of the tile samples pixel position `bx - 0.5 + (X + 0.5) / s`
+        let (ox, oy) = stage.origin_pxl();
+        let mut tile = Stage::new(w * s, h * s);
+        tile.set_origin_pxl((
+            sf * (ox - bx as f32 + 0.5) - 0.5,
+            sf * (oy - by as f32 + 0.5) - 0.5,
+        ));
+
+        let scaled = self.zoomed((0.0, 0.0), sf);
+        let mut style = style;
+        if let Some(stroke) = style.stroke.as_mut() {
+            stroke.width *= sf;
+            stroke.dash = stroke.dash.scaled(sf);
+        }
+        if let Some(g) = style.fill.as_mut().and_then(|f| f.paint.gradient_mut()) {
+            *g = g.scaled(sf);
+        }
+        scaled.render_crisp(&mut tile, style);
+
+        let full = (s * s) as u32;
+        for ty in 0..h {
+            for tx in 0..w {
+                let mut sum = [0u32; 4];
+                let mut covered = 0;
+                for sy in 0..s {
+                    for &px in &tile.row(ty * s + sy)[tx * s..(tx + 1) * s] {
+                        if px[3] == 0 { continue; }
+                        covered += 1;
+                        let a = px[3] as u32;
+                        for c in 0..3 { sum[c] += px[c] as u32 * a; }
+                        sum[3] += a;
+                    }
+                }
+                if covered == 0 { continue; }
+
+                let (x, y) = (bx + tx as isize, by + ty as isize);
+                let unpremultiply = |v: u32| ((v + sum[3] / 2) / sum[3]) as u8;
+                let color = [unpremultiply(sum[0]), unpremultiply(sum[1]), unpremultiply(sum[2])];
+
+                if covered == full {
+                    // interior pixels are written like crisp fills
+                    let a = ((sum[3] + full / 2) / full) as u8;
+                    stage.plot_pxl(x, y, Color::new([color[0], color[1], color[2], a]));
+                } else {
+                    let src = [color[0], color[1], color[2], 255];
+                    let coverage = sum[3] as f32 / (255 * full) as f32;
+                    stage.blend_pxl(x, y, Color::new(src), coverage);
+                }
+            }
+        }
+    }
+
+    /// Renders `self` on a `stage` using `style`, confined to the interior of `clip`.
+    ///
+    /// Arguments: 
+    /// - stage: &mut [Stage] - stage to draw onto. 
+    /// - style: [Style] - struct containing style args.
+    /// - clip: &[Path] - clip outline, treated as closed.
+    pub fn render_clipped(&self, stage: &mut Stage, style: Style, clip: &Path) {
+        stage.with_clip(clip, |stage| self.render(stage, style));
+    }
+}
+
+/// Fluent builder for a [`Path`] made of one or more subpaths.
+///
+/// ```ignore
+/// let slice = PathBuilder::new()
+///     .move_to((0.0, 0.0))
+///     .arc_to((0.0, 0.0), 50.0, 30.0, 120.0)
+///     .close()
+///     .build();
+///
+/// let path = PathBuilder::new()
+///     .move_to((-40.0, -40.0))
+///     .line_to((40.0, -40.0))
+///     .line_to((0.0, 30.0))
+///     .close()
+///     .move_to((-60.0, 50.0))
+///     .line_to((60.0, 50.0))
+///     .build();
+/// ```
+#[derive(Default)]
+pub struct PathBuilder {
+    done: Vec<Path>,
+    current: Vec<(f32, f32)>,
+    /// Start of the last closed subpath, where a `line_to` right after
+    /// [`PathBuilder::close`] continues from.
+    closed_at: Option<(f32, f32)>,
+}
+
+impl PathBuilder {
+    /// Creates an empty [`PathBuilder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ends the current subpath open and starts a new one at `point`.
+    ///
+    /// Arguments:
+    /// - point: ([f32], [f32]) - world coord of the new subpath start.
+    pub fn move_to(mut self, point: (f32, f32)) -> Self {
+        self.finish(false);
+        self.current.push(point);
+        self
+    }
+
+    /// Extends the current subpath with a segment to `point`. Without a
+    /// current subpath, one is started at the last closed subpath's start,
+    /// or at `point` if there is none.
+    ///
+    /// Arguments:
+    /// - point: ([f32], [f32]) - world coord of the segment end.
+    pub fn line_to(mut self, point: (f32, f32)) -> Self {
+        if self.current.is_empty() && let Some(start) = self.closed_at.take() {
+            self.current.push(start);
+        }
+        self.current.push(point);
+        self
+    }
+
+    /// Extends the current subpath along a circular arc around `center`.
+    ///
+    /// A straight segment joins the current point to the arc start, so a pie
+    /// slice is `move_to(center)`, `arc_to(..)`, `close()`. Invalid radii or
+    /// angles, or `start == end`, add nothing.
+    ///
+    /// Arguments:
+    /// - center: ([f32], [f32]) - world coord of the circle center.
+    /// - radius: [f32] - radius in world units.
+    /// - start: [f32] - start angle in degrees, counter-clockwise from `+x`.
+    /// - end: [f32] - end angle in degrees; below `start` runs clockwise.
+    ///   At most one full turn is drawn.
+    pub fn arc_to(mut self, center: (f32, f32), radius: f32, start: f32, end: f32) -> Self {
+        let Some(samples) = sample_conic(center, (radius, radius), start, end - start) else {
+            return self;
+        };
+        for (p, _) in samples {
+            self = self.line_to(p);
+        }
+        self
+    }
+
+    /// Closes the current subpath back to its start.
+    pub fn close(mut self) -> Self {
+        let start = self.current.first().copied();
+        self.finish(true);
+        self.closed_at = start;
+        self
+    }
+
+    /// Returns the built [`Path`]. The current subpath is kept open.
+    pub fn build(mut self) -> Path {
+        self.finish(false);
+        let mut subpaths = self.done.into_iter();
+        let Some(mut path) = subpaths.next() else {
+            return Path::new(Vec::new(), false);
+        };
+        path.rest = subpaths.collect();
+        path
+    }
+
+    /// Moves the current subpath, if any, into `done`.
+    fn finish(&mut self, closed: bool) {
+        self.closed_at = None;
+        if self.current.is_empty() { return; }
+        self.done.push(Path::new(std::mem::take(&mut self.current), closed));
+    }
+}
+
+/// Converts world coord `nodes` to pixel coords, or `None` if any node is
+/// unrepresentable.
+fn nodes_to_pxls(nodes: &[(f32, f32)], stage: &Stage) -> Option<Vec<(isize, isize)>> {
+    let mut out: Vec<(isize, isize)> = Vec::with_capacity(nodes.len());
+    for &xy in nodes {
+        out.push(stage.world_to_pxl(xy)?);
+    }
+    Some(out)
+}
+
+/// Shortest dash period in pixels; finer patterns are scaled up to it.
+pub(crate) const MIN_DASH_PERIOD: f64 = 1.0;
+
+/// Calls `emit(run, closed)` with each "on" run of `dash` along the polyline
+/// `nodes` that touches the box `bounds`, in order.
+///
+/// Dash boundaries are placed at whole periods from the first node, so the
+/// pattern restarts there and does not drift along long paths. Runs are
+/// cut where the polyline leaves `bounds`. On a closed polyline a dash that
+/// runs through the first node is joined into one run.
+fn for_each_dash<F: FnMut(&[(f32, f32)], bool)>(
+    nodes: &[(f32, f32)],
+    closed: bool,
+    dash: Dash,
+    bounds: ((f32, f32), (f32, f32)),
+    mut emit: F,
+) {
+    let n = nodes.len();
+    if n < 2 { return; }
+
+    let (lengths, offset) = dash.pattern();
+    let period: f64 = lengths.iter().map(|&l| l as f64).sum();
+    let k = (MIN_DASH_PERIOD / period).max(1.0);
+    // ends[j] is where pattern entry j stops within one period
+    let ends: Vec<f64> = lengths
+        .iter()
+        .scan(0.0, |acc, &l| { *acc += l as f64 * k; Some(*acc) })
+        .collect();
+    let (period, offset) = (period * k, offset as f64 * k);
+
+    // pattern entry and period index at distance `d` along the polyline
+    let locate = |d: f64| {
+        let u = d + offset;
+        let p = (u / period).floor();
+        let rem = u - p * period;
+        (ends.iter().position(|&e| rem < e).unwrap_or(0), p)
+    };
+    let boundary = |j: usize, p: f64| p * period + ends[j] - offset;
+
+    let mut run: Vec<(f32, f32)> = Vec::new();
+    let mut on = false;
+    // a closed polyline holds back its first run for the closing dash
+    let mut first: Option<Vec<(f32, f32)>> = None;
+    let mut hold_first = false;
+    let mut finish = |run: &mut Vec<(f32, f32)>, hold_first: &mut bool| {
+        if std::mem::take(hold_first) {
+            first = Some(run.clone());
+        } else {
+            emit(run, false);
+        }
+        run.clear();
+    };
+
+    let segments = if closed { n } else { n - 1 };
+    let mut s0 = 0.0_f64;
+    for seg in 0..segments {
+        let (a, b) = (nodes[seg], nodes[(seg + 1) % n]);
+        let len = ((b.0 - a.0) as f64).hypot((b.1 - a.1) as f64);
+        let at = |d: f64| {
+            let t = if len > 0.0 { ((d - s0) / len) as f32 } else { 0.0 };
+            (a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t)
+        };
+
+        let Some((ta, tb)) = clip_segment(a, b, bounds) else {
+            if on { finish(&mut run, &mut hold_first); on = false; }
+            s0 += len;
+            continue;
+        };
+        let (da, db) = (s0 + ta as f64 * len, s0 + tb as f64 * len);
+
+        let (mut j, mut p) = locate(da);
+        if on && j % 2 == 1 {
+            // the previous segment ended exactly on a dash end
+            finish(&mut run, &mut hold_first);
+            on = false;
+        } else if !on && j % 2 == 0 {
+            run.push(at(da));
+            on = true;
+            hold_first = closed && seg == 0 && ta == 0.0;
+        }
+
+        // walk the pattern boundaries falling inside the visible part
+        loop {
+            let d = boundary(j, p);
+            if d >= db { break; }
+            run.push(at(d));
+            if on { finish(&mut run, &mut hold_first); }
+            on = !on;
+            j += 1;
+            if j == ends.len() { j = 0; p += 1.0; }
+        }
+
+        if on {
+            if tb < 1.0 {
+                // leaving the box, the rest of the dash is never visible
+                run.push(at(db));
+                finish(&mut run, &mut hold_first);
+                on = false;
+            } else {
+                run.push(b);
+            }
+        }
+        s0 += len;
+    }
+
+    match (on, first) {
+        // the whole loop is one dash
+        (true, None) if hold_first => emit(&run, true),
+        // a dash over the closing node continues into the first one
+        (true, Some(head)) if closed => {
+            run.extend(head.into_iter().skip(1));
+            emit(&run, false);
+        }
+        (on, head) => {
+            if on { emit(&run, false); }
+            if let Some(head) = head { emit(&head, false); }
+        }
+    }
+}
+
+/// Returns the parameter range `(t0, t1)` of the segment `a -> b` inside
+/// the box `((x0, y0), (x1, y1))`, or `None` if it misses the box.
+fn clip_segment(a: (f32, f32), b: (f32, f32), ((x0, y0), (x1, y1)): ((f32, f32), (f32, f32))) -> Option<(f32, f32)> {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let (mut t0, mut t1) = (0.0_f32, 1.0_f32);
+    for (p, q) in [(-dx, a.0 - x0), (dx, x1 - a.0), (-dy, a.1 - y0), (dy, y1 - a.1)] {
+        if p == 0.0 {
+            if q < 0.0 { return None; }
+        } else {
+            let r = q / p;
+            if p < 0.0 { t0 = t0.max(r); } else { t1 = t1.min(r); }
+        }
+    }
+    (t0 <= t1).then_some((t0, t1))
+}
+
+/// Returns `true` if `stroke` is a hairline that should be drawn with
+/// anti-aliased lines on `stage`.
+pub(crate) fn antialiased(stroke: Stroke, stage: &Stage) -> bool {
+    stroke.width.is_finite() && stroke.width > 0.0 && stroke.width <= 1.0
+        && (stroke.antialias || stage.line_antialias())
+}
+
+/// Subpixels per axis used by [`Quality::Smooth`].
+const SUPERSAMPLE: usize = 4;
+
+/// Rounds world radii up to whole pixels, at least one.
+pub(crate) fn radii_pxl((rx, ry): (f32, f32)) -> (isize, isize) {
+    (rx.ceil().max(1.0) as isize, ry.ceil().max(1.0) as isize)
 }
 
 fn y_bound(nodes_px: &[(isize, isize)]) -> (isize, isize) {
@@ -218,11 +969,13 @@ fn y_bound(nodes_px: &[(isize, isize)]) -> (isize, isize) {
 }
 
 /// Returns the corners of a line with a stroke `width`.
-/// Projected ends to account for corners. 
+/// Ends flagged in `extend` are projected by half the width, to overlap
+/// neighbouring segments at joins or to form square caps.
 fn stroke_corners(
     xy1: (isize, isize),
     xy2: (isize, isize),
     width: f32,
+    extend: (bool, bool),
 ) -> Option<[(isize, isize); 4]> {
     if !width.is_finite() || width <= 0.0 {
         return None;
@@ -253,16 +1006,17 @@ fn stroke_corners(
 
     // extend endpoints 
     // to ensure overlap
-    let ex = tx * r;
-    let ey = ty * r;
+    let (e1, e2) = (if extend.0 { r } else { 0.0 }, if extend.1 { r } else { 0.0 });
+    let (ex1, ey1) = (tx * e1, ty * e1);
+    let (ex2, ey2) = (tx * e2, ty * e2);
 
     let ox = nx * r;
     let oy = ny * r;
 
-    let a = ((x1 - ex + ox).round() as isize, (y1 - ey + oy).round() as isize);
-    let b = ((x2 + ex + ox).round() as isize, (y2 + ey + oy).round() as isize);
-    let c = ((x2 + ex - ox).round() as isize, (y2 + ey - oy).round() as isize);
-    let d = ((x1 - ex - ox).round() as isize, (y1 - ey - oy).round() as isize);
+    let a = ((x1 - ex1 + ox).round() as isize, (y1 - ey1 + oy).round() as isize);
+    let b = ((x2 + ex2 + ox).round() as isize, (y2 + ey2 + oy).round() as isize);
+    let c = ((x2 + ex2 - ox).round() as isize, (y2 + ey2 - oy).round() as isize);
+    let d = ((x1 - ex1 - ox).round() as isize, (y1 - ey1 - oy).round() as isize);
 
     Some([a, b, c, d])
 }
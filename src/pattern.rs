@@ -0,0 +1,95 @@
+//! Repeating bitmap textures for filling shapes.
+//!
+//! A [`Fill`](crate::Fill) cannot hold a texture: [`Style`](crate::Style) and
+//! its parts are `Copy` and passed by value throughout the crate, while a
+//! texture owns its pixels. Shapes are therefore filled with a [`Pattern`]
+//! through [`Path::fill_pattern`] rather than through a [`Paint`](crate::Paint)
+//! variant, and strokes cannot be patterned.
+
+use std::path::Path as FsPath;
+
+use image::ImageResult;
+
+use crate::{Color, Path, Stage};
+
+/// A bitmap repeated over the world plane, for filling shapes with
+/// [`Path::fill_pattern`].
+///
+/// Texture pixel `(0, 0)` sits at `offset` and every pixel covers `scale`
+/// world units, sampled nearest neighbour. It is passed by reference
+/// rather than stored in a [`Style`](crate::Style), see the module docs.
+///
+/// Fields:
+/// - pixels: Vec<[[u8]; 4]> - row major RGBA texture.
+/// - width, height: [usize] - texture dimensions.
+/// - offset: ([f32], [f32]) - world coord of the texture top left corner.
+/// - scale: [f32] - world units per texture pixel.
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    pixels: Vec<[u8; 4]>,
+    width: usize,
+    height: usize,
+    offset: (f32, f32),
+    scale: f32,
+}
+
+impl Pattern {
+    /// Creates a [`Pattern`] from the pixels of `stage`.
+    pub fn from_stage(stage: &Stage) -> Self {
+        let (width, height) = stage.dimensions();
+        let pixels = (0..height).flat_map(|y| stage.row(y).iter().copied()).collect();
+        Self { pixels, width, height, offset: (0.0, 0.0), scale: 1.0 }
+    }
+
+    /// Loads the image at `path` as a [`Pattern`].
+    pub fn load<P: AsRef<FsPath>>(path: P) -> ImageResult<Self> {
+        let img = image::open(path)?.to_rgba8();
+        let (width, height) = (img.width() as usize, img.height() as usize);
+        let pixels = img.pixels().map(|px| px.0).collect();
+        Ok(Self { pixels, width, height, offset: (0.0, 0.0), scale: 1.0 })
+    }
+
+    /// Returns `self` with the texture top left corner at world coord `offset`.
+    pub fn with_offset(mut self, offset: (f32, f32)) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Returns `self` with each texture pixel covering `scale` world units.
+    /// Non-finite or non-positive scales are ignored.
+    pub fn with_scale(mut self, scale: f32) -> Self {
+        if scale.is_finite() && scale > 0.0 {
+            self.scale = scale;
+        }
+        self
+    }
+
+    /// Returns the texture dimensions `(width, height)`.
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    /// Returns the texture color at the world coord `p`, wrapping around
+    /// in both directions. Non-finite coords are transparent.
+    pub fn sample(&self, p: (f32, f32)) -> Color {
+        if self.pixels.is_empty() || !p.0.is_finite() || !p.1.is_finite() {
+            return Color::TRANSPARENT;
+        }
+        let u = ((p.0 - self.offset.0) / self.scale).floor() as i64;
+        let v = ((self.offset.1 - p.1) / self.scale).floor() as i64;
+        let x = u.rem_euclid(self.width as i64) as usize;
+        let y = v.rem_euclid(self.height as i64) as usize;
+        Color::new(self.pixels[y * self.width + x])
+    }
+}
+
+impl Path {
+    /// Fills the interior of `self` with `pattern`, like [`Path::fill_with`].
+    ///
+    /// Arguments:
+    /// - stage: &mut [`Stage`] - stage to draw onto.
+    /// - pattern: &[`Pattern`] - repeating texture.
+    pub fn fill_pattern(&self, stage: &mut Stage, pattern: &Pattern) {
+        self.fill_with(stage, |p| pattern.sample(p));
+    }
+}
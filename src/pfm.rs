@@ -0,0 +1,99 @@
+//! 32-bit float image export in the Portable Float Map (`pfm`) format, for
+//! data-bearing images such as coverage maps, SDFs and height fields.
+//!
+//! `pfm` stores raw little-endian floats with no compression, so values
+//! outside `[0.0, 1.0]` and negative distances survive unchanged.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path as FsPath;
+
+use image::error::{ImageError, ParameterError, ParameterErrorKind};
+use image::ImageResult;
+
+use crate::resample::srgb_to_linear;
+use crate::{Mask, Stage};
+
+/// Saves `data` as a single-channel `pfm`.
+///
+/// Returns `Err(ImageError::Parameter)` without creating the file if
+/// `data.len()` is not `width * height`.
+///
+/// Arguments:
+/// - path: destination file.
+/// - dimensions: ([usize], [usize]) - image `(width, height)`.
+/// - data: &[[f32]] - row major samples, top row first.
+pub fn save_gray<P: AsRef<FsPath>>(path: P, dimensions: (usize, usize), data: &[f32]) -> ImageResult<()> {
+    let w = dimensions.0;
+    check_len(dimensions, data.len())?;
+    write_pfm(path, dimensions, 1, |y, out| out.extend_from_slice(&data[y * w..(y + 1) * w]))
+}
+
+/// Saves `data` as a three-channel `pfm`.
+///
+/// Returns `Err(ImageError::Parameter)` without creating the file if
+/// `data.len()` is not `width * height`.
+///
+/// Arguments:
+/// - path: destination file.
+/// - dimensions: ([usize], [usize]) - image `(width, height)`.
+/// - data: &[[[f32]; 3]] - row major RGB samples, top row first.
+pub fn save_rgb<P: AsRef<FsPath>>(path: P, dimensions: (usize, usize), data: &[[f32; 3]]) -> ImageResult<()> {
+    let w = dimensions.0;
+    check_len(dimensions, data.len())?;
+    write_pfm(path, dimensions, 3, |y, out| out.extend(data[y * w..(y + 1) * w].iter().flatten()))
+}
+
+/// Checks that `len` samples fill a `pfm` of the given dimensions.
+fn check_len((w, h): (usize, usize), len: usize) -> ImageResult<()> {
+    if w.checked_mul(h) == Some(len) { return Ok(()); }
+    Err(ImageError::Parameter(ParameterError::from_kind(ParameterErrorKind::DimensionMismatch)))
+}
+
+/// Writes a `pfm` with `channels` floats per pixel, filling each row with
+/// `row(y, out)` for `y` counted from the top.
+fn write_pfm<P, F>(path: P, (w, h): (usize, usize), channels: usize, mut row: F) -> ImageResult<()>
+where
+    P: AsRef<FsPath>,
+    F: FnMut(usize, &mut Vec<f32>),
+{
+    let mut out = BufWriter::new(File::create(path)?);
+
+    // a negative scale marks little-endian samples
+    let tag = if channels == 1 { "Pf" } else { "PF" };
+    write!(out, "{tag}\n{w} {h}\n-1.0\n")?;
+
+    // rows are stored bottom to top
+    let mut samples = Vec::with_capacity(w * channels);
+    for y in (0..h).rev() {
+        samples.clear();
+        row(y, &mut samples);
+        for v in &samples {
+            out.write_all(&v.to_le_bytes())?;
+        }
+    }
+    out.flush()?;
+    Ok(())
+}
+
+impl Stage {
+    /// Saves a [`Stage`] as an RGB `pfm` in linear light, channels in
+    /// `[0.0, 1.0]`. Alpha is dropped.
+    pub fn save_pfm<P: AsRef<FsPath>>(&self, path: P) -> ImageResult<()> {
+        let decode: [f32; 256] = std::array::from_fn(|i| srgb_to_linear(i as f32 / 255.0));
+        write_pfm(path, self.dimensions(), 3, |y, out| {
+            out.extend(self.row(y).iter().flat_map(|px| [0, 1, 2].map(|c| decode[px[c] as usize])));
+        })
+    }
+}
+
+impl Mask {
+    /// Saves a [`Mask`] as a single-channel `pfm` with coverage in `[0.0, 1.0]`.
+    pub fn save_pfm<P: AsRef<FsPath>>(&self, path: P) -> ImageResult<()> {
+        let (w, _) = self.dimensions();
+        let data = self.data();
+        write_pfm(path, self.dimensions(), 1, |y, out| {
+            out.extend(data[y * w..(y + 1) * w].iter().map(|&c| c as f32 / 255.0));
+        })
+    }
+}
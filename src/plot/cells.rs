@@ -0,0 +1,28 @@
+use crate::grid::fill_runs;
+use crate::{Color, Rect, Stage};
+
+/// Draws a boolean cell grid, e.g. a cellular automaton generation, stretched
+/// over `rect` (pixel coords).
+///
+/// Each pixel row is written as one span per run of equal cells rather than
+/// one square per cell, so large mostly uniform grids render in a few fills
+/// per row. See [`grid::fill_cells`](crate::grid::fill_cells) for grids of
+/// more than two states.
+///
+/// Arguments:
+/// - stage: &mut [`Stage`] - stage to draw onto.
+/// - grid: &[[bool]] - row major cells, `true` for live.
+/// - dims: ([usize], [usize]) - `(cols, rows)` of the grid.
+/// - rect: [`Rect`] - grid extent in pixel coords.
+/// - on_color: [`Color`] - color of live cells.
+/// - off_color: [`Color`] - color of dead cells.
+pub fn cells(
+    stage: &mut Stage,
+    grid: &[bool],
+    dims: (usize, usize),
+    rect: Rect,
+    on_color: Color,
+    off_color: Color,
+) {
+    fill_runs(stage, grid, dims, rect, |on| Some(if on { on_color } else { off_color }));
+}
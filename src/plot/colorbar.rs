@@ -0,0 +1,79 @@
+use crate::{Color, Colormap, Rect, Stage, Style};
+
+/// Length in pixels of colorbar tick marks.
+const TICK_LEN: isize = 4;
+
+/// Draws a colorbar for `colormap` filling `rect` (pixel coords) with tick
+/// marks at the given data values.
+///
+/// The bar is vertical, with `range.1` at the top, if `rect` is at least as
+/// tall as it is wide, and horizontal, with `range.0` at the left, otherwise.
+/// Ticks extend outward from the right or bottom edge, and ticks outside
+/// `range` are skipped. The stroke of `style` draws a one pixel outline
+/// around the bar and the ticks. Tick labels are not drawn.
+///
+/// Arguments:
+/// - stage: &mut [`Stage`] - stage to draw onto.
+/// - rect: [`Rect`] - bar extent in pixel coords.
+/// - colormap: &[`Colormap`] - colormap to display.
+/// - range: ([f32], [f32]) - data values `(min, max)` mapped to the ends of the bar.
+/// - ticks: &[[f32]] - data values to mark.
+/// - style: [`Style`] - struct containing style args. Only the stroke is used.
+pub fn colorbar(
+    stage: &mut Stage,
+    rect: Rect,
+    colormap: &Colormap,
+    range: (f32, f32),
+    ticks: &[f32],
+    style: Style,
+) {
+    if rect.is_empty() { return; }
+
+    let (x0, y0) = (rect.x as isize, rect.y as isize);
+    let (x1, y1) = (rect.right() as isize - 1, rect.bottom() as isize - 1);
+    let vertical = rect.height >= rect.width;
+
+    if vertical {
+        let span = (rect.height - 1).max(1) as f32;
+        for y in y0..=y1 {
+            let color = colormap.sample(1.0 - (y - y0) as f32 / span);
+            stage.fill_span_pxl(y, x0, x1, color);
+        }
+    } else {
+        let span = (rect.width - 1).max(1) as f32;
+        for y in y0..=y1 {
+            stage.fill_span_with(y, x0, x1, |x| colormap.sample((x - x0) as f32 / span));
+        }
+    }
+
+    let Some(stroke) = style.stroke else { return; };
+    let color = stroke.rgba();
+
+    // outline just outside the bar so it doesn't cover the extreme colors
+    stage.fill_span_pxl(y0 - 1, x0 - 1, x1 + 1, color);
+    stage.fill_span_pxl(y1 + 1, x0 - 1, x1 + 1, color);
+    vline(stage, x0 - 1, y0, y1, color);
+    vline(stage, x1 + 1, y0, y1, color);
+
+    let (lo, hi) = range;
+    if !(lo.is_finite() && hi.is_finite()) || lo == hi { return; }
+
+    for &v in ticks {
+        let t = (v - lo) / (hi - lo);
+        if !(0.0..=1.0).contains(&t) { continue; }
+
+        if vertical {
+            let y = y1 - (t * (y1 - y0) as f32).round() as isize;
+            stage.fill_span_pxl(y, x1 + 2, x1 + 1 + TICK_LEN, color);
+        } else {
+            let x = x0 + (t * (x1 - x0) as f32).round() as isize;
+            vline(stage, x, y1 + 2, y1 + 1 + TICK_LEN, color);
+        }
+    }
+}
+
+fn vline(stage: &mut Stage, x: isize, y0: isize, y1: isize, color: Color) {
+    for y in y0..=y1 {
+        stage.plot_pxl(x, y, color);
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{Stage, Style, Path};
+use crate::plot::grid_to_world;
+
+/// Iso-line segment in fractional grid coords.
+pub(crate) type Segment = ((f32, f32), (f32, f32));
+
+/// Strokes the iso-lines of a 2D scalar `field` at each of the given `levels`
+/// using marching squares.
+///
+/// Arguments:
+/// - stage: &mut [`Stage`] - stage to draw onto.
+/// - field: &[[f32]] - row major scalar samples of length `cols * rows`.
+/// - dims: ([usize], [usize]) - grid dimensions `(cols, rows)`.
+/// - levels: &[[f32]] - iso values to trace.
+/// - style: [`Style`] - struct containing style args. Only the stroke is used.
+pub fn contour(
+    stage: &mut Stage,
+    field: &[f32],
+    dims: (usize, usize),
+    levels: &[f32],
+    style: Style,
+) {
+    if style.stroke.is_none() { return; }
+
+    let (cols, rows) = dims;
+    if cols < 2 || rows < 2 { return; }
+    if cols.checked_mul(rows) != Some(field.len()) { return; }
+
+    let mut segments: Vec<Segment> = Vec::new();
+    for &level in levels {
+        if !level.is_finite() { continue; }
+
+        segments.clear();
+        march(field, dims, level, &mut segments);
+
+        for &(a, b) in &segments {
+            let a = grid_to_world(stage, dims, a);
+            let b = grid_to_world(stage, dims, b);
+            Path::new(Vec::from([a, b]), false).render(stage, style);
+        }
+    }
+}
+
+/// Runs marching squares over `field` and appends the iso-line segments at
+/// `level` to `out` in fractional grid coords.
+///
+/// Cells containing a non-finite sample are skipped. Saddle cells are
+/// disambiguated using the average of the four corners.
+pub(crate) fn march(
+    field: &[f32],
+    dims: (usize, usize),
+    level: f32,
+    out: &mut Vec<Segment>,
+) {
+    let (cols, rows) = dims;
+    if cols < 2 || rows < 2 { return; }
+
+    for j in 0..rows - 1 {
+        for i in 0..cols - 1 {
+            // corners: top-left, top-right, bottom-right, bottom-left
+            let tl = field[j * cols + i];
+            let tr = field[j * cols + i + 1];
+            let br = field[(j + 1) * cols + i + 1];
+            let bl = field[(j + 1) * cols + i];
+
+            if !(tl.is_finite() && tr.is_finite() && br.is_finite() && bl.is_finite()) {
+                continue;
+            }
+
+            let mut case = 0u8;
+            if tl >= level { case |= 8; }
+            if tr >= level { case |= 4; }
+            if br >= level { case |= 2; }
+            if bl >= level { case |= 1; }
+
+            if case == 0 || case == 15 { continue; }
+
+            let (x, y) = (i as f32, j as f32);
+            let top    = (x + lerp_t(tl, tr, level), y);
+            let right  = (x + 1.0, y + lerp_t(tr, br, level));
+            let bottom = (x + lerp_t(bl, br, level), y + 1.0);
+            let left   = (x, y + lerp_t(tl, bl, level));
+
+            let center_above = (tl + tr + br + bl) * 0.25 >= level;
+
+            match case {
+                1 | 14 => out.push((left, bottom)),
+                2 | 13 => out.push((bottom, right)),
+                3 | 12 => out.push((left, right)),
+                4 | 11 => out.push((top, right)),
+                6 | 9  => out.push((top, bottom)),
+                7 | 8  => out.push((left, top)),
+                5 => {
+                    if center_above {
+                        out.push((left, top));
+                        out.push((bottom, right));
+                    } else {
+                        out.push((left, bottom));
+                        out.push((top, right));
+                    }
+                }
+                10 => {
+                    if center_above {
+                        out.push((left, bottom));
+                        out.push((top, right));
+                    } else {
+                        out.push((left, top));
+                        out.push((bottom, right));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Returns the interpolation parameter in `[0, 1]` where `level` crosses `a -> b`.
+#[inline(always)]
+pub(crate) fn lerp_t(a: f32, b: f32, level: f32) -> f32 {
+    let d = b - a;
+    if d == 0.0 { return 0.5; }
+    ((level - a) / d).clamp(0.0, 1.0)
+}
+
+/// Joins segments sharing endpoints into polylines.
+///
+/// Returns `(nodes, closed)` for each chain. Endpoints are matched exactly, which
+/// holds for segments produced by [`march`] since shared cell edges interpolate
+/// the same two samples.
+pub(crate) fn chain_segments(segments: &[Segment]) -> Vec<(Vec<(f32, f32)>, bool)> {
+    use std::collections::HashMap;
+
+    let key = |p: (f32, f32)| (p.0.to_bits(), p.1.to_bits());
+
+    let mut by_point: HashMap<(u32, u32), Vec<usize>> = HashMap::new();
+    for (i, &(a, b)) in segments.iter().enumerate() {
+        by_point.entry(key(a)).or_default().push(i);
+        by_point.entry(key(b)).or_default().push(i);
+    }
+
+    let mut used = vec![false; segments.len()];
+
+    // next unused segment touching `p`, oriented to start at `p`
+    let mut take_next = |p: (f32, f32), used: &mut Vec<bool>| -> Option<(f32, f32)> {
+        let candidates = by_point.get_mut(&key(p))?;
+        while let Some(i) = candidates.pop() {
+            if used[i] { continue; }
+            used[i] = true;
+
+            let (a, b) = segments[i];
+            return Some(if key(a) == key(p) { b } else { a });
+        }
+        None
+    };
+
+    let mut chains = Vec::new();
+    for start in 0..segments.len() {
+        if used[start] { continue; }
+        used[start] = true;
+
+        let (a, b) = segments[start];
+        let mut forward = Vec::from([a, b]);
+        while let Some(next) = take_next(forward[forward.len() - 1], &mut used) {
+            forward.push(next);
+        }
+
+        let closed = forward.len() > 2 && key(forward[0]) == key(forward[forward.len() - 1]);
+        if closed {
+            forward.pop();
+            chains.push((forward, true));
+            continue;
+        }
+
+        let mut backward = Vec::new();
+        while let Some(next) = take_next(*backward.last().unwrap_or(&a), &mut used) {
+            backward.push(next);
+        }
+
+        backward.reverse();
+        backward.extend(forward);
+        chains.push((backward, false));
+    }
+
+    chains
+}
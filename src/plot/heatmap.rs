@@ -0,0 +1,123 @@
+use crate::{Color, Colormap, Stage};
+
+/// How scalar samples are mapped onto `[0.0, 1.0]` before colormapping.
+///
+/// Non-finite samples always map to `NaN` and are left undrawn.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ColorScale {
+    /// Linear between the smallest and largest finite sample.
+    #[default]
+    Auto,
+    /// Linear between `min` and `max`. Samples outside are clipped.
+    Clip { min: f32, max: f32 },
+    /// Linear between the `lo` and `hi` percentiles (`0.0..=100.0`) of the
+    /// finite samples. Outliers beyond them are clipped.
+    Percentile { lo: f32, hi: f32 },
+    /// Histogram equalization: each sample maps to its rank among the finite
+    /// samples, so every color is used by roughly the same number of samples.
+    Equalize,
+}
+
+impl ColorScale {
+    /// Maps `values` onto `[0.0, 1.0]` according to `self`.
+    ///
+    /// Returns one entry per value. Non-finite values map to `NaN`. If all
+    /// finite values are equal, or the range is empty, they map to `0.5`.
+    pub fn normalize(&self, values: &[f32]) -> Vec<f32> {
+        let mut sorted: Vec<f32> = values.iter().copied().filter(|v| v.is_finite()).collect();
+        if sorted.is_empty() { return vec![f32::NAN; values.len()]; }
+        sorted.sort_by(f32::total_cmp);
+
+        let (min, max) = match *self {
+            ColorScale::Auto => (sorted[0], sorted[sorted.len() - 1]),
+            ColorScale::Clip { min, max } => (min, max),
+            ColorScale::Percentile { lo, hi } => (percentile(&sorted, lo), percentile(&sorted, hi)),
+            ColorScale::Equalize => return equalize(values, &sorted),
+        };
+
+        let span = max - min;
+        values
+            .iter()
+            .map(|&v| {
+                if !v.is_finite() { return f32::NAN; }
+                if !(span.is_finite() && span > 0.0) { return 0.5; }
+                ((v - min) / span).clamp(0.0, 1.0)
+            })
+            .collect()
+    }
+}
+
+/// Maps each of `values` to its normalized rank in ascending `sorted`.
+fn equalize(values: &[f32], sorted: &[f32]) -> Vec<f32> {
+    let n = sorted.len();
+    values
+        .iter()
+        .map(|&v| {
+            if !v.is_finite() { return f32::NAN; }
+            if n == 1 { return 0.5; }
+            // average rank of ties, so equal samples share a color
+            let below = sorted.partition_point(|&s| s < v);
+            let through = sorted.partition_point(|&s| s <= v);
+            (below + through - 1) as f32 / (2 * (n - 1)) as f32
+        })
+        .collect()
+}
+
+/// Linearly interpolated percentile `p` (`0.0..=100.0`) of ascending `sorted`.
+fn percentile(sorted: &[f32], p: f32) -> f32 {
+    let p = if p.is_finite() { p.clamp(0.0, 100.0) } else { 0.0 };
+    let pos = p / 100.0 * (sorted.len() - 1) as f32;
+    let i = pos.floor() as usize;
+    let j = (i + 1).min(sorted.len() - 1);
+    sorted[i] + (sorted[j] - sorted[i]) * (pos - i as f32)
+}
+
+/// Draws a 2D scalar `field` as a grid of colored cells covering the drawable
+/// area of `stage`.
+///
+/// Unlike [`contour`](crate::plot::contour), each sample owns a rectangular
+/// cell rather than sitting on a grid node. Non-finite samples are skipped.
+///
+/// Arguments:
+/// - stage: &mut [`Stage`] - stage to draw onto.
+/// - field: &[[f32]] - row major scalar samples of length `cols * rows`.
+/// - dims: ([usize], [usize]) - grid dimensions `(cols, rows)`.
+/// - colormap: &[`Colormap`] - maps normalized samples to colors.
+/// - scale: [`ColorScale`] - how samples are normalized before colormapping.
+pub fn heatmap(
+    stage: &mut Stage,
+    field: &[f32],
+    dims: (usize, usize),
+    colormap: &Colormap,
+    scale: ColorScale,
+) {
+    let (cols, rows) = dims;
+    if cols == 0 || rows == 0 { return; }
+    if cols.checked_mul(rows) != Some(field.len()) { return; }
+
+    let colors: Vec<_> = scale
+        .normalize(field)
+        .into_iter()
+        .map(|t| (!t.is_nan()).then(|| colormap.sample(t)))
+        .collect();
+
+    fill_grid_cells(stage, dims, &colors);
+}
+
+/// Fills one cell of the drawable area of `stage` per entry of the row major
+/// `colors` of a `dims` grid, skipping `None`.
+pub(crate) fn fill_grid_cells(stage: &mut Stage, (cols, rows): (usize, usize), colors: &[Option<Color>]) {
+    let r = stage.draw_rect();
+    let (w, h) = (r.width, r.height);
+    for y in 0..h {
+        let row = y * rows / h;
+        for i in 0..cols {
+            // cell `i` covers pixels [i * w / cols, (i + 1) * w / cols)
+            let (x0, x1) = (r.x + i * w / cols, r.x + (i + 1) * w / cols);
+            if x1 == x0 { continue; }
+            if let Some(c) = colors[row * cols + i] {
+                stage.fill_span_pxl((r.y + y) as isize, x0 as isize, x1 as isize - 1, c);
+            }
+        }
+    }
+}
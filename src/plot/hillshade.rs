@@ -0,0 +1,97 @@
+use crate::{BlendMode, Color, Stage};
+use crate::plot::heatmap::fill_grid_cells;
+
+/// Options for [`hillshade`] and [`hillshade_field`].
+#[derive(Debug, Clone, Copy)]
+pub struct HillshadeOptions {
+    /// Direction the light comes from, in degrees clockwise from the top of
+    /// the grid. The cartographic default `315.0` lights from the upper left.
+    pub azimuth: f32,
+    /// Height of the light above the horizon in degrees.
+    pub altitude: f32,
+    /// Height units per cell spacing. Raise it to exaggerate gentle relief.
+    pub z_factor: f32,
+    /// How much of the shading [`hillshade`] multiplies in, in `[0.0, 1.0]`.
+    pub strength: f32,
+}
+
+impl Default for HillshadeOptions {
+    fn default() -> Self {
+        Self { azimuth: 315.0, altitude: 45.0, z_factor: 1.0, strength: 1.0 }
+    }
+}
+
+/// Returns the Lambert shading of the height `field` lit as described by
+/// `options`, one value in `[0.0, 1.0]` per sample.
+///
+/// Slopes come from central differences of the neighbouring samples, one
+/// sided at the edges. Non-finite samples map to `NaN` and non-finite
+/// neighbours count as level with the sample.
+///
+/// Arguments:
+/// - field: &[[f32]] - row major heights of length `cols * rows`, first row at the top.
+/// - dims: ([usize], [usize]) - grid dimensions `(cols, rows)`.
+/// - options: [`HillshadeOptions`] - light direction and relief scale.
+pub fn hillshade_field(field: &[f32], dims: (usize, usize), options: HillshadeOptions) -> Vec<f32> {
+    let (cols, rows) = dims;
+    if cols.checked_mul(rows) != Some(field.len()) { return Vec::new(); }
+
+    let (az, alt) = (options.azimuth.to_radians(), options.altitude.to_radians());
+    let light = (az.sin() * alt.cos(), az.cos() * alt.cos(), alt.sin());
+
+    let mut shade = Vec::with_capacity(field.len());
+    for r in 0..rows {
+        for c in 0..cols {
+            let z = field[r * cols + c];
+            if !z.is_finite() {
+                shade.push(f32::NAN);
+                continue;
+            }
+
+            let at = |r: usize, c: usize| Some(field[r * cols + c]).filter(|v| v.is_finite()).unwrap_or(z);
+            let slope = |lo: f32, hi: f32, span: usize| if span > 0 { (hi - lo) / span as f32 } else { 0.0 };
+            let (c0, c1) = (c.saturating_sub(1), (c + 1).min(cols - 1));
+            let (r0, r1) = (r.saturating_sub(1), (r + 1).min(rows - 1));
+
+            // rows grow downward, so the slope toward the top is r0 minus r1
+            let dx = slope(at(r, c0), at(r, c1), c1 - c0) * options.z_factor;
+            let dy = slope(at(r1, c), at(r0, c), r1 - r0) * options.z_factor;
+
+            let len = (dx * dx + dy * dy + 1.0).sqrt();
+            let lambert = (-dx * light.0 - dy * light.1 + light.2) / len;
+            shade.push(lambert.clamp(0.0, 1.0));
+        }
+    }
+    shade
+}
+
+/// Multiplies the hillshading of the height `field` into the drawable area
+/// of `stage`, cell for cell like [`heatmap`](crate::plot::heatmap), so a
+/// heatmap drawn first gains relief.
+///
+/// ```ignore
+/// plot::heatmap(&mut stage, &elevation, dims, &Colormap::viridis(), ColorScale::Auto);
+/// plot::hillshade(&mut stage, &elevation, dims, HillshadeOptions { z_factor: 4.0, ..Default::default() });
+/// ```
+///
+/// Arguments:
+/// - stage: &mut [`Stage`] - stage to shade.
+/// - field: &[[f32]] - row major heights of length `cols * rows`, first row at the top.
+/// - dims: ([usize], [usize]) - grid dimensions `(cols, rows)`.
+/// - options: [`HillshadeOptions`] - light direction, relief scale and strength.
+pub fn hillshade(stage: &mut Stage, field: &[f32], dims: (usize, usize), options: HillshadeOptions) {
+    let (cols, rows) = dims;
+    if cols == 0 || rows == 0 { return; }
+
+    let alpha = (options.strength.clamp(0.0, 1.0) * 255.0).round() as u8;
+    let colors: Vec<_> = hillshade_field(field, dims, options)
+        .into_iter()
+        .map(|s| {
+            let v = (s * 255.0).round() as u8;
+            (!s.is_nan()).then_some(Color::new([v, v, v, alpha]))
+        })
+        .collect();
+    if colors.is_empty() { return; }
+
+    stage.with_blend(Some(BlendMode::Multiply), |stage| fill_grid_cells(stage, dims, &colors));
+}
@@ -0,0 +1,132 @@
+use crate::{Colormap, FillRule, Path, Stage, Style};
+use crate::plot::grid_to_world;
+
+/// Fills the bands between consecutive `levels` of a 2D scalar `field`, colored
+/// by `colormap`, producing a filled contour plot.
+///
+/// Each grid cell is clipped to every band with marching squares style edge
+/// interpolation, so band boundaries coincide with the iso-lines drawn by
+/// [`contour`](crate::plot::contour) at the same levels. The pieces of a band
+/// are filled together as one [`Path`] under [`FillRule::NonZero`], so edges
+/// shared by neighbouring cells leave no seams. Band `i` of `n` is
+/// colored with `colormap.sample((i + 0.5) / n)`. Samples outside the outer
+/// levels are left unfilled.
+///
+/// Arguments:
+/// - stage: &mut [`Stage`] - stage to draw onto.
+/// - field: &[[f32]] - row major scalar samples of length `cols * rows`.
+/// - dims: ([usize], [usize]) - grid dimensions `(cols, rows)`.
+/// - levels: &[[f32]] - band edges, sorted ascending internally.
+/// - colormap: &[`Colormap`] - band colors.
+pub fn isobands(
+    stage: &mut Stage,
+    field: &[f32],
+    dims: (usize, usize),
+    levels: &[f32],
+    colormap: &Colormap,
+) {
+    let (cols, rows) = dims;
+    if cols < 2 || rows < 2 { return; }
+    if cols.checked_mul(rows) != Some(field.len()) { return; }
+
+    let mut levels: Vec<f32> = levels.iter().copied().filter(|l| l.is_finite()).collect();
+    levels.sort_by(f32::total_cmp);
+    levels.dedup();
+    if levels.len() < 2 { return; }
+
+    let bands = levels.len() - 1;
+    for (b, w) in levels.windows(2).enumerate() {
+        let style = Style::fill_only(colormap.sample((b as f32 + 0.5) / bands as f32));
+
+        let mut pieces = band_polygons(field, dims, w[0], w[1], b + 1 == bands)
+            .into_iter()
+            .map(|polygon| polygon.into_iter().map(|p| grid_to_world(stage, dims, p)).collect());
+        let Some(first) = pieces.next() else { continue; };
+
+        // every piece winds the same way, so shared edges cancel out
+        let band = Path::with_holes(first, pieces.collect()).with_fill_rule(FillRule::NonZero);
+        band.render(stage, style);
+    }
+}
+
+/// Returns one polygon per cell, in fractional grid coords, covering the part
+/// of the cell where the bilinear field lies in `[lo, hi)` (`[lo, hi]` if
+/// `inclusive`). Cells with non-finite samples are skipped.
+pub(crate) fn band_polygons(
+    field: &[f32],
+    dims: (usize, usize),
+    lo: f32,
+    hi: f32,
+    inclusive: bool,
+) -> Vec<Vec<(f32, f32)>> {
+    let (cols, rows) = dims;
+    let mut out = Vec::new();
+    let mut scratch = Vec::with_capacity(8);
+
+    for j in 0..rows - 1 {
+        for i in 0..cols - 1 {
+            let (x, y) = (i as f32, j as f32);
+            let cell = [
+                ((x, y), field[j * cols + i]),
+                ((x + 1.0, y), field[j * cols + i + 1]),
+                ((x + 1.0, y + 1.0), field[(j + 1) * cols + i + 1]),
+                ((x, y + 1.0), field[(j + 1) * cols + i]),
+            ];
+            if cell.iter().any(|(_, v)| !v.is_finite()) { continue; }
+
+            let below = cell.iter().all(|&(_, v)| v < lo);
+            let above = cell.iter().all(|&(_, v)| if inclusive { v > hi } else { v >= hi });
+            if below || above { continue; }
+
+            let inside = cell.iter().all(|&(_, v)| v >= lo && if inclusive { v <= hi } else { v < hi });
+            if inside {
+                out.push(cell.iter().map(|&(p, _)| p).collect());
+                continue;
+            }
+
+            scratch.clear();
+            scratch.extend_from_slice(&cell);
+            clip_above(&mut scratch, lo);
+            clip_below(&mut scratch, hi);
+            if scratch.len() >= 3 {
+                out.push(scratch.iter().map(|&(p, _)| p).collect());
+            }
+        }
+    }
+
+    out
+}
+
+type Vertex = ((f32, f32), f32);
+
+/// Keeps the part of polygon `poly` where the interpolated value is `>= level`.
+fn clip_above(poly: &mut Vec<Vertex>, level: f32) {
+    clip(poly, |v| v - level);
+}
+
+/// Keeps the part of polygon `poly` where the interpolated value is `<= level`.
+fn clip_below(poly: &mut Vec<Vertex>, level: f32) {
+    clip(poly, |v| level - v);
+}
+
+/// Sutherland-Hodgman clip of `poly` against `side(value) >= 0`, with values
+/// interpolated linearly along edges.
+fn clip<F: Fn(f32) -> f32>(poly: &mut Vec<Vertex>, side: F) {
+    let input = std::mem::take(poly);
+    let n = input.len();
+
+    for k in 0..n {
+        let (p, vp) = input[k];
+        let (q, vq) = input[(k + 1) % n];
+        let (sp, sq) = (side(vp), side(vq));
+
+        if sp >= 0.0 {
+            poly.push((p, vp));
+        }
+        if (sp >= 0.0) != (sq >= 0.0) {
+            let t = sp / (sp - sq);
+            let point = (p.0 + (q.0 - p.0) * t, p.1 + (q.1 - p.1) * t);
+            poly.push((point, vp + (vq - vp) * t));
+        }
+    }
+}
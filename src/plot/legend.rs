@@ -0,0 +1,114 @@
+use crate::{Color, Rect, Stage, Style};
+
+/// Legend listing colored swatches with labels, built entry by entry.
+///
+/// Only the swatches are drawn; the crate has no text rendering yet, so the
+/// labels are carried alongside for callers that place them separately.
+///
+/// ```ignore
+/// let legend = Legend::new()
+///     .entry(theme.series_color(0), "measured")
+///     .entry(theme.series_color(1), "model");
+/// legend.render(&mut stage, (10, 10), theme.foreground_style());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Legend {
+    entries: Vec<(Color, String)>,
+    swatch: usize,
+    gap: usize,
+}
+
+impl Default for Legend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Legend {
+    /// Creates an empty [`Legend`] with 12 pixel swatches 4 pixels apart.
+    pub fn new() -> Self {
+        Self { entries: Vec::new(), swatch: 12, gap: 4 }
+    }
+
+    /// Appends an entry with swatch `color` and `label`.
+    pub fn entry(mut self, color: Color, label: impl Into<String>) -> Self {
+        self.entries.push((color, label.into()));
+        self
+    }
+
+    /// Sets the swatch side length in pixels.
+    pub fn swatch_size(mut self, size: usize) -> Self {
+        self.swatch = size.max(1);
+        self
+    }
+
+    /// Sets the spacing in pixels between swatches and around the legend.
+    pub fn gap(mut self, gap: usize) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Returns the `(color, label)` entries in order.
+    pub fn entries(&self) -> &[(Color, String)] {
+        &self.entries
+    }
+
+    /// Returns the pixel rect of swatch `index` when the legend is rendered
+    /// at `top_left`, or `None` if there is no such entry.
+    ///
+    /// Useful for placing the label of each entry beside its swatch.
+    pub fn swatch_rect(&self, top_left: (usize, usize), index: usize) -> Option<Rect> {
+        if index >= self.entries.len() { return None; }
+        let (x, y) = top_left;
+        let step = self.swatch + self.gap;
+        Some(Rect::new(x + self.gap, y + self.gap + index * step, self.swatch, self.swatch))
+    }
+
+    /// Returns the `(width, height)` in pixels of the legend box.
+    pub fn size(&self) -> (usize, usize) {
+        let n = self.entries.len();
+        if n == 0 { return (0, 0); }
+        (self.swatch + 2 * self.gap, n * self.swatch + (n + 1) * self.gap)
+    }
+
+    /// Draws the legend with its top left corner at pixel `top_left`.
+    ///
+    /// The fill of `style`, if any, paints the legend box behind the swatches
+    /// and the stroke, if any, outlines each swatch.
+    ///
+    /// Arguments:
+    /// - stage: &mut [`Stage`] - stage to draw onto.
+    /// - top_left: ([usize], [usize]) - top left corner in pixel coords.
+    /// - style: [`Style`] - struct containing style args.
+    pub fn render(&self, stage: &mut Stage, top_left: (usize, usize), style: Style) {
+        let (w, h) = self.size();
+        if w == 0 { return; }
+
+        if let Some(fill) = style.fill {
+            let (x, y) = (top_left.0 as isize, top_left.1 as isize);
+            for row in y..y + h as isize {
+                stage.fill_span_pxl(row, x, x + w as isize - 1, fill.rgba());
+            }
+        }
+
+        for (i, &(color, _)) in self.entries.iter().enumerate() {
+            let Some(r) = self.swatch_rect(top_left, i) else { continue; };
+            let (x0, y0) = (r.x as isize, r.y as isize);
+            let (x1, y1) = (r.right() as isize - 1, r.bottom() as isize - 1);
+
+            for y in y0..=y1 {
+                stage.fill_span_pxl(y, x0, x1, color);
+            }
+
+            if let Some(stroke) = style.stroke {
+                let c = stroke.rgba();
+                stage.fill_span_pxl(y0, x0, x1, c);
+                stage.fill_span_pxl(y1, x0, x1, c);
+                for y in y0..=y1 {
+                    stage.plot_pxl(x0, y, c);
+                    stage.plot_pxl(x1, y, c);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//! Plotting helpers for scientific output built on top of [`Path`](crate::Path).
+//!
+//! Scalar fields are row major `&[f32]` slices of size `cols * rows` where
+//! `dims = (cols, rows)`. The first row is drawn at the top of the [`Stage`](crate::Stage)
+//! and the grid is stretched across the drawable area: the whole stage, or the
+//! current scissor rect, e.g. inside an [`InsetStage`](crate::InsetStage).
+
+pub mod cells;
+pub use cells::cells;
+pub mod colorbar;
+pub use colorbar::colorbar;
+pub mod contour;
+pub use contour::contour;
+pub mod heatmap;
+pub use heatmap::{heatmap, ColorScale};
+pub mod hillshade;
+pub use hillshade::{hillshade, hillshade_field, HillshadeOptions};
+pub mod isobands;
+pub use isobands::isobands;
+pub mod legend;
+pub use legend::Legend;
+pub mod quiver;
+pub use quiver::{quiver, QuiverOptions};
+pub mod sparkline;
+pub use sparkline::{sparkline, SparklineKind, SparklineOptions};
+pub mod streamlines;
+pub use streamlines::{streamline, streamlines, StreamlineOptions};
+pub mod strip_chart;
+pub use strip_chart::StripChart;
+pub mod surface;
+pub use surface::surface;
+
+use crate::Stage;
+
+/// Maps a fractional grid coord `(gx, gy)` of a `dims` grid stretched over
+/// the drawable area of `stage` into world coords.
+pub(crate) fn grid_to_world(stage: &Stage, dims: (usize, usize), (gx, gy): (f32, f32)) -> (f32, f32) {
+    let (cols, rows) = dims;
+    let r = stage.draw_rect();
+    let (w, h) = (r.width as f32, r.height as f32);
+
+    let sx = if cols > 1 { (w - 1.0) / (cols as f32 - 1.0) } else { 0.0 };
+    let sy = if rows > 1 { (h - 1.0) / (rows as f32 - 1.0) } else { 0.0 };
+
+    stage.pxl_to_world_f32((r.x as f32 + gx * sx, r.y as f32 + gy * sy))
+}
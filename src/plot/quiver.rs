@@ -0,0 +1,54 @@
+use crate::shapes::arrows::render_arrow;
+use crate::shapes::{ArrowHead, ArrowStyle};
+use crate::{Stage, Style};
+use crate::plot::grid_to_world;
+
+/// Parameters for [`quiver`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuiverOptions {
+    /// Arrow length in grid cells per unit of vector magnitude.
+    pub scale: f32,
+    /// Draws an arrow on every `stride`-th grid point along each axis.
+    pub stride: usize,
+    /// End decorations of each arrow.
+    pub arrow: ArrowStyle,
+}
+
+impl Default for QuiverOptions {
+    fn default() -> Self {
+        Self { scale: 0.9, stride: 1, arrow: ArrowStyle::new(ArrowHead::Triangle, 5.0) }
+    }
+}
+
+/// Draws a 2D vector `field` as arrows starting on its grid points.
+///
+/// Each vector is `(vx, vy)` in grid cells with `+y` pointing up, matching
+/// world coords, and is drawn `options.scale` times its length. Zero and
+/// non-finite vectors are skipped; arrows too short for their decorations
+/// are drawn as plain lines.
+///
+/// Arguments:
+/// - stage: &mut [`Stage`] - stage to draw onto.
+/// - field: &[([f32], [f32])] - row major vectors of length `cols * rows`.
+/// - dims: ([usize], [usize]) - grid dimensions `(cols, rows)`.
+/// - options: [`QuiverOptions`] - scale, density and arrow style.
+/// - style: [`Style`] - struct containing style args. Only the stroke is used;
+///   filled arrowheads use the stroke color.
+pub fn quiver(stage: &mut Stage, field: &[(f32, f32)], dims: (usize, usize), options: QuiverOptions, style: Style) {
+    let (cols, rows) = dims;
+    if style.stroke.is_none() || field.len() < cols * rows { return; }
+    if !options.scale.is_finite() { return; }
+    let stride = options.stride.max(1);
+
+    for row in (0..rows).step_by(stride) {
+        for col in (0..cols).step_by(stride) {
+            let (vx, vy) = field[row * cols + col];
+            if !(vx.is_finite() && vy.is_finite()) || (vx == 0.0 && vy == 0.0) { continue; }
+
+            let (gx, gy) = (col as f32, row as f32);
+            let from = grid_to_world(stage, dims, (gx, gy));
+            let to = grid_to_world(stage, dims, (gx + vx * options.scale, gy - vy * options.scale));
+            render_arrow(stage, &[from, to], options.arrow, style);
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{Color, Path, Rect, Stage, Style};
+
+/// Half side in pixels of the min and max markers.
+const MARKER_HALF: isize = 1;
+
+/// How [`sparkline`] draws its values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SparklineKind {
+    /// Polyline through the values, drawn with the stroke.
+    #[default]
+    Line,
+    /// One bar per value from the baseline, drawn with the fill.
+    Bars,
+}
+
+/// Options for [`sparkline`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SparklineOptions {
+    /// Line or bars.
+    pub kind: SparklineKind,
+    /// Value of a horizontal baseline drawn across the rect with the stroke,
+    /// also the base of bars. Bars start at the bottom edge if `None`.
+    pub baseline: Option<f32>,
+    /// Color of a small square marking the lowest value.
+    pub min_marker: Option<Color>,
+    /// Color of a small square marking the highest value.
+    pub max_marker: Option<Color>,
+}
+
+/// Draws a minimal sparkline of `values` auto-scaled to fill `rect` (pixel
+/// coords), first value at the left edge.
+///
+/// The vertical range spans the finite values and the baseline, if any.
+/// Non-finite values break the line and draw no bar.
+///
+/// Arguments:
+/// - stage: &mut [`Stage`] - stage to draw onto.
+/// - rect: [`Rect`] - sparkline extent in pixel coords.
+/// - values: &[[f32]] - samples in order.
+/// - options: [`SparklineOptions`] - kind, baseline and markers.
+/// - style: [`Style`] - stroke for the line and baseline, fill for bars.
+pub fn sparkline(
+    stage: &mut Stage,
+    rect: Rect,
+    values: &[f32],
+    options: SparklineOptions,
+    style: Style,
+) {
+    if rect.is_empty() || values.is_empty() { return; }
+
+    let finite = values.iter().copied().filter(|v| v.is_finite());
+    let baseline = options.baseline.filter(|b| b.is_finite());
+    let Some((lo, hi)) = finite.chain(baseline).fold(None, |acc: Option<(f32, f32)>, v| {
+        Some(acc.map_or((v, v), |(lo, hi)| (lo.min(v), hi.max(v))))
+    }) else { return; };
+
+    let (x0, y1) = (rect.x as isize, rect.bottom() as isize - 1);
+    let (w, h) = ((rect.width - 1) as f32, (rect.height - 1) as f32);
+    let n = values.len();
+
+    let x_of = |i: usize| x0 + if n > 1 { (i as f32 * w / (n - 1) as f32).round() as isize } else { 0 };
+    let y_of = |v: f32| {
+        let t = if hi > lo { (v - lo) / (hi - lo) } else { 0.5 };
+        y1 - (t * h).round() as isize
+    };
+
+    match options.kind {
+        SparklineKind::Line => {
+            let mut run: Vec<(isize, isize)> = Vec::with_capacity(n);
+            for (i, &v) in values.iter().enumerate() {
+                if v.is_finite() {
+                    run.push((x_of(i), y_of(v)));
+                } else {
+                    Path::render_pxl(&run, false, stage, Style { fill: None, ..style });
+                    run.clear();
+                }
+            }
+            Path::render_pxl(&run, false, stage, Style { fill: None, ..style });
+        }
+        SparklineKind::Bars => {
+            if let Some(fill) = style.fill {
+                let color = fill.rgba();
+                let base = baseline.map_or(y1, y_of);
+                for (i, &v) in values.iter().enumerate() {
+                    if !v.is_finite() { continue; }
+
+                    // each bar spans from its own column to just before the next
+                    let (bx0, bx1) = (x_of(i), if i + 1 < n { x_of(i + 1) - 1 } else { x_of(i) });
+                    let y = y_of(v);
+                    for row in y.min(base)..=y.max(base) {
+                        stage.fill_span_pxl(row, bx0, bx1.max(bx0), color);
+                    }
+                }
+            }
+        }
+    }
+
+    if let (Some(b), Some(stroke)) = (baseline, style.stroke) {
+        stage.fill_span_pxl(y_of(b), x0, rect.right() as isize - 1, stroke.rgba());
+    }
+
+    let extreme = |pick: fn(f32, f32) -> bool| {
+        values
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| v.is_finite())
+            .reduce(|a, b| if pick(*b.1, *a.1) { b } else { a })
+            .map(|(i, &v)| (x_of(i), y_of(v)))
+    };
+    let markers = [
+        (options.min_marker, extreme(|a, b| a < b)),
+        (options.max_marker, extreme(|a, b| a > b)),
+    ];
+    for (color, at) in markers {
+        let (Some(color), Some((x, y))) = (color, at) else { continue; };
+        for row in y - MARKER_HALF..=y + MARKER_HALF {
+            stage.fill_span_pxl(row, x - MARKER_HALF, x + MARKER_HALF, color);
+        }
+    }
+}
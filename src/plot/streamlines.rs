@@ -0,0 +1,267 @@
+use crate::shapes::ArrowHead;
+use crate::{Path, Stage, Style};
+use crate::plot::grid_to_world;
+
+/// Parameters for [`streamlines`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StreamlineOptions {
+    /// Integration step in grid cells.
+    pub step: f32,
+    /// Largest number of steps traced in each direction from a seed.
+    pub max_steps: usize,
+    /// Smallest distance in grid cells kept between neighbouring streamlines,
+    /// at least [`MIN_SEPARATION`]. Seeds are also placed on a lattice with
+    /// this spacing.
+    pub separation: f32,
+    /// Decoration drawn at the middle of each streamline pointing along the
+    /// flow, [`ArrowHead::None`] for plain lines.
+    pub arrow: ArrowHead,
+    /// Length of the decoration in world units.
+    pub arrow_size: f32,
+}
+
+impl Default for StreamlineOptions {
+    fn default() -> Self {
+        Self { step: 0.2, max_steps: 2000, separation: 1.0, arrow: ArrowHead::Triangle, arrow_size: 6.0 }
+    }
+}
+
+/// Traces and strokes evenly spaced streamlines of a 2D vector `field`.
+///
+/// Each vector is `(vx, vy)` in grid cells per unit time with `+y` pointing up,
+/// matching world coords. Streamlines are integrated with fourth order
+/// Runge-Kutta along the normalized flow and stop when they leave the grid,
+/// reach a stagnation point, or come within `options.separation` of an
+/// already drawn streamline.
+///
+/// Arguments:
+/// - stage: &mut [`Stage`] - stage to draw onto.
+/// - field: &[([f32], [f32])] - row major vectors of length `cols * rows`.
+/// - dims: ([usize], [usize]) - grid dimensions `(cols, rows)`.
+/// - options: [`StreamlineOptions`] - integration and density parameters.
+/// - style: [`Style`] - struct containing style args. Only the stroke is used;
+///   filled arrowheads use the stroke color.
+pub fn streamlines(
+    stage: &mut Stage,
+    field: &[(f32, f32)],
+    dims: (usize, usize),
+    options: StreamlineOptions,
+    style: Style,
+) {
+    let Some(stroke) = style.stroke else { return; };
+
+    let line_style = Style { fill: None, stroke: Some(stroke), ..style };
+
+    for line in trace_streamlines(field, dims, options) {
+        let nodes: Vec<(f32, f32)> = line.iter().map(|&p| grid_to_world(stage, dims, p)).collect();
+        let path = Path::new(nodes, false);
+
+        if let Some((tip, dir)) = arrow_tip(&path, options.arrow_size) {
+            options.arrow.render(stage, tip, dir, options.arrow_size, line_style);
+        }
+        path.render(stage, line_style);
+    }
+}
+
+/// Traces a single streamline of `field` through `seed`, in fractional grid
+/// coords, and returns it as a [`Path`] in world coords.
+///
+/// Returns `None` if `seed` lies outside the grid or at a stagnation point.
+///
+/// Arguments:
+/// - stage: &[`Stage`] - stage used to map grid coords to world coords.
+/// - field: &[([f32], [f32])] - row major vectors of length `cols * rows`.
+/// - dims: ([usize], [usize]) - grid dimensions `(cols, rows)`.
+/// - seed: ([f32], [f32]) - starting point in grid coords, `(0, 0)` is the top left sample.
+/// - options: [`StreamlineOptions`] - integration parameters. `separation` is ignored.
+pub fn streamline(
+    stage: &Stage,
+    field: &[(f32, f32)],
+    dims: (usize, usize),
+    seed: (f32, f32),
+    options: StreamlineOptions,
+) -> Option<Path> {
+    let flow = Flow::new(field, dims)?;
+    let line = flow.trace(seed, options, |_| true)?;
+    let nodes = line.into_iter().map(|p| grid_to_world(stage, dims, p)).collect();
+    Some(Path::new(nodes, false))
+}
+
+/// Smallest [`StreamlineOptions::separation`], in grid cells. Closer lines
+/// would be finer than the integration step resolves, and the occupancy
+/// grid would grow with the inverse square of the separation.
+pub const MIN_SEPARATION: f32 = 0.1;
+
+/// Seeds streamlines on a lattice and traces them with the given separation,
+/// returning polylines in fractional grid coords.
+pub(crate) fn trace_streamlines(
+    field: &[(f32, f32)],
+    dims: (usize, usize),
+    options: StreamlineOptions,
+) -> Vec<Vec<(f32, f32)>> {
+    let Some(flow) = Flow::new(field, dims) else { return Vec::new(); };
+    let sep = if options.separation.is_finite() && options.separation > 0.0 { options.separation } else { 1.0 };
+    let sep = sep.max(MIN_SEPARATION);
+
+    let (cols, rows) = dims;
+    let Some(mut occupancy) = Occupancy::new((cols - 1) as f32, (rows - 1) as f32, sep) else { return Vec::new(); };
+    let mut lines = Vec::new();
+
+    let (nx, ny) = occupancy.dims;
+    for cy in 0..ny {
+        for cx in 0..nx {
+            let seed = ((cx as f32 + 0.5) * sep, (cy as f32 + 0.5) * sep);
+            if occupancy.is_taken(seed) { continue; }
+
+            let Some(line) = flow.trace(seed, options, |p| !occupancy.is_taken(p)) else { continue; };
+            for &p in &line {
+                occupancy.take(p);
+            }
+            lines.push(line);
+        }
+    }
+
+    lines
+}
+
+/// Bilinearly interpolated vector field over grid coords.
+struct Flow<'a> {
+    field: &'a [(f32, f32)],
+    cols: usize,
+    rows: usize,
+}
+
+impl<'a> Flow<'a> {
+    fn new(field: &'a [(f32, f32)], (cols, rows): (usize, usize)) -> Option<Self> {
+        if cols < 2 || rows < 2 { return None; }
+        if cols.checked_mul(rows) != Some(field.len()) { return None; }
+        Some(Self { field, cols, rows })
+    }
+
+    /// Returns the unit flow direction at grid coord `p`, with `+y` pointing
+    /// down the grid. `None` outside the grid, at non-finite samples, or where
+    /// the flow vanishes.
+    fn direction(&self, (gx, gy): (f32, f32)) -> Option<(f32, f32)> {
+        let (max_x, max_y) = ((self.cols - 1) as f32, (self.rows - 1) as f32);
+        if !(gx >= 0.0 && gx <= max_x && gy >= 0.0 && gy <= max_y) { return None; }
+
+        let i = (gx.floor() as usize).min(self.cols - 2);
+        let j = (gy.floor() as usize).min(self.rows - 2);
+        let (fx, fy) = (gx - i as f32, gy - j as f32);
+
+        let at = |i: usize, j: usize| self.field[j * self.cols + i];
+        let (a, b, c, d) = (at(i, j), at(i + 1, j), at(i, j + 1), at(i + 1, j + 1));
+        let lerp = |p: f32, q: f32, t: f32| p + (q - p) * t;
+
+        let vx = lerp(lerp(a.0, b.0, fx), lerp(c.0, d.0, fx), fy);
+        let vy = lerp(lerp(a.1, b.1, fx), lerp(c.1, d.1, fx), fy);
+
+        let len = (vx * vx + vy * vy).sqrt();
+        if !len.is_finite() || len < 1e-6 { return None; }
+        Some((vx / len, -vy / len))
+    }
+
+    /// One RK4 step of length `h` from `p`.
+    fn rk4(&self, p: (f32, f32), h: f32) -> Option<(f32, f32)> {
+        let at = |k: (f32, f32), s: f32| (p.0 + k.0 * s, p.1 + k.1 * s);
+
+        let k1 = self.direction(p)?;
+        let k2 = self.direction(at(k1, 0.5 * h))?;
+        let k3 = self.direction(at(k2, 0.5 * h))?;
+        let k4 = self.direction(at(k3, h))?;
+
+        Some((
+            p.0 + h / 6.0 * (k1.0 + 2.0 * k2.0 + 2.0 * k3.0 + k4.0),
+            p.1 + h / 6.0 * (k1.1 + 2.0 * k2.1 + 2.0 * k3.1 + k4.1),
+        ))
+    }
+
+    /// Traces backwards then forwards from `seed` while `free` accepts each
+    /// new point. Returns the polyline ordered along the flow.
+    fn trace<F: Fn((f32, f32)) -> bool>(
+        &self,
+        seed: (f32, f32),
+        options: StreamlineOptions,
+        free: F,
+    ) -> Option<Vec<(f32, f32)>> {
+        self.direction(seed)?;
+        let step = if options.step.is_finite() && options.step > 0.0 { options.step } else { 0.2 };
+
+        let mut backward = self.walk(seed, -step, options.max_steps, &free);
+        let forward = self.walk(seed, step, options.max_steps, &free);
+
+        backward.reverse();
+        backward.push(seed);
+        backward.extend(forward);
+
+        if backward.len() < 2 { return None; }
+        Some(backward)
+    }
+
+    fn walk<F: Fn((f32, f32)) -> bool>(&self, seed: (f32, f32), h: f32, max_steps: usize, free: &F) -> Vec<(f32, f32)> {
+        let mut out = Vec::new();
+        let mut p = seed;
+
+        for n in 0..max_steps {
+            let Some(q) = self.rk4(p, h) else { break; };
+            if !free(q) { break; }
+
+            // closed orbit: stop once the line returns to its seed
+            let (dx, dy) = (q.0 - seed.0, q.1 - seed.1);
+            if n > 2 && dx * dx + dy * dy < h * h { break; }
+
+            out.push(q);
+            p = q;
+        }
+
+        out
+    }
+}
+
+/// Coarse grid marking which cells already hold a streamline.
+struct Occupancy {
+    cells: Vec<bool>,
+    dims: (usize, usize),
+    cell: f32,
+}
+
+impl Occupancy {
+    /// Returns `None` if the grid would not fit in memory.
+    fn new(width: f32, height: f32, cell: f32) -> Option<Self> {
+        let nx = ((width / cell).ceil() as usize).max(1);
+        let ny = ((height / cell).ceil() as usize).max(1);
+        let len = nx.checked_mul(ny).filter(|&n| n <= isize::MAX as usize)?;
+        Some(Self { cells: vec![false; len], dims: (nx, ny), cell })
+    }
+
+    fn index(&self, (x, y): (f32, f32)) -> Option<usize> {
+        if !(x >= 0.0 && y >= 0.0) { return None; }
+        let (cx, cy) = ((x / self.cell) as usize, (y / self.cell) as usize);
+        let (nx, ny) = self.dims;
+        (cx < nx && cy < ny).then_some(cy * nx + cx)
+    }
+
+    fn is_taken(&self, p: (f32, f32)) -> bool {
+        self.index(p).is_some_and(|i| self.cells[i])
+    }
+
+    fn take(&mut self, p: (f32, f32)) {
+        if let Some(i) = self.index(p) {
+            self.cells[i] = true;
+        }
+    }
+}
+
+/// Returns the tip and unit direction of a decoration of length `size`
+/// centered on the middle of `path`, pointing along its direction.
+fn arrow_tip(path: &Path, size: f32) -> Option<((f32, f32), (f32, f32))> {
+    if !size.is_finite() || size <= 0.0 { return None; }
+
+    let length = path.length();
+    if length < size { return None; }
+    let ((x, y), angle) = path.point_at_distance(0.5 * length)?;
+
+    let (s, c) = angle.sin_cos();
+    let half = 0.5 * size;
+    Some(((x + c * half, y + s * half), (c, s)))
+}
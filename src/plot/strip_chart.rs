@@ -0,0 +1,114 @@
+use crate::{Color, Rect, Stage};
+
+/// Scrolling strip chart for live telemetry, drawn into a fixed pixel rect of
+/// a [`Stage`].
+///
+/// Each [`StripChart::push`] shifts the pixels in the rect one column left
+/// and draws only the newest column, so the cost per sample is one region
+/// copy however long the history is. The chart owns its rect: clips and
+/// scissors are ignored inside it.
+///
+/// ```ignore
+/// let mut chart = StripChart::new(Rect::new(0, 0, 300, 80), (-1.0, 1.0))
+///     .color(theme.series_color(0));
+/// chart.clear(&mut stage);
+/// for sample in samples {
+///     chart.push(&mut stage, sample);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct StripChart {
+    rect: Rect,
+    range: (f32, f32),
+    color: Color,
+    background: Color,
+    /// Pixel row of the previous sample, or `None` after a gap.
+    last_row: Option<usize>,
+}
+
+impl StripChart {
+    /// Creates a [`StripChart`] over pixel `rect` mapping `range = (min, max)`
+    /// to its bottom and top rows. Draws white on black by default.
+    pub fn new(rect: Rect, range: (f32, f32)) -> Self {
+        Self { rect, range, color: Color::WHITE, background: Color::BLACK, last_row: None }
+    }
+
+    /// Sets the trace color.
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Sets the background color filling newly exposed columns.
+    pub fn background(mut self, color: Color) -> Self {
+        self.background = color;
+        self
+    }
+
+    /// Returns the pixel rect of the chart.
+    pub fn rect(&self) -> Rect {
+        self.rect
+    }
+
+    /// Returns the `(min, max)` value range.
+    pub fn range(&self) -> (f32, f32) {
+        self.range
+    }
+
+    /// Fills the chart rect with the background and forgets the last sample.
+    pub fn clear(&mut self, stage: &mut Stage) {
+        self.last_row = None;
+        let Some(r) = self.rect.intersect(stage.bounds()) else { return; };
+        let bg = self.background.rgba();
+        for y in r.y..r.bottom() {
+            stage.row_mut(y)[r.x..r.right()].fill(bg);
+        }
+    }
+
+    /// Scrolls the chart one column left and draws `value` in the rightmost
+    /// column, joined vertically to the previous sample.
+    ///
+    /// Values outside the range are drawn at the nearest edge. Non-finite
+    /// values leave a gap.
+    ///
+    /// Arguments:
+    /// - stage: &mut [`Stage`] - stage holding the chart.
+    /// - value: [f32] - newest sample.
+    pub fn push(&mut self, stage: &mut Stage, value: f32) {
+        let Some(r) = self.rect.intersect(stage.bounds()) else { return; };
+
+        if r.width > 1 {
+            stage.copy_within(Rect::new(r.x + 1, r.y, r.width - 1, r.height), (r.x as isize, r.y as isize));
+        }
+
+        let x = r.right() - 1;
+        let bg = self.background.rgba();
+        for y in r.y..r.bottom() {
+            stage.row_mut(y)[x] = bg;
+        }
+
+        let row = self.value_row(value, r);
+        if let Some(row) = row {
+            let (y0, y1) = match self.last_row {
+                Some(last) => (last.min(row), last.max(row)),
+                None => (row, row),
+            };
+            let color = self.color.rgba();
+            for y in y0..=y1 {
+                stage.row_mut(y)[x] = color;
+            }
+        }
+        self.last_row = row;
+    }
+
+    /// Returns the pixel row of `value` inside `r`, or `None` if it is not finite.
+    fn value_row(&self, value: f32, r: Rect) -> Option<usize> {
+        if !value.is_finite() || r.height == 0 { return None; }
+
+        let (min, max) = self.range;
+        let span = max - min;
+        let t = if span != 0.0 && span.is_finite() { ((value - min) / span).clamp(0.0, 1.0) } else { 0.5 };
+        let offset = (t * (r.height - 1) as f32).round() as usize;
+        Some(r.bottom() - 1 - offset)
+    }
+}
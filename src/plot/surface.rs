@@ -0,0 +1,61 @@
+use crate::{Colormap, Stage};
+use crate::wire3d::{self, Camera, ShadeOptions};
+
+/// Light direction of [`surface`] in plot coords, from the upper left front.
+const LIGHT: (f32, f32, f32) = (-0.4, -0.6, 1.0);
+
+/// Draws the shaded 3D surface `z = f(x, y)` over `x_range` x `y_range`.
+///
+/// The function is sampled on a `resolution = (cols, rows)` grid and each
+/// cell is split into two flat shaded triangles, colored by `colormap` at
+/// their mean height normalized over the finite samples. Cells touching a
+/// non-finite sample are left out. Triangles are painted back to front with
+/// [`wire3d::render_triangles`].
+///
+/// Plot coords are `(x, y, z)` with `+z` up, so the up direction of `camera`
+/// is replaced by `+z`; place its eye and target in the same coords.
+///
+/// Arguments:
+/// - stage: &mut [`Stage`] - stage to draw onto.
+/// - f: `Fn(f32, f32) -> f32` - height at `(x, y)`.
+/// - x_range: ([f32], [f32]) - `x` extent.
+/// - y_range: ([f32], [f32]) - `y` extent.
+/// - resolution: ([usize], [usize]) - samples `(cols, rows)` along `x` and `y`.
+/// - colormap: &[`Colormap`] - maps normalized heights to colors.
+/// - camera: &[`Camera`] - eye and projection.
+pub fn surface<F: Fn(f32, f32) -> f32>(
+    stage: &mut Stage,
+    f: F,
+    x_range: (f32, f32),
+    y_range: (f32, f32),
+    resolution: (usize, usize),
+    colormap: &Colormap,
+    camera: &Camera,
+) {
+    // wire3d surfaces are y-up, so sample with (x, y) in the ground plane
+    // and swap the height into z
+    let triangles: Vec<_> = wire3d::surface_triangles(f, x_range, y_range, resolution)
+        .into_iter()
+        .map(|tri| tri.map(|(x, z, y)| (x, y, z)))
+        .collect();
+
+    let (lo, hi) = triangles
+        .iter()
+        .flatten()
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), p| (lo.min(p.2), hi.max(p.2)));
+    if lo > hi { return; }
+
+    let span = hi - lo;
+    let colored: Vec<_> = triangles
+        .into_iter()
+        .map(|tri| {
+            let z = (tri[0].2 + tri[1].2 + tri[2].2) / 3.0;
+            let t = if span > 0.0 { (z - lo) / span } else { 0.5 };
+            (tri, colormap.sample(t))
+        })
+        .collect();
+
+    let camera = camera.up((0.0, 0.0, 1.0));
+    let options = ShadeOptions { light: Some(LIGHT), ..ShadeOptions::default() };
+    wire3d::render_triangles(stage, &camera, &colored, options);
+}
@@ -0,0 +1,101 @@
+//! Progressive `png` encoding for renders too large to hold in memory at once.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path as FsPath;
+
+use image::error::EncodingError;
+use image::{ImageError, ImageFormat, ImageResult};
+
+use crate::Stage;
+
+/// A `png` encoder that accepts the image top to bottom in rows or strips.
+///
+/// Render a tall image one full-width strip [`Stage`] at a time and hand each
+/// to [`write_stage`](Self::write_stage); only the current strip and the
+/// compressor state are ever resident.
+///
+/// ```ignore
+/// let mut png = PngStream::create("big.png", 8192, 8192)?;
+/// for band in 0..32 {
+///     let mut strip = Stage::new(8192, 256);
+///     strip.set_origin_pxl((4095.5, 4095.5 - band as f32 * 256.0));
+///     draw(&mut strip);
+///     png.write_stage(&strip)?;
+/// }
+/// png.finish()?;
+/// ```
+pub struct PngStream<W: Write + 'static> {
+    writer: png::StreamWriter<'static, W>,
+    width: usize,
+    height: usize,
+    rows_written: usize,
+}
+
+impl PngStream<BufWriter<File>> {
+    /// Creates the file at `path` and starts a `width` x `height` `png` in it.
+    pub fn create<P: AsRef<FsPath>>(path: P, width: usize, height: usize) -> ImageResult<Self> {
+        Self::new(BufWriter::new(File::create(path)?), width, height)
+    }
+}
+
+impl<W: Write + 'static> PngStream<W> {
+    /// Starts a `width` x `height` `png` written to `out`.
+    pub fn new(out: W, width: usize, height: usize) -> ImageResult<Self> {
+        assert!(width > 0 && height > 0, "Stage must be strictly positive in size");
+
+        let mut encoder = png::Encoder::new(out, width as u32, height as u32);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let writer = encoder
+            .write_header()
+            .and_then(png::Writer::into_stream_writer)
+            .map_err(png_err)?;
+
+        Ok(Self { writer, width, height, rows_written: 0 })
+    }
+
+    /// Returns the number of rows still expected.
+    pub fn rows_remaining(&self) -> usize {
+        self.height - self.rows_written
+    }
+
+    /// Appends one row of `width` pixels.
+    ///
+    /// Panics if `row` has the wrong length or the image is already complete.
+    pub fn write_row(&mut self, row: &[[u8; 4]]) -> ImageResult<()> {
+        assert_eq!(row.len(), self.width, "row length must match the png width");
+        assert!(self.rows_written < self.height, "png already has all of its rows");
+
+        self.writer.write_all(row.as_flattened())?;
+        self.rows_written += 1;
+        Ok(())
+    }
+
+    /// Appends every row of `strip`, which must be as wide as the `png`.
+    pub fn write_stage(&mut self, strip: &Stage) -> ImageResult<()> {
+        assert!(
+            strip.height() <= self.rows_remaining(),
+            "strip has more rows than the png has left",
+        );
+
+        for y in 0..strip.height() {
+            self.write_row(strip.row(y))?;
+        }
+        Ok(())
+    }
+
+    /// Flushes the remaining data. Fails if fewer than `height` rows were written.
+    pub fn finish(self) -> ImageResult<()> {
+        self.writer.finish().map_err(png_err)
+    }
+}
+
+/// Converts a `png` crate error into an [`ImageError`].
+pub(crate) fn png_err(e: png::EncodingError) -> ImageError {
+    match e {
+        png::EncodingError::IoError(e) => ImageError::IoError(e),
+        e => ImageError::Encoding(EncodingError::new(ImageFormat::Png.into(), e)),
+    }
+}
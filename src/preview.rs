@@ -0,0 +1,86 @@
+//! Terminal previews of a [`Stage`] using truecolor ANSI escapes.
+
+use std::fmt::Write;
+
+use crate::Stage;
+
+/// Upper half block, drawn with the top pixel as foreground and the bottom
+/// pixel as background so each character cell shows two pixel rows.
+pub(crate) const HALF_BLOCK: char = '\u{2580}';
+
+impl Stage {
+    /// Renders the [`Stage`] as truecolor ANSI half-block text, at most
+    /// `max_cols` characters wide, for previews in terminals and CI logs.
+    ///
+    /// Wider stages are area averaged down keeping their aspect ratio. Each
+    /// character covers one column and two rows of pixels. Pixels are
+    /// composited over black, and every line ends with a color reset.
+    ///
+    /// Arguments:
+    /// - max_cols: [usize]: maximum characters per line.
+    ///
+    /// Returns:
+    /// [String] of escape coded lines separated by `\n`, empty if
+    /// `max_cols` is zero.
+    pub fn to_ansi_string(&self, max_cols: usize) -> String {
+        if max_cols == 0 {
+            return String::new();
+        }
+
+        let (w, h) = self.dimensions();
+        let cols = w.min(max_cols);
+        let rows = ((h as f32 * cols as f32 / w as f32).round() as usize).max(1);
+
+        let mut out = String::with_capacity(rows.div_ceil(2) * (cols * 40 + 8));
+        self.half_blocks(cols, rows, |col, _, top, bottom| {
+            let (r, g, b) = top;
+            let _ = write!(out, "\x1b[38;2;{r};{g};{b}m");
+            if let Some((r, g, b)) = bottom {
+                let _ = write!(out, "\x1b[48;2;{r};{g};{b}m");
+            } else {
+                out.push_str("\x1b[49m");
+            }
+            out.push(HALF_BLOCK);
+            if col + 1 == cols {
+                out.push_str("\x1b[0m\n");
+            }
+        });
+        out.pop();
+        out
+    }
+
+    /// Resamples `self` to `cols` x `rows` pixels and calls
+    /// `cell(col, line, top, bottom)` for each half-block character cell,
+    /// line by line. Colors are RGB composited over black. `bottom` is `None`
+    /// on the last line when `rows` is odd.
+    pub(crate) fn half_blocks<F>(&self, cols: usize, rows: usize, mut cell: F)
+    where
+        F: FnMut(usize, usize, (u8, u8, u8), Option<(u8, u8, u8)>),
+    {
+        if cols == 0 || rows == 0 {
+            return;
+        }
+
+        let scaled;
+        let src = if (cols, rows) == self.dimensions() {
+            self
+        } else {
+            scaled = self.resized(cols, rows);
+            &scaled
+        };
+
+        let over_black = |px: [u8; 4]| {
+            let a = px[3] as u16;
+            let c = |v: u8| ((v as u16 * a + 127) / 255) as u8;
+            (c(px[0]), c(px[1]), c(px[2]))
+        };
+
+        for (line, y) in (0..rows).step_by(2).enumerate() {
+            let top = src.row(y);
+            let bottom = (y + 1 < rows).then(|| src.row(y + 1));
+            for col in 0..cols {
+                cell(col, line, over_black(top[col]), bottom.map(|row| over_black(row[col])));
+            }
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{Stage, Style};
+use crate::primitives::SpanPart;
+
+/// Draws a circle in pixel coords with nominal radius `r0_pxl`.
+///
+/// Strokes are rasterized as an exact annulus `stroke.width` pixels wide,
+/// centered on `r0_pxl`.
+pub(crate) fn draw_circle_pxl(
+    stage: &mut Stage,
+    origin_pxl: (isize, isize),
+    r0_pxl: isize,
+    style: Style,
+) {
+    if !style.fill_or_stroke_exists() {
+        return;
+    }
+
+    let fill = style.fill;
+    let stroke_rgba = style.stroke.map(|s| s.rgba());
+    let stroke_width = style.stroke.map(|s| s.width);
+
+    circle_spans(origin_pxl, r0_pxl, fill.is_some(), stroke_width, |part, y, x0, x1| {
+        match part {
+            SpanPart::Fill => if let Some(f) = fill { stage.fill_span_paint(y, x0, x1, f) },
+            SpanPart::Stroke => if let Some(c) = stroke_rgba { stage.fill_span_pxl(y, x0, x1, c) },
+        }
+    });
+}
+
+/// Walks the scanline spans of a circle with nominal radius `r0_pxl`, calling
+/// `span(part, y, x0, x1)` for each inclusive run of pixels.
+///
+/// If `stroke_width` is `Some`, the stroke is an annulus of that width
+/// centered on `r0_pxl` and the fill stops at its inner edge.
+pub(crate) fn circle_spans<F: FnMut(SpanPart, isize, isize, isize)>(
+    origin_pxl: (isize, isize),
+    r0_pxl: isize,
+    fill: bool,
+    stroke_width: Option<f32>,
+    mut span: F,
+) {
+    if r0_pxl <= 0 {
+        return;
+    }
+
+    let stroked = stroke_width.is_some();
+    let (r_out, r_in) = if let Some(w) = stroke_width {
+        if !w.is_finite() || w <= 0.0 {
+            (r0_pxl, r0_pxl)
+        } else {
+            let half_out = (0.5 * w).ceil() as isize;
+            let half_in  = (0.5 * w).floor() as isize;
+            let r_out = r0_pxl + half_out;
+            let r_in  = (r0_pxl - half_in).max(0);
+            (r_out, r_in)
+        }
+    } else {
+        (r0_pxl, r0_pxl)
+    };
+
+    let r_fill = if fill {
+        if stroked { r_in } else { r0_pxl }
+    } else {
+        0
+    };
+
+    let (xc, yc) = origin_pxl;
+
+    let r_out_i64 = r_out as i64;
+    let r_out2: i64 = r_out_i64 * r_out_i64;
+
+    let r_in_i64 = r_in as i64;
+    let r_in2: i64 = r_in_i64 * r_in_i64;
+
+    let r_fill_i64 = r_fill as i64;
+    let r_fill2: i64 = r_fill_i64 * r_fill_i64;
+
+    let mut x_out: isize = r_out;
+    let mut x_out2: i64 = r_out2;
+
+    let mut x_in: isize = r_in;
+    let mut x_in2: i64 = r_in2;
+
+    let mut x_fill: isize = r_fill;
+    let mut x_fill2: i64 = r_fill2;
+
+    let shrink_x = |mut x: isize, mut x2: i64, y2: i64, limit2: i64| -> (isize, i64) {
+        while x > 0 && x2 + y2 > limit2 {
+            x2 -= 2 * (x as i64) - 1;
+            x -= 1;
+        }
+        (x, x2)
+    };
+
+    let mut y2: i64 = 0;
+
+    for y in 0..=r_out {
+        (x_out, x_out2) = shrink_x(x_out, x_out2, y2, r_out2);
+
+        let x_in_row: isize = if stroked {
+            if r_in == 0 {
+                -1
+            } else {
+                (x_in, x_in2) = shrink_x(x_in, x_in2, y2, r_in2);
+                if x_in2 + y2 <= r_in2 { x_in } else { -1 }
+            }
+        } else {
+            -1
+        };
+
+        let x_fill_row: isize = if fill {
+            if r_fill == 0 {
+                -1
+            } else {
+                (x_fill, x_fill2) = shrink_x(x_fill, x_fill2, y2, r_fill2);
+                if x_fill2 + y2 <= r_fill2 { x_fill } else { -1 }
+            }
+        } else {
+            -1
+        };
+
+        let y_top = yc - y;
+        let y_bot = yc + y;
+
+        if fill && x_fill_row >= 0 {
+            span(SpanPart::Fill, y_top, xc - x_fill_row, xc + x_fill_row);
+            if y != 0 {
+                span(SpanPart::Fill, y_bot, xc - x_fill_row, xc + x_fill_row);
+            }
+        }
+
+        if stroked {
+            let a = x_in_row + 1;
+            if a <= x_out {
+                if a <= 0 {
+                    span(SpanPart::Stroke, y_top, xc - x_out, xc + x_out);
+                    if y != 0 {
+                        span(SpanPart::Stroke, y_bot, xc - x_out, xc + x_out);
+                    }
+                } else {
+                    span(SpanPart::Stroke, y_top, xc - x_out, xc - a);
+                    span(SpanPart::Stroke, y_top, xc + a, xc + x_out);
+                    if y != 0 {
+                        span(SpanPart::Stroke, y_bot, xc - x_out, xc - a);
+                        span(SpanPart::Stroke, y_bot, xc + a, xc + x_out);
+                    }
+                }
+            }
+        }
+
+        y2 += 2 * (y as i64) + 1;
+    }
+}
+
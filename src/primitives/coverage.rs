@@ -0,0 +1,85 @@
+use crate::{Color, Stage};
+
+/// Union of scanline spans over a band of rows, collected before any pixel is
+/// written so overlapping pieces of one shape land on the [`Stage`] once.
+pub(crate) struct SpanCoverage {
+    y0: isize,
+    /// Inclusive `(x0, x1)` spans per row, unsorted and possibly overlapping.
+    rows: Vec<Vec<(isize, isize)>>,
+}
+
+impl SpanCoverage {
+    /// Creates an empty coverage for rows `y0..=y1`. Spans outside are dropped.
+    pub(crate) fn new(y0: isize, y1: isize) -> Self {
+        let n = if y1 >= y0 { (y1 - y0 + 1) as usize } else { 0 };
+        Self { y0, rows: vec![Vec::new(); n] }
+    }
+
+    /// Adds the inclusive span `x0..=x1` on row `y`.
+    pub(crate) fn add(&mut self, y: isize, x0: isize, x1: isize) {
+        if x0 > x1 || y < self.y0 { return; }
+        if let Some(row) = self.rows.get_mut((y - self.y0) as usize) {
+            row.push((x0, x1));
+        }
+    }
+
+    /// Merges overlapping and touching spans, then fills each resulting run
+    /// on `stage` with `color`.
+    pub(crate) fn fill(mut self, stage: &mut Stage, color: Color) {
+        for (i, row) in self.rows.iter_mut().enumerate() {
+            if row.is_empty() { continue; }
+            let y = self.y0 + i as isize;
+
+            row.sort_unstable();
+            let (mut a, mut b) = row[0];
+            for &(s, e) in &row[1..] {
+                if s > b + 1 {
+                    stage.fill_span_pxl(y, a, b, color);
+                    a = s;
+                }
+                b = b.max(e);
+            }
+            stage.fill_span_pxl(y, a, b, color);
+        }
+    }
+}
+
+/// Scanline spans of a filled disc around `(0, 0)`, rasterized once and
+/// stamped at every round join and cap of a stroke.
+pub(crate) struct Disc {
+    /// Inclusive `(dy, dx0, dx1)` spans relative to the center.
+    spans: Vec<(isize, isize, isize)>,
+    r: isize,
+}
+
+impl Disc {
+    /// Rasterizes the disc matching a stroke `width` pixels wide.
+    ///
+    /// Stroke quads span `round(width / 2)` pixels either side of an integer
+    /// centerline, the far side exclusive, so they cover rows `-r..r` around
+    /// it whether `width` is odd or even. The disc has the same diameter `2r`
+    /// and is centered half a pixel up and left to line up with them.
+    pub(crate) fn new(width: f32) -> Self {
+        let r = (0.5 * width).round() as isize;
+        let mut spans = Vec::with_capacity(2 * r.max(0) as usize);
+        for dy in -r..r {
+            let h2 = (r * r) as f32 - (dy as f32 + 0.5).powi(2);
+            if h2 < 0.0 { continue; }
+            let h = h2.sqrt();
+            spans.push((dy, (-h - 0.5).ceil() as isize, (h - 0.5).floor() as isize));
+        }
+        Self { spans, r }
+    }
+}
+
+impl SpanCoverage {
+    /// Adds `disc` centered on `(cx, cy)`, skipping it whole if it misses
+    /// every row.
+    pub(crate) fn add_disc(&mut self, (cx, cy): (isize, isize), disc: &Disc) {
+        let y1 = self.y0 + self.rows.len() as isize - 1;
+        if cy + disc.r < self.y0 || cy - disc.r > y1 { return; }
+        for &(dy, dx0, dx1) in &disc.spans {
+            self.add(cy + dy, cx + dx0, cx + dx1);
+        }
+    }
+}
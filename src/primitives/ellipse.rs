@@ -0,0 +1,94 @@
+use crate::{Stage, Style};
+use crate::primitives::SpanPart;
+
+/// Draws an axis-aligned ellipse in pixel coords with nominal radii `radii_pxl`.
+///
+/// Strokes are rasterized as the ring between two concentric ellipses whose
+/// radii differ from `radii_pxl` by half of `stroke.width` on each side.
+pub(crate) fn draw_ellipse_pxl(
+    stage: &mut Stage,
+    origin_pxl: (isize, isize),
+    radii_pxl: (isize, isize),
+    style: Style,
+) {
+    if !style.fill_or_stroke_exists() {
+        return;
+    }
+
+    let fill = style.fill;
+    let stroke_rgba = style.stroke.map(|s| s.rgba());
+    let stroke_width = style.stroke.map(|s| s.width);
+
+    ellipse_spans(origin_pxl, radii_pxl, fill.is_some(), stroke_width, |part, y, x0, x1| {
+        match part {
+            SpanPart::Fill => if let Some(f) = fill { stage.fill_span_paint(y, x0, x1, f) },
+            SpanPart::Stroke => if let Some(c) = stroke_rgba { stage.fill_span_pxl(y, x0, x1, c) },
+        }
+    });
+}
+
+/// Walks the scanline spans of an ellipse with nominal radii `radii_pxl`,
+/// calling `span(part, y, x0, x1)` for each inclusive run of pixels.
+///
+/// If `stroke_width` is `Some`, the stroke is the ring between the outer and
+/// inner ellipses and the fill stops at the inner one.
+pub(crate) fn ellipse_spans<F: FnMut(SpanPart, isize, isize, isize)>(
+    origin_pxl: (isize, isize),
+    radii_pxl: (isize, isize),
+    fill: bool,
+    stroke_width: Option<f32>,
+    mut span: F,
+) {
+    let (rx, ry) = radii_pxl;
+    if rx <= 0 || ry <= 0 {
+        return;
+    }
+
+    let (half_out, half_in) = match stroke_width {
+        Some(w) if w.is_finite() && w > 0.0 => ((0.5 * w).ceil() as isize, (0.5 * w).floor() as isize),
+        _ => (0, 0),
+    };
+    let stroked = stroke_width.is_some();
+
+    let outer = (rx + half_out, ry + half_out);
+    let inner = ((rx - half_in).max(0), (ry - half_in).max(0));
+    let fill_radii = if stroked { inner } else { radii_pxl };
+
+    let (xc, yc) = origin_pxl;
+
+    for dy in 0..=outer.1 {
+        let x_out = half_width(outer, dy);
+        let x_in = if stroked { half_width(inner, dy) } else { None };
+        let x_fill = if fill { half_width(fill_radii, dy) } else { None };
+
+        let rows: &[isize] = if dy == 0 { &[yc] } else { &[yc - dy, yc + dy] };
+        for &y in rows {
+            if let Some(xf) = x_fill {
+                span(SpanPart::Fill, y, xc - xf, xc + xf);
+            }
+
+            if !stroked { continue; }
+            let Some(xo) = x_out else { continue; };
+            match x_in {
+                Some(xi) if xi < xo => {
+                    span(SpanPart::Stroke, y, xc - xo, xc - xi - 1);
+                    span(SpanPart::Stroke, y, xc + xi + 1, xc + xo);
+                }
+                Some(_) => {}
+                None => span(SpanPart::Stroke, y, xc - xo, xc + xo),
+            }
+        }
+    }
+}
+
+/// Largest `dx` with `(dx / rx)^2 + (dy / ry)^2 <= 1`, or `None` if row `dy`
+/// misses the ellipse.
+#[inline(always)]
+fn half_width((rx, ry): (isize, isize), dy: isize) -> Option<isize> {
+    if rx <= 0 || ry <= 0 || dy > ry {
+        return None;
+    }
+
+    let t = dy as f64 / ry as f64;
+    Some((rx as f64 * (1.0 - t * t).max(0.0).sqrt()).floor() as isize)
+}
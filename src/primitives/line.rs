@@ -1,66 +1,217 @@
 use crate::{Stage, Color}; 
+use crate::stage::PixelCursor;
 
-/// Draws a line in pixel coords. 
+/// Draws a connected polyline in pixel coords as one continuous scan.
 ///
-/// Arguments: 
-/// - stage: &mut [Stage] 
-/// - xy1_px: ([isize], [isize])
-/// - xy2_px: ([isize], [isize])
-/// - color: [Color] 
-pub(crate) fn draw_line_pxl( 
-    stage: &mut Stage, 
-    xy1_px: (isize, isize), 
-    xy2_px: (isize, isize), 
-    color: Color
-) { 
-    let Some((xy1_px, xy2_px)) = clip_line_to_stage(stage, xy1_px, xy2_px) else { return; };
-
-    let (x1, y1) = xy1_px; 
-    let (x2, y2) = xy2_px;
+/// Each segment starts where the previous one ended, so shared vertices are
+/// plotted exactly once and consecutive 1px segments stay 8-connected.
+/// Repeated nodes are skipped. If `closed`, the closing segment joins the
+/// last node back to the first without replotting it.
+pub(crate) fn draw_polyline_pxl(
+    stage: &mut Stage,
+    nodes_px: &[(isize, isize)],
+    closed: bool,
+    color: Color,
+) {
+    let mut nodes: Vec<(isize, isize)> = Vec::with_capacity(nodes_px.len() + 1);
+    for &p in nodes_px {
+        if nodes.last() != Some(&p) { nodes.push(p); }
+    }
+    if closed && nodes.len() > 1 && nodes.first() == nodes.last() {
+        nodes.pop();
+    }
+
+    match nodes.len() {
+        0 => return,
+        1 => {
+            stage.plot_pxl(nodes[0].0, nodes[0].1, color);
+            return;
+        }
+        _ => {}
+    }
+
+    let closing = closed && nodes.len() > 2;
+    if closing {
+        nodes.push(nodes[0]);
+    }
+
+    let last = nodes.len() - 2;
+    for (i, w) in nodes.windows(2).enumerate() {
+        let skip_last = closing && i == last;
+        draw_segment_pxl(stage, w[0], w[1], (i > 0, skip_last), color);
+    }
+}
+
+/// Draws a connected polyline through fractional pixel coords with
+/// anti-aliased 1px segments, see [`draw_line_aa_pxl`]. Segments touching a
+/// non-finite node are skipped.
+pub(crate) fn draw_polyline_aa_pxl(
+    stage: &mut Stage,
+    nodes_px: &[(f32, f32)],
+    closed: bool,
+    color: Color,
+) {
+    for w in nodes_px.windows(2) {
+        draw_line_aa_pxl(stage, w[0], w[1], color);
+    }
+    if closed && nodes_px.len() > 2 {
+        draw_line_aa_pxl(stage, nodes_px[nodes_px.len() - 1], nodes_px[0], color);
+    }
+}
+
+/// Xiaolin Wu line from `p0` to `p1` in fractional pixel coords, where
+/// integers are pixel centers.
+///
+/// Each column (or row, for steep lines) blends the two pixels straddling
+/// the line, weighted by how close their centers are, so diagonals come out
+/// smooth instead of stair-stepped. Endpoints are weighted by how much of
+/// their pixel the line covers, so the shared vertex of consecutive
+/// segments adds up to roughly one pixel.
+pub(crate) fn draw_line_aa_pxl(stage: &mut Stage, p0: (f32, f32), p1: (f32, f32), color: Color) {
+    let Some(((mut x0, mut y0), (mut x1, mut y1))) = clip_line_aa(stage, p0, p1) else { return; };
+
+    let steep = (y1 - y0).abs() > (x1 - x0).abs();
+    if steep {
+        std::mem::swap(&mut x0, &mut y0);
+        std::mem::swap(&mut x1, &mut y1);
+    }
+    if x0 > x1 {
+        std::mem::swap(&mut x0, &mut x1);
+        std::mem::swap(&mut y0, &mut y1);
+    }
+
+    let dx = x1 - x0;
+    let gradient = if dx == 0.0 { 1.0 } else { (y1 - y0) / dx };
+    let mut plot = |x: isize, y: isize, coverage: f32| {
+        if steep {
+            stage.blend_pxl(y, x, color, coverage);
+        } else {
+            stage.blend_pxl(x, y, color, coverage);
+        }
+    };
+    let fpart = |v: f32| v - v.floor();
+
+    // pixel columns of the endpoints, weighted by the part of them covered
+    let mut endpoint = |x: f32, y: f32, gap: f32| {
+        let xend = x.round();
+        let yend = y + gradient * (xend - x);
+        let (px, py) = (xend as isize, yend.floor() as isize);
+        plot(px, py, (1.0 - fpart(yend)) * gap);
+        plot(px, py + 1, fpart(yend) * gap);
+        (px, yend)
+    };
+    let (xa, yend) = endpoint(x0, y0, 1.0 - fpart(x0 + 0.5));
+    let (xb, _) = endpoint(x1, y1, fpart(x1 + 0.5));
+
+    let mut intery = yend + gradient;
+    for x in xa + 1..xb {
+        let y = intery.floor() as isize;
+        plot(x, y, 1.0 - fpart(intery));
+        plot(x, y + 1, fpart(intery));
+        intery += gradient;
+    }
+}
+
+/// Liang–Barsky clip of the fractional segment `p0`-`p1` against the draw
+/// rect grown by one pixel, so the blended fringe at its edges survives.
+/// Returns `None` if nothing is left or a coord is not finite.
+fn clip_line_aa(
+    stage: &Stage,
+    p0: (f32, f32),
+    p1: (f32, f32),
+) -> Option<((f32, f32), (f32, f32))> {
+    if ![p0.0, p0.1, p1.0, p1.1].iter().all(|v| v.is_finite()) { return None; }
+
+    let r = stage.draw_rect();
+    if r.is_empty() { return None; }
+    let (xmin, ymin) = (r.x as f32 - 1.0, r.y as f32 - 1.0);
+    let (xmax, ymax) = (r.right() as f32, r.bottom() as f32);
+
+    let (dx, dy) = (p1.0 - p0.0, p1.1 - p0.1);
+    let (mut t0, mut t1) = (0.0f32, 1.0f32);
+    for (p, q) in [(-dx, p0.0 - xmin), (dx, xmax - p0.0), (-dy, p0.1 - ymin), (dy, ymax - p0.1)] {
+        if p == 0.0 {
+            if q < 0.0 { return None; }
+            continue;
+        }
+        let t = q / p;
+        if p < 0.0 { t0 = t0.max(t); } else { t1 = t1.min(t); }
+        if t0 > t1 { return None; }
+    }
+
+    Some(((p0.0 + t0 * dx, p0.1 + t0 * dy), (p0.0 + t1 * dx, p0.1 + t1 * dy)))
+}
+
+/// Bresenham line from `xy1_px` to `xy2_px`, optionally leaving out the
+/// endpoints listed in `skip = (first, last)` when they survive clipping.
+fn draw_segment_pxl(
+    stage: &mut Stage,
+    xy1_px: (isize, isize),
+    xy2_px: (isize, isize),
+    skip: (bool, bool),
+    color: Color,
+) {
+    let Some((p1, p2)) = clip_line_to_stage(stage, xy1_px, xy2_px) else { return; };
+
+    // an endpoint moved by clipping is not a shared vertex, so it is kept
+    let skip_first = skip.0 && p1 == xy1_px;
+    let skip_last = skip.1 && p2 == xy2_px;
+
+    let (x1, y1) = p1; 
+    let (x2, y2) = p2;
 
     debug_assert!((x2 - x1) != isize::MIN);
     debug_assert!((y2 - y1) != isize::MIN);
 
-    let mut x = x1; 
-    let mut y = y1; 
-
     let dx = (x2 - x1).abs(); 
     let dy = (y2 - y1).abs(); 
 
     let sx = (x2 - x1).signum(); 
     let sy = (y2 - y1).signum();
+
+    let rgba = color.rgba();
+    let steps = dx.max(dy);
+    // SAFETY: both endpoints are inside the draw rect, and so is every
+    // pixel the walk between them puts.
+    let mut cursor = unsafe { stage.cursor(x1 as usize, y1 as usize) };
+    let plot = |cursor: &mut PixelCursor, i: isize| {
+        if (i == 0 && skip_first) || (i == steps && skip_last) { return; }
+        cursor.put(rgba);
+    };
  
     // Bresenham line
     if dx >= dy { 
         let mut err = 2 * dy - dx; 
 
-        for _ in 0..=dx { 
-            stage.plot_pxl(x, y, color); 
+        for i in 0..=dx { 
+            plot(&mut cursor, i); 
+            if i == dx { break; }
 
             if err >= 0 { 
-                y += sy; 
+                cursor.step_y(sy); 
                 err -= 2 * dx; 
             }
 
-            x += sx; 
+            cursor.step_x(sx); 
             err += 2 * dy; 
         }
     } else { 
         let mut err = 2 * dx - dy; 
 
-        for _ in 0..=dy { 
-            stage.plot_pxl(x, y, color); 
+        for i in 0..=dy { 
+            plot(&mut cursor, i); 
+            if i == dy { break; }
 
             if err >= 0 { 
-                x += sx; 
+                cursor.step_x(sx); 
                 err -= 2 * dy; 
             }
 
-            y += sy; 
+            cursor.step_y(sy); 
             err += 2 * dx; 
         }
     }
-} 
+}
 
 #[inline(always)]
 fn out_code(
@@ -79,17 +230,20 @@ fn out_code(
     c
 }
 
-/// Cohen–Sutherland clip.
-/// Returns `None` if fully outside; otherwise clipped endpoints.
+/// Cohen–Sutherland clip against the active draw rect (the scissor, or the
+/// whole stage). Returns `None` if fully outside; otherwise clipped endpoints.
 fn clip_line_to_stage(
     stage: &Stage,
     p0: (isize, isize),
     p1: (isize, isize),
 ) -> Option<((isize, isize), (isize, isize))> {
-    let xmin = 0isize;
-    let ymin = 0isize;
-    let xmax = stage.width() as isize - 1;
-    let ymax = stage.height() as isize - 1;
+    let r = stage.draw_rect();
+    if r.is_empty() { return None; }
+
+    let xmin = r.x as isize;
+    let ymin = r.y as isize;
+    let xmax = r.right() as isize - 1;
+    let ymax = r.bottom() as isize - 1;
 
     let (mut x0, mut y0) = p0;
     let (mut x1, mut y1) = p1;
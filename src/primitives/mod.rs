@@ -1,2 +1,20 @@
+//! Pixel coord rasterization core.
+//!
+//! Every public shape, whether drawn through [`Path`](crate::Path) or the
+//! [`shapes`](crate::shapes) functions, resolves to these routines. They only
+//! write through `Stage::plot_pxl` and the span fills built on
+//! [`Stage::fill_span_with`](crate::Stage::fill_span_with), so scissoring and
+//! clipping are implemented once.
+
+pub(crate) mod circle;
+pub(crate) mod coverage;
+pub(crate) mod ellipse;
 pub(crate) mod line; 
 pub(crate) mod triangle;
+
+/// Which part of a shape a rasterized span belongs to.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SpanPart {
+    Fill,
+    Stroke,
+}
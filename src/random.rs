@@ -0,0 +1,79 @@
+//! Seeded random colors, palettes, and jitter for generative sketches.
+//!
+//! Every helper takes the caller's [`Rng`], so seeding that generator (e.g.
+//! `SmallRng::seed_from_u64(seed)`) makes a whole sketch reproducible.
+//! Requires the `rand` feature.
+
+use rand::Rng;
+
+use crate::{Color, Path};
+
+impl Color {
+    /// Returns an opaque color with uniformly random RGB channels.
+    pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Color::new([rng.random(), rng.random(), rng.random(), 255])
+    }
+}
+
+/// Color harmony used by [`palette`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scheme {
+    /// Base hue and the hue opposite it.
+    Complementary,
+    /// Neighbouring hues within 30 degrees either side of the base hue.
+    Analogous,
+    /// Three hues 120 degrees apart.
+    Triadic,
+    /// A single hue at varying lightness.
+    Monochrome,
+}
+
+/// Generates `count` opaque colors in `scheme` around a random base hue.
+///
+/// Arguments:
+/// - rng: random number generator.
+/// - scheme: [`Scheme`] - hue relationship between the colors.
+/// - count: [usize] - number of colors to return.
+pub fn palette<R: Rng + ?Sized>(rng: &mut R, scheme: Scheme, count: usize) -> Vec<Color> {
+    let base: f32 = rng.random_range(0.0..360.0);
+    let saturation: f32 = rng.random_range(0.45..0.85);
+
+    (0..count)
+        .map(|i| {
+            let t = if count > 1 { i as f32 / (count - 1) as f32 } else { 0.5 };
+            let (hue, lightness) = match scheme {
+                Scheme::Complementary => (base + 180.0 * (i % 2) as f32, 0.35 + 0.3 * t),
+                Scheme::Analogous => (base - 30.0 + 60.0 * t, rng.random_range(0.4..0.65)),
+                Scheme::Triadic => (base + 120.0 * (i % 3) as f32, 0.35 + 0.3 * t),
+                Scheme::Monochrome => (base, 0.2 + 0.6 * t),
+            };
+            let s = (saturation + rng.random_range(-0.1..0.1)).clamp(0.0, 1.0);
+            Color::from_hsl(hue, s, lightness)
+        })
+        .collect()
+}
+
+/// Offsets `point` by a uniformly random vector within a disc of radius `amount`.
+pub fn jitter<R: Rng + ?Sized>(rng: &mut R, point: (f32, f32), amount: f32) -> (f32, f32) {
+    if !amount.is_finite() || amount <= 0.0 { return point; }
+
+    let r = amount * rng.random::<f32>().sqrt();
+    let (sin, cos) = rng.random_range(0.0..std::f32::consts::TAU).sin_cos();
+    (point.0 + r * cos, point.1 + r * sin)
+}
+
+/// Returns a copy of `path` with every node of every subpath passed
+/// through [`jitter`].
+pub fn jitter_path<R: Rng + ?Sized>(rng: &mut R, path: &Path, amount: f32) -> Path {
+    path.map_nodes(|p| Some(jitter(rng, p, amount)))
+        .expect("jitter maps every node")
+}
+
+/// Offsets each RGB channel of `color` by a random value in `[-amount, amount]`.
+/// Alpha is kept.
+pub fn jitter_color<R: Rng + ?Sized>(rng: &mut R, color: Color, amount: u8) -> Color {
+    let [r, g, b, a] = color.rgba();
+    let amount = amount as i16;
+    let mut channel = |c: u8| (c as i16 + rng.random_range(-amount..=amount)).clamp(0, 255) as u8;
+    Color::new([channel(r), channel(g), channel(b), a])
+}
@@ -0,0 +1,46 @@
+/// Axis-aligned rectangle in pixel coords where `(x, y)` is the top-left corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rect {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Rect {
+    /// Creates a [`Rect`] with top-left corner `(x, y)` of given `width` and `height`.
+    pub const fn new(x: usize, y: usize, width: usize, height: usize) -> Self {
+        Self { x, y, width, height }
+    }
+
+    /// Returns `true` if `self` covers no pixels.
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// Returns the exclusive right edge `x + width`.
+    pub fn right(&self) -> usize {
+        self.x.saturating_add(self.width)
+    }
+
+    /// Returns the exclusive bottom edge `y + height`.
+    pub fn bottom(&self) -> usize {
+        self.y.saturating_add(self.height)
+    }
+
+    /// Returns the overlap of `self` and `other`, or `None` if they are disjoint.
+    pub fn intersect(&self, other: Rect) -> Option<Rect> {
+        let x0 = self.x.max(other.x);
+        let y0 = self.y.max(other.y);
+        let x1 = self.right().min(other.right());
+        let y1 = self.bottom().min(other.bottom());
+
+        if x0 >= x1 || y0 >= y1 { return None; }
+        Some(Rect::new(x0, y0, x1 - x0, y1 - y0))
+    }
+
+    /// Returns `true` if pixel `(x, y)` lies inside `self`.
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x >= self.x && x < self.right() && y >= self.y && y < self.bottom()
+    }
+}
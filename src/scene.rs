@@ -0,0 +1,202 @@
+//! Retained display lists drawn through a pannable, zoomable viewport.
+
+use crate::{Path, Rect, Stage, Style};
+
+type Bounds = ((f32, f32), (f32, f32));
+
+/// Maps scene coords onto a [`Stage`]: `center` lands on the stage world
+/// origin and scene units are scaled by `zoom`.
+///
+/// Fields:
+/// - center: ([f32], [f32]) - scene coord shown at the stage world origin.
+/// - zoom: [f32] - stage world units per scene unit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    pub center: (f32, f32),
+    pub zoom: f32,
+}
+
+impl Default for Viewport {
+    fn default() -> Self {
+        Self { center: (0.0, 0.0), zoom: 1.0 }
+    }
+}
+
+impl Viewport {
+    /// Creates a [`Viewport`] centered on `center` with the given `zoom`.
+    pub fn new(center: (f32, f32), zoom: f32) -> Self {
+        Self { center, zoom }
+    }
+
+    /// Returns the scene coord rect `((xmin, ymin), (xmax, ymax))` visible on
+    /// `stage`, or `None` if `zoom` is not finite and positive.
+    pub fn visible_rect(&self, stage: &Stage) -> Option<Bounds> {
+        if !self.zoom.is_finite() || self.zoom <= 0.0 { return None; }
+
+        let ((x0, y0), (x1, y1)) = stage.world_bounds();
+        let to_scene = |(x, y): (f32, f32)| (x / self.zoom + self.center.0, y / self.zoom + self.center.1);
+        Some((to_scene((x0, y0)), to_scene((x1, y1))))
+    }
+}
+
+/// What [`Scene::render_with_stats`] did with one item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemStatus {
+    /// The path has no nodes, so there is nothing to draw.
+    Empty,
+    /// The item bounds miss the visible rect, so it was skipped.
+    Culled,
+    /// The item was rasterized.
+    Drawn,
+}
+
+/// Per-item report of [`Scene::render_with_stats`].
+///
+/// Fields:
+/// - status: [`ItemStatus`] - whether the item was culled or drawn.
+/// - pixels: [usize] - pixels whose value the item changed, `0` unless drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ItemStats {
+    pub status: ItemStatus,
+    pub pixels: usize,
+}
+
+/// One retained draw call.
+struct SceneItem {
+    path: Path,
+    style: Style,
+    /// Scene coord bounds including the stroke, `None` for empty paths.
+    bounds: Option<Bounds>,
+}
+
+/// A retained list of paths and styles in scene coords, redrawn through a
+/// [`Viewport`] each frame.
+///
+/// Item bounds are computed once on insertion, and items whose bounds miss
+/// the visible rect are skipped without converting or rasterizing them, so
+/// large scenes only pay for what is on screen. Items draw in insertion order.
+#[derive(Default)]
+pub struct Scene {
+    items: Vec<SceneItem>,
+}
+
+impl Scene {
+    /// Creates an empty [`Scene`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends `path` drawn with `style` and returns its index.
+    pub fn push(&mut self, path: Path, style: Style) -> usize {
+        let pad = style.stroke.map_or(0.0, |s| if s.hairline { 0.0 } else { 0.5 * s.width });
+        let bounds = path.bounds().map(|((x0, y0), (x1, y1))| ((x0 - pad, y0 - pad), (x1 + pad, y1 + pad)));
+
+        self.items.push(SceneItem { path, style, bounds });
+        self.items.len() - 1
+    }
+
+    /// Returns the number of items.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if there are no items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Removes every item.
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+
+    /// Draws every item intersecting the visible rect of `viewport` onto
+    /// `stage` and returns how many were drawn.
+    ///
+    /// Stroke widths scale with `viewport.zoom`, except for hairlines.
+    ///
+    /// Arguments:
+    /// - stage: &mut [`Stage`] - stage to draw onto.
+    /// - viewport: &[`Viewport`] - scene to stage mapping.
+    pub fn render(&self, stage: &mut Stage, viewport: &Viewport) -> usize {
+        let Some(visible) = viewport.visible_rect(stage) else { return 0; };
+
+        let mut drawn = 0;
+        for item in &self.items {
+            let Some(bounds) = item.bounds else { continue; };
+            if !intersects(bounds, visible) { continue; }
+
+            item.draw(stage, viewport);
+            drawn += 1;
+        }
+        drawn
+    }
+
+    /// Like [`Scene::render`], but also reports for every item, by index,
+    /// whether it was culled and how many pixels drawing it changed.
+    ///
+    /// Drawn items with `0` pixels are invisible, e.g. fully transparent,
+    /// covered by the scissor or painted in the color already underneath,
+    /// and are candidates for removal. Each drawn item snapshots its pixel
+    /// bounds to count changes, so use this for debugging, not every frame.
+    ///
+    /// Arguments:
+    /// - stage: &mut [`Stage`] - stage to draw onto.
+    /// - viewport: &[`Viewport`] - scene to stage mapping.
+    pub fn render_with_stats(&self, stage: &mut Stage, viewport: &Viewport) -> Vec<ItemStats> {
+        let visible = viewport.visible_rect(stage);
+
+        self.items
+            .iter()
+            .map(|item| {
+                let Some(bounds) = item.bounds else {
+                    return ItemStats { status: ItemStatus::Empty, pixels: 0 };
+                };
+                if !visible.is_some_and(|v| intersects(bounds, v)) {
+                    return ItemStats { status: ItemStatus::Culled, pixels: 0 };
+                }
+
+                let before = stage.snapshot_region(pixel_rect(stage, viewport, bounds));
+                item.draw(stage, viewport);
+
+                let r = before.rect();
+                let pixels = (r.y..r.bottom())
+                    .flat_map(|y| stage.row(y)[r.x..r.right()].iter())
+                    .zip(before.pixels())
+                    .filter(|(a, b)| a != b)
+                    .count();
+                ItemStats { status: ItemStatus::Drawn, pixels }
+            })
+            .collect()
+    }
+}
+
+impl SceneItem {
+    /// Draws `self` onto `stage` through `viewport`.
+    fn draw(&self, stage: &mut Stage, viewport: &Viewport) {
+        let mut style = self.style;
+        if let Some(stroke) = style.stroke.as_mut().filter(|s| !s.hairline) {
+            stroke.width *= viewport.zoom;
+        }
+        self.path.zoomed(viewport.center, viewport.zoom).render(stage, style);
+    }
+}
+
+/// Returns the pixel rect of `stage` covering the scene coord `bounds`,
+/// padded for rounding.
+fn pixel_rect(stage: &Stage, viewport: &Viewport, bounds: Bounds) -> Rect {
+    let to_pxl = |(x, y): (f32, f32)| {
+        stage.world_to_pxl_f32(((x - viewport.center.0) * viewport.zoom, (y - viewport.center.1) * viewport.zoom))
+    };
+    let ((x0, y1), (x1, y0)) = (to_pxl(bounds.0), to_pxl(bounds.1));
+    let (w, h) = stage.dimensions();
+    let clamp = |v: f32, max: usize| v.clamp(0.0, max as f32) as usize;
+
+    let (left, top) = (clamp((x0 - 2.0).floor(), w), clamp((y0 - 2.0).floor(), h));
+    let (right, bottom) = (clamp((x1 + 3.0).ceil(), w), clamp((y1 + 3.0).ceil(), h));
+    Rect::new(left, top, right.saturating_sub(left), bottom.saturating_sub(top))
+}
+
+fn intersects(a: Bounds, b: Bounds) -> bool {
+    a.0.0 <= b.1.0 && b.0.0 <= a.1.0 && a.0.1 <= b.1.1 && b.0.1 <= a.1.1
+}
@@ -0,0 +1,214 @@
+use crate::{Fill, Path, Stage, Style};
+use crate::path::MIN_DASH_PERIOD;
+
+/// Draws the stroke of a circular arc in world coords.
+///
+/// Strokes wider than one pixel are filled as the exact annular sector
+/// `stroke.width` wide centered on `radius`, with square ends along the radii.
+///
+/// Arguments:
+/// - stage: &mut [`Stage`] - stage to draw onto.
+/// - center: ([f32], [f32]) - world coord of the circle center.
+/// - radius: [f32] - radius in world units.
+/// - start_angle: [f32] - start angle in degrees, counter-clockwise from `+x`.
+/// - end_angle: [f32] - end angle in degrees; below `start_angle` runs
+///   clockwise. At most one full turn is drawn.
+/// - style: [`Style`] - only the stroke is used.
+pub fn arc(
+    stage: &mut Stage,
+    center: (f32, f32),
+    radius: f32,
+    start_angle: f32,
+    end_angle: f32,
+    style: Style,
+) {
+    let (start, sweep) = (start_angle, end_angle - start_angle);
+    let style = style.resolve_hairline();
+    let Some(stroke) = style.stroke else { return; };
+    if stroke.width.is_finite() && stroke.width > 1.0 {
+        render_sector(stage, center, radius, (start, sweep), style);
+        return;
+    }
+
+    let Some(samples) = sample_conic(center, (radius, radius), start, sweep) else { return; };
+    let nodes = samples.into_iter().map(|(p, _)| p).collect();
+    Path::new(nodes, false).render(stage, stroke_only(style));
+}
+
+/// Fills the annular sector covered by the thick stroke of `style`, so its edges
+/// follow the true circles rather than a chain of segment quads.
+fn render_sector(stage: &mut Stage, center: (f32, f32), radius: f32, (start, sweep): (f32, f32), style: Style) {
+    let Some(stroke) = style.stroke else { return; };
+    let half = 0.5 * stroke.width;
+    let ring = |r: f32| sample_conic(center, (r, r), start, sweep).map(|s| s.into_iter().map(|(p, _)| p));
+    let Some(outer) = ring(radius + half) else { return; };
+
+    let mut nodes: Vec<(f32, f32)> = outer.collect();
+    match ring(radius - half) {
+        Some(inner) => nodes.extend(inner.rev()),
+        None => nodes.push(center),
+    }
+
+    let fill = Fill::new(stroke.color, stroke.opacity);
+    Path::new(nodes, true).render(stage, Style { fill: Some(fill), stroke: None, ..style });
+}
+
+/// Draws a circle whose stroke follows the on/off `dashes` pattern.
+///
+/// The pattern is stretched slightly so it repeats a whole number of times
+/// around the circle, leaving no short dash where the ends meet.
+///
+/// Arguments:
+/// - stage: &mut [`Stage`] - stage to draw onto.
+/// - center: ([f32], [f32]) - world coord of the circle center.
+/// - radius: [f32] - radius in world units.
+/// - dashes: &[[f32]] - alternating dash and gap lengths in world units.
+/// - offset: [f32] - distance into the pattern at angle `0`.
+/// - style: [`Style`] - fill and dashed stroke.
+pub fn dashed_circle(
+    stage: &mut Stage,
+    center: (f32, f32),
+    radius: f32,
+    dashes: &[f32],
+    offset: f32,
+    style: Style,
+) {
+    dashed_ellipse(stage, center, radius, radius, dashes, offset, style);
+}
+
+/// Draws an axis-aligned ellipse whose stroke follows the on/off `dashes`
+/// pattern, measured along the curve so dashes stay evenly spaced however
+/// eccentric the ellipse is. See [`dashed_circle`].
+pub fn dashed_ellipse(
+    stage: &mut Stage,
+    center: (f32, f32),
+    rx: f32,
+    ry: f32,
+    dashes: &[f32],
+    offset: f32,
+    style: Style,
+) {
+    if style.fill.is_some() {
+        Path::ellipse(center, rx, ry).render(stage, Style { stroke: None, ..style });
+    }
+    let Some(samples) = sample_conic(center, (rx, ry), 0.0, 360.0) else { return; };
+    render_dashes(stage, &samples, dashes, offset, true, stroke_only(style));
+}
+
+/// Draws a circular arc whose stroke follows the on/off `dashes` pattern,
+/// starting `offset` into the pattern at the start angle. See [`arc`].
+///
+/// Arguments:
+/// - angles: ([f32], [f32]) - `(start_angle, end_angle)` in degrees, as for [`arc`].
+pub fn dashed_arc(
+    stage: &mut Stage,
+    center: (f32, f32),
+    radius: f32,
+    (start_angle, end_angle): (f32, f32),
+    dashes: &[f32],
+    offset: f32,
+    style: Style,
+) {
+    let Some(samples) = sample_conic(center, (radius, radius), start_angle, end_angle - start_angle) else { return; };
+    render_dashes(stage, &samples, dashes, offset, false, stroke_only(style));
+}
+
+fn stroke_only(style: Style) -> Style {
+    Style { fill: None, ..style }
+}
+
+/// Samples an elliptical arc by angle, pairing each point with the arc length
+/// travelled to reach it.
+pub(crate) fn sample_conic(
+    center: (f32, f32),
+    (rx, ry): (f32, f32),
+    start: f32,
+    sweep: f32,
+) -> Option<Vec<((f32, f32), f32)>> {
+    let valid = |v: f32| v.is_finite();
+    if !valid(center.0) || !valid(center.1) || !valid(start) || !valid(sweep) { return None; }
+    if !valid(rx) || !valid(ry) || rx <= 0.0 || ry <= 0.0 || sweep == 0.0 { return None; }
+
+    let sweep = sweep.clamp(-360.0, 360.0);
+    let span = rx.max(ry) * sweep.to_radians().abs();
+    let steps = ((span / 2.0).ceil() as usize).clamp(16, 4096);
+
+    let mut out: Vec<((f32, f32), f32)> = Vec::with_capacity(steps + 1);
+    let mut travelled = 0.0;
+    for i in 0..=steps {
+        let t = (start + sweep * i as f32 / steps as f32).to_radians();
+        let p = (center.0 + rx * t.cos(), center.1 + ry * t.sin());
+        if let Some(&((qx, qy), _)) = out.last() {
+            travelled += ((p.0 - qx).powi(2) + (p.1 - qy).powi(2)).sqrt();
+        }
+        out.push((p, travelled));
+    }
+    Some(out)
+}
+
+/// Strokes the "on" intervals of `dashes` along the sampled curve.
+fn render_dashes(
+    stage: &mut Stage,
+    samples: &[((f32, f32), f32)],
+    dashes: &[f32],
+    offset: f32,
+    closed: bool,
+    style: Style,
+) {
+    let total = samples.last().map_or(0.0, |&(_, d)| d);
+    if total <= 0.0 { return; }
+
+    let period: f32 = dashes.iter().sum();
+    let usable = !dashes.is_empty() && dashes.iter().all(|d| d.is_finite() && *d >= 0.0) && period > 0.0 && period.is_finite();
+    if !usable {
+        let nodes = samples.iter().map(|&(p, _)| p).collect();
+        Path::new(nodes, false).render(stage, style);
+        return;
+    }
+    // an odd pattern alternates on and off across two repeats
+    let (dashes, period) = if dashes.len() % 2 == 1 { (dashes.repeat(2), 2.0 * period) } else { (dashes.to_vec(), period) };
+
+    // stretch fine patterns to the minimum period, or to at most
+    // `MAX_DASH_PERIODS` periods along the curve, so every dash advances
+    let k = (MIN_DASH_PERIOD as f32 / period).max(total / (MAX_DASH_PERIODS as f32 * period)).max(1.0);
+    let period = period * k;
+    // stretch closed patterns to tile the perimeter exactly
+    let scale = k * if closed { total / ((total / period).round().max(1.0) * period) } else { 1.0 };
+    let offset = if offset.is_finite() { offset.rem_euclid(period / k) * scale } else { 0.0 };
+
+    // ends[j] is where pattern entry j stops within one period
+    let ends: Vec<f32> = dashes.iter().scan(0.0, |acc, &l| { *acc += l * scale; Some(*acc) }).collect();
+    let step = ends[ends.len() - 1];
+
+    // periods start just before 0 so the offset is honored
+    let count = ((total + offset) / step).ceil() as usize;
+    for p in 0..count {
+        let base = p as f32 * step - offset;
+        for j in (0..dashes.len()).step_by(2) {
+            let start = if j == 0 { 0.0 } else { ends[j - 1] };
+            let (a, b) = ((base + start).max(0.0), (base + ends[j]).min(total));
+            if a < b {
+                Path::new(slice_at(samples, a, b), false).render(stage, style);
+            }
+        }
+    }
+}
+
+/// Most pattern periods laid along one dashed curve; finer patterns are
+/// scaled up to fit.
+const MAX_DASH_PERIODS: usize = 1 << 16;
+
+/// Returns the polyline of `samples` between arc lengths `a` and `b`.
+fn slice_at(samples: &[((f32, f32), f32)], a: f32, b: f32) -> Vec<(f32, f32)> {
+    let point_at = |d: f32| {
+        let i = samples.partition_point(|&(_, s)| s < d).clamp(1, samples.len() - 1);
+        let ((p0, d0), (p1, d1)) = (samples[i - 1], samples[i]);
+        let t = if d1 > d0 { (d - d0) / (d1 - d0) } else { 0.0 };
+        (p0.0 + (p1.0 - p0.0) * t, p0.1 + (p1.1 - p0.1) * t)
+    };
+
+    let mut nodes = vec![point_at(a)];
+    nodes.extend(samples.iter().filter(|&&(_, d)| d > a && d < b).map(|&(p, _)| p));
+    nodes.push(point_at(b));
+    nodes
+}
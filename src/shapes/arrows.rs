@@ -0,0 +1,178 @@
+use crate::{Path, Stage, Style};
+
+type Point = (f32, f32);
+
+/// Decoration at one end of an [`arrow`], a diagram connector or a quiver
+/// arrow, drawn in the stroke color of the line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ArrowHead {
+    /// Plain line end.
+    None,
+    /// Filled triangle with its point on the end.
+    #[default]
+    Triangle,
+    /// Open "V" of two stroked wings meeting at the end.
+    OpenV,
+    /// Filled disc touching the end.
+    Dot,
+    /// Stroked bar across the end.
+    Bar,
+}
+
+impl ArrowHead {
+    /// Returns how far back from the end the line stops for `self` of length
+    /// `size`, so thick strokes don't poke through filled heads.
+    fn inset(self, size: f32) -> f32 {
+        match self {
+            ArrowHead::Triangle | ArrowHead::Dot => size,
+            ArrowHead::None | ArrowHead::OpenV | ArrowHead::Bar => 0.0,
+        }
+    }
+
+    /// Draws `self`, `size` world units long, at `tip` pointing along the
+    /// unit direction `(ux, uy)`.
+    ///
+    /// Filled heads use the stroke color of `style` as their fill, stroked
+    /// heads its stroke. Does nothing without a stroke.
+    ///
+    /// Arguments:
+    /// - stage: &mut [`Stage`] - stage to draw onto.
+    /// - tip: ([f32], [f32]) - world coord the head points at.
+    /// - dir: ([f32], [f32]) - unit direction of the head.
+    /// - size: [f32] - head length in world units.
+    /// - style: [`Style`] - only the stroke and blend mode are used.
+    pub fn render(self, stage: &mut Stage, tip: Point, (ux, uy): Point, size: f32, style: Style) {
+        let Some(stroke) = style.stroke else { return; };
+        if !size.is_finite() || size <= 0.0 { return; }
+
+        let fill = Style { blend: style.blend, ..Style::fill_only(stroke.rgba()) };
+        let line = Style { fill: None, ..style };
+        let back = (tip.0 - ux * size, tip.1 - uy * size);
+        let wing = 0.35 * size;
+        let left = (back.0 - uy * wing, back.1 + ux * wing);
+        let right = (back.0 + uy * wing, back.1 - ux * wing);
+
+        match self {
+            ArrowHead::None => {}
+            ArrowHead::Triangle => Path::new(vec![tip, left, right], true).render(stage, fill),
+            ArrowHead::OpenV => Path::new(vec![left, tip, right], false).render(stage, line),
+            ArrowHead::Dot => {
+                let r = 0.5 * size;
+                Path::circle((tip.0 - ux * r, tip.1 - uy * r), r).render(stage, fill);
+            }
+            ArrowHead::Bar => {
+                let (a, b) = ((tip.0 - uy * wing, tip.1 + ux * wing), (tip.0 + uy * wing, tip.1 - ux * wing));
+                Path::new(vec![a, b], false).render(stage, line);
+            }
+        }
+    }
+}
+
+/// End decorations of an arrow.
+///
+/// Fields:
+/// - head: [`ArrowHead`] - decoration at the end.
+/// - tail: [`ArrowHead`] - decoration at the start, pointing backwards.
+/// - size: [f32] - decoration length in world units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArrowStyle {
+    pub head: ArrowHead,
+    pub tail: ArrowHead,
+    pub size: f32,
+}
+
+impl Default for ArrowStyle {
+    fn default() -> Self {
+        Self { head: ArrowHead::Triangle, tail: ArrowHead::None, size: 8.0 }
+    }
+}
+
+impl ArrowStyle {
+    /// [`ArrowStyle`] without decorations.
+    pub const NONE: Self = Self { head: ArrowHead::None, tail: ArrowHead::None, size: 0.0 };
+
+    /// Creates an [`ArrowStyle`] with a `head` of length `size` and no tail.
+    pub fn new(head: ArrowHead, size: f32) -> Self {
+        Self { head, tail: ArrowHead::None, size }
+    }
+
+    /// Returns `self` with `tail` at the start.
+    pub fn with_tail(mut self, tail: ArrowHead) -> Self {
+        self.tail = tail;
+        self
+    }
+}
+
+/// Draws a straight arrow from `from` to `to` with the stroke of `style`.
+///
+/// Arguments:
+/// - stage: &mut [`Stage`] - stage to draw onto.
+/// - from: ([f32], [f32]) - world coord of the tail.
+/// - to: ([f32], [f32]) - world coord of the head.
+/// - arrow: [`ArrowStyle`] - end decorations.
+/// - style: [`Style`] - only the stroke is used.
+pub fn arrow(stage: &mut Stage, from: Point, to: Point, arrow: ArrowStyle, style: Style) {
+    render_arrow(stage, &[from, to], arrow, style);
+}
+
+/// Strokes the polyline `nodes` with `style` and decorates its ends with
+/// `arrow`, shortening the line under filled decorations.
+///
+/// Decorations are skipped when the line is not longer than both of them.
+pub(crate) fn render_arrow(stage: &mut Stage, nodes: &[Point], arrow: ArrowStyle, style: Style) {
+    if style.stroke.is_none() || nodes.len() < 2 { return; }
+    let line = Style { fill: None, ..style };
+
+    let size = if arrow.size.is_finite() { arrow.size.max(0.0) } else { 0.0 };
+    let length: f32 = nodes.windows(2).map(|w| (w[1].0 - w[0].0).hypot(w[1].1 - w[0].1)).sum();
+    let (head, tail) = (arrow.head.inset(size), arrow.tail.inset(size));
+    if size == 0.0 || length <= head + tail || length <= size {
+        return Path::new(nodes.to_vec(), false).render(stage, line);
+    }
+
+    let tip = nodes[nodes.len() - 1];
+    let start = nodes[0];
+    let mut body = trim_end(nodes, head);
+    body.reverse();
+    let mut body = trim_end(&body, tail);
+    body.reverse();
+    Path::new(body, false).render(stage, line);
+
+    // decorations point along the last and first segments
+    if let Some(dir) = end_direction(nodes.iter().rev()) {
+        arrow.head.render(stage, tip, dir, size, style);
+    }
+    if let Some(dir) = end_direction(nodes.iter()) {
+        arrow.tail.render(stage, start, dir, size, style);
+    }
+}
+
+/// Returns the unit direction into the first of `nodes` from the nearest
+/// distinct node after it.
+fn end_direction<'a, I: Iterator<Item = &'a Point>>(mut nodes: I) -> Option<Point> {
+    let end = *nodes.next()?;
+    nodes.find_map(|p| {
+        let (dx, dy) = (end.0 - p.0, end.1 - p.1);
+        let len = dx.hypot(dy);
+        (len > 0.0).then(|| (dx / len, dy / len))
+    })
+}
+
+/// Returns `nodes` with the last `length` world units removed.
+fn trim_end(nodes: &[Point], length: f32) -> Vec<Point> {
+    let mut out = nodes.to_vec();
+    let mut remaining = length;
+    while out.len() >= 2 {
+        let (b, a) = (out[out.len() - 1], out[out.len() - 2]);
+        let seg = ((b.0 - a.0).powi(2) + (b.1 - a.1).powi(2)).sqrt();
+        if seg > remaining {
+            let t = remaining / seg;
+            let last = out.len() - 1;
+            out[last] = (b.0 + t * (a.0 - b.0), b.1 + t * (a.1 - b.1));
+            break;
+        }
+        remaining -= seg;
+        out.pop();
+    }
+    out
+}
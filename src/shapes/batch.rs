@@ -0,0 +1,88 @@
+//! Batched drawing of many shapes sharing one [`Style`].
+//!
+//! Per-shape [`Path`] construction and world to pixel setup are hoisted out of
+//! the loop, shapes entirely outside the drawable area are culled, and the rest
+//! are drawn top to bottom so writes walk the framebuffer in order. Because of
+//! that reordering, overlapping shapes may stack differently than the input.
+
+use crate::{Path, Stage, Style};
+use crate::path::radii_pxl;
+use crate::primitives::circle::draw_circle_pxl;
+
+type Point = (f32, f32);
+
+/// Draws many circles in world coords with a shared `style`.
+///
+/// Equivalent to calling [`circle`](crate::shapes::circle) for each entry,
+/// without the per-call overhead.
+///
+/// Arguments:
+/// - stage: &mut [`Stage`] - stage to draw onto.
+/// - circles: &[(([f32], [f32]), [f32])] - `(center, radius)` pairs in world coords.
+/// - style: [`Style`] - struct containing styling args.
+pub fn circles_batch(stage: &mut Stage, circles: &[(Point, f32)], style: Style) {
+    if !style.fill_or_stroke_exists() { return; }
+    let style = style.resolve_hairline();
+
+    let pad = stroke_pad(style);
+    let mut items: Vec<((isize, isize), isize)> = circles
+        .iter()
+        .filter(|&&(_, r)| r.is_finite() && r > 0.0)
+        .filter_map(|&(center, r)| Some((stage.world_to_pxl(center)?, radii_pxl((r, r)).0)))
+        .filter(|&(c, r)| overlaps_draw_rect(stage, c, (r + pad, r + pad)))
+        .collect();
+    items.sort_unstable_by_key(|&((x, y), _)| (y, x));
+
+    for (center, r) in items {
+        draw_circle_pxl(stage, center, r, style);
+    }
+}
+
+/// Draws many axis-aligned rectangles in world coords with a shared `style`.
+///
+/// Equivalent to calling [`rectangle`](crate::shapes::rectangle) for each
+/// entry, without the per-call overhead.
+///
+/// Arguments:
+/// - stage: &mut [`Stage`] - stage to draw onto.
+/// - rects: &[(([f32], [f32]), ([f32], [f32]))] - `(center, (width, height))` pairs in world coords.
+/// - style: [`Style`] - struct containing styling args.
+pub fn rectangles_batch(stage: &mut Stage, rects: &[(Point, (f32, f32))], style: Style) {
+    if !style.fill_or_stroke_exists() { return; }
+
+    let pad = stroke_pad(style);
+    let mut items: Vec<[(isize, isize); 4]> = rects
+        .iter()
+        .filter(|&&(_, (w, h))| w.is_finite() && w > 0.0 && h.is_finite() && h > 0.0)
+        .filter_map(|&((x, y), (w, h))| {
+            let (hw, hh) = (0.5 * w, 0.5 * h);
+            let tl = stage.world_to_pxl((x - hw, y + hh))?;
+            let br = stage.world_to_pxl((x + hw, y - hh))?;
+            Some([tl, (br.0, tl.1), br, (tl.0, br.1)])
+        })
+        .filter(|&[tl, _, br, _]| {
+            let center = ((tl.0 + br.0) / 2, (tl.1 + br.1) / 2);
+            let half = ((br.0 - tl.0) / 2 + 1 + pad, (br.1 - tl.1) / 2 + 1 + pad);
+            overlaps_draw_rect(stage, center, half)
+        })
+        .collect();
+    items.sort_unstable_by_key(|&[(x, y), ..]| (y, x));
+
+    for nodes in items {
+        Path::render_pxl(&nodes, true, stage, style);
+    }
+}
+
+/// Extra pixels a stroke reaches beyond a shape outline.
+pub(crate) fn stroke_pad(style: Style) -> isize {
+    style.resolve_hairline().stroke.map_or(0, |s| (0.5 * s.width).ceil().max(1.0) as isize)
+}
+
+/// Returns `true` if the box `center ± half` in pixel coords touches the
+/// drawable area of `stage`.
+pub(crate) fn overlaps_draw_rect(stage: &Stage, center: (isize, isize), half: (isize, isize)) -> bool {
+    let r = stage.draw_rect();
+    let (x0, x1) = (center.0.saturating_sub(half.0), center.0.saturating_add(half.0));
+    let (y0, y1) = (center.1.saturating_sub(half.1), center.1.saturating_add(half.1));
+    x1 >= r.x as isize && x0 < r.right() as isize && y1 >= r.y as isize && y0 < r.bottom() as isize
+}
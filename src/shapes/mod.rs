@@ -1,5 +1,14 @@
 pub mod polygons; 
-pub use polygons::{line, triangle, rectangle, equilateral_triangle, square}; 
+pub use polygons::{line, polyline, triangle, rectangle, rounded_rectangle, equilateral_triangle, square}; 
 
 pub mod circles; 
-pub use circles::circle; 
+pub use circles::{circle, ellipse}; 
+
+pub mod arcs; 
+pub use arcs::{arc, dashed_arc, dashed_circle, dashed_ellipse}; 
+
+pub mod batch; 
+pub use batch::{circles_batch, rectangles_batch}; 
+
+pub mod arrows; 
+pub use arrows::{arrow, ArrowHead, ArrowStyle}; 
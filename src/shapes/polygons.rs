@@ -1,4 +1,5 @@
-use crate::{Stage, Style, Path};
+use crate::{Fill, Path, Stage, Style};
+use crate::shapes::arcs::sample_conic;
 
 const SQRT3: f32 = 1.7320508;
 
@@ -21,6 +22,24 @@ pub fn line(
     line_path.render(stage, style); 
 }
 
+/// Draws an open polyline through `nodes` in world coords as one path, so
+/// thick strokes are joined at the vertices instead of leaving the gaps of
+/// separate [`line`] calls. Only the stroke of `style` is used.
+///
+/// Arguments: 
+/// - stage: &mut [`Stage`] - stage to draw onto. 
+/// - nodes: &[([f32], [f32])] - ordered vertex coords. 
+/// - style: [`Style`] - struct containing style args. 
+pub fn polyline( 
+    stage: &mut Stage, 
+    nodes: &[(f32, f32)], 
+    style: Style, 
+) { 
+    let polyline_path = Path::new(nodes.to_vec(), false); 
+
+    polyline_path.render(stage, Style { fill: None, ..style }); 
+}
+
 /// Draws a triangle using three world coords. 
 ///
 /// Arguments: 
@@ -61,16 +80,8 @@ pub fn rectangle(
         return; 
     } 
 
-    // pixel coords
-    let (stage_width, stage_height) = stage.dimensions();
-
     // clamp to stage 
-    let stage_width = stage_width as f32; 
-    let stage_height = stage_height as f32; 
-    let min_x = -stage_width / 2.0; 
-    let max_x = stage_width / 2.0;  
-    let min_y = -stage_height / 2.0; 
-    let max_y = stage_height / 2.0;  
+    let ((min_x, min_y), (max_x, max_y)) = stage.world_bounds(); 
     
     let (x, y) = origin; 
     let hhalf = height / 2.0; 
@@ -146,14 +157,7 @@ pub fn square(
         return; 
     } 
 
-    let (stage_width, stage_height) = stage.dimensions(); 
-    let stage_width = stage_width as f32; 
-    let stage_height = stage_height as f32; 
-
-    let xmin = -stage_width / 2.0; 
-    let xmax = stage_width / 2.0; 
-    let ymin = -stage_height / 2.0; 
-    let ymax = stage_height / 2.0; 
+    let ((xmin, ymin), (xmax, ymax)) = stage.world_bounds(); 
 
     let (xc, yc) = origin; 
     let side_half = side_length / 2.0; 
@@ -172,3 +176,87 @@ pub fn square(
     square_path.render(stage, style); 
 }
 
+
+
+/// Draws a rectangle centered on `origin` of given `width` and `height` whose
+/// corners are quarter circles of `radius`, in world coords.
+///
+/// `radius` is clamped to half the shorter side, so a large radius gives a
+/// pill shape. Strokes wider than one pixel are filled as the exact band
+/// between the rounded outlines `stroke.width / 2` outside and inside the
+/// nominal one, so corners stay smooth however thick the stroke is.
+///
+/// Arguments: 
+/// - stage: &mut [Stage] - stage to draw onto. 
+/// - origin: ([f32], [f32]) - coords for origin. 
+/// - width: [f32] - width of rectangle. 
+/// - height: [f32] - height of rectangle. 
+/// - radius: [f32] - corner radius. 
+/// - style: [Style] - struct containing style args. 
+pub fn rounded_rectangle( 
+    stage: &mut Stage, 
+    origin: (f32, f32), 
+    width: f32, 
+    height: f32, 
+    radius: f32, 
+    style: Style, 
+) { 
+    if !height.is_finite() || height <= 0.0 || !width.is_finite() || width <= 0.0 { 
+        return; 
+    } 
+    let radius = if radius.is_finite() { radius.max(0.0) } else { 0.0 };
+    let style = style.resolve_hairline();
+
+    if style.fill.is_some() { 
+        let outline = Path::new(rounded_rect_nodes(origin, width, height, radius), true); 
+        outline.render(stage, Style { stroke: None, ..style }); 
+    } 
+
+    let Some(stroke) = style.stroke else { return; }; 
+    if !stroke.width.is_finite() || stroke.width <= 1.0 { 
+        let outline = Path::new(rounded_rect_nodes(origin, width, height, radius), true); 
+        outline.render(stage, Style { fill: None, ..style }); 
+        return; 
+    } 
+
+    let sw = stroke.width; 
+    let radius = radius.min(0.5 * width.min(height)); 
+    let mut nodes = rounded_rect_nodes(origin, width + sw, height + sw, radius + 0.5 * sw); 
+    if width > sw && height > sw { 
+        // outer ring, then the inner ring backwards, joined by a seam at the start
+        let inner = rounded_rect_nodes(origin, width - sw, height - sw, (radius - 0.5 * sw).max(0.0)); 
+        nodes.push(nodes[0]); 
+        nodes.push(inner[0]); 
+        nodes.extend(inner.iter().skip(1).rev()); 
+        nodes.push(inner[0]); 
+    } 
+
+    let band = Fill::new(stroke.color, stroke.opacity); 
+    Path::new(nodes, true).render(stage, Style { fill: Some(band), stroke: None, ..style }); 
+}
+
+/// Returns the outline of a rounded rectangle counter-clockwise, starting at
+/// the right end of the bottom edge. `radius` is clamped to half the
+/// shorter side.
+fn rounded_rect_nodes(origin: (f32, f32), width: f32, height: f32, radius: f32) -> Vec<(f32, f32)> { 
+    let (xc, yc) = origin; 
+    let (whalf, hhalf) = (0.5 * width, 0.5 * height); 
+    let r = radius.min(whalf.min(hhalf)); 
+
+    let (ix, iy) = (whalf - r, hhalf - r); 
+    let corners = [ 
+        ((xc + ix, yc - iy), 270.0), 
+        ((xc + ix, yc + iy), 0.0), 
+        ((xc - ix, yc + iy), 90.0), 
+        ((xc - ix, yc - iy), 180.0), 
+    ]; 
+
+    let mut nodes = Vec::new(); 
+    for (center, start) in corners { 
+        match sample_conic(center, (r, r), start, 90.0) { 
+            Some(arc) => nodes.extend(arc.into_iter().map(|(p, _)| p)), 
+            None => nodes.push(center), 
+        } 
+    } 
+    nodes 
+}
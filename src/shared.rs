@@ -0,0 +1,114 @@
+//! Thread-safe tiled canvas for drawing disjoint regions concurrently.
+
+use std::sync::{Mutex, PoisonError};
+
+use crate::{Rect, Stage};
+
+/// A canvas partitioned into square tiles, each an independently locked [`Stage`].
+///
+/// Every tile shares the world coords of the full canvas, so a shape drawn into
+/// a tile lands exactly where it would on a single [`Stage`] of the same size,
+/// clipped to that tile. Threads drawing different tiles never contend.
+///
+/// Fields:
+/// - width, height: [usize] - canvas dimensions.
+/// - tile_size: [usize] - tile side length in pixels. Edge tiles may be smaller.
+/// - tiles: Vec<Mutex<[`Stage`]>> - row major tiles.
+pub struct SharedStage {
+    width: usize,
+    height: usize,
+    tile_size: usize,
+    tiles_x: usize,
+    tiles_y: usize,
+    tiles: Vec<Mutex<Stage>>,
+}
+
+impl SharedStage {
+    /// Creates a `width` x `height` [`SharedStage`] split into `tile_size` tiles.
+    ///
+    /// Arguments:
+    /// - width: [usize]: canvas width.
+    /// - height: [usize]: canvas height.
+    /// - tile_size: [usize]: tile side length in pixels.
+    pub fn new(width: usize, height: usize, tile_size: usize) -> Self {
+        assert!(width > 0 && height > 0, "Stage must be strictly positive in size");
+        assert!(tile_size > 0, "tile size must be strictly positive");
+
+        let tiles_x = width.div_ceil(tile_size);
+        let tiles_y = height.div_ceil(tile_size);
+
+        let center = ((width as f32 - 1.0) * 0.5, (height as f32 - 1.0) * 0.5);
+        let mut tiles = Vec::with_capacity(tiles_x * tiles_y);
+        for ty in 0..tiles_y {
+            for tx in 0..tiles_x {
+                let r = tile_rect(width, height, tile_size, tx, ty);
+                let mut tile = Stage::new(r.width, r.height);
+                tile.set_origin_pxl((center.0 - r.x as f32, center.1 - r.y as f32));
+                tiles.push(Mutex::new(tile));
+            }
+        }
+
+        Self { width, height, tile_size, tiles_x, tiles_y, tiles }
+    }
+
+    /// Returns the dimensions `(width, height)` of the canvas.
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    /// Returns the number of tiles `(columns, rows)`.
+    pub fn tile_count(&self) -> (usize, usize) {
+        (self.tiles_x, self.tiles_y)
+    }
+
+    /// Returns the canvas region covered by tile `(tx, ty)` in pixel coords.
+    pub fn tile_rect(&self, tx: usize, ty: usize) -> Rect {
+        tile_rect(self.width, self.height, self.tile_size, tx, ty)
+    }
+
+    /// Locks tile `(tx, ty)` and runs `draw` on it. Blocks only if another
+    /// thread is drawing the same tile.
+    ///
+    /// Arguments:
+    /// - tx, ty: [usize] - tile column and row.
+    /// - draw: `FnOnce(&mut Stage)` - draw calls in canvas world coords.
+    pub fn draw_tile<F: FnOnce(&mut Stage)>(&self, tx: usize, ty: usize, draw: F) {
+        assert!(tx < self.tiles_x && ty < self.tiles_y, "tile out of range");
+
+        let mut tile = self.tiles[ty * self.tiles_x + tx]
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        draw(&mut tile);
+    }
+
+    /// Runs `draw` on every tile in turn, e.g. for shapes spanning tiles.
+    pub fn draw_all<F: Fn(&mut Stage)>(&self, draw: F) {
+        for ty in 0..self.tiles_y {
+            for tx in 0..self.tiles_x {
+                self.draw_tile(tx, ty, &draw);
+            }
+        }
+    }
+
+    /// Merges every tile into a single [`Stage`].
+    pub fn into_stage(self) -> Stage {
+        let mut out = Stage::new(self.width, self.height);
+
+        for (i, tile) in self.tiles.into_iter().enumerate() {
+            let tile = tile.into_inner().unwrap_or_else(PoisonError::into_inner);
+            let r = tile_rect(self.width, self.height, self.tile_size, i % self.tiles_x, i / self.tiles_x);
+
+            for y in 0..r.height {
+                out.row_mut(r.y + y)[r.x..r.right()].copy_from_slice(tile.row(y));
+            }
+        }
+
+        out
+    }
+}
+
+fn tile_rect(width: usize, height: usize, tile_size: usize, tx: usize, ty: usize) -> Rect {
+    let x = tx * tile_size;
+    let y = ty * tile_size;
+    Rect::new(x, y, tile_size.min(width - x), tile_size.min(height - y))
+}
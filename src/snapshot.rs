@@ -0,0 +1,59 @@
+//! Cheap copies of [`Stage`] pixels for undo and re-stamping workflows.
+
+use crate::{Rect, Stage};
+
+/// Copy of the pixels of a [`Stage`] region, created by [`Stage::snapshot`]
+/// or [`Stage::snapshot_region`].
+#[derive(Clone)]
+pub struct StageSnapshot {
+    rect: Rect,
+    pixels: Vec<[u8; 4]>,
+}
+
+impl StageSnapshot {
+    /// Returns the region of the [`Stage`] captured by `self` in pixel coords.
+    pub fn rect(&self) -> Rect {
+        self.rect
+    }
+
+    /// Returns the captured pixels in row major order.
+    pub fn pixels(&self) -> &[[u8; 4]] {
+        &self.pixels
+    }
+}
+
+impl Stage {
+    /// Captures every pixel of the [`Stage`].
+    pub fn snapshot(&self) -> StageSnapshot {
+        self.snapshot_region(self.bounds())
+    }
+
+    /// Captures the pixels inside `rect`. The part of `rect` outside the
+    /// [`Stage`] is dropped.
+    pub fn snapshot_region(&self, rect: Rect) -> StageSnapshot {
+        let Some(rect) = rect.intersect(self.bounds()) else {
+            return StageSnapshot { rect: Rect::new(0, 0, 0, 0), pixels: Vec::new() };
+        };
+
+        let mut pixels = Vec::with_capacity(rect.width * rect.height);
+        for y in rect.y..rect.bottom() {
+            pixels.extend_from_slice(&self.row(y)[rect.x..rect.right()]);
+        }
+
+        StageSnapshot { rect, pixels }
+    }
+
+    /// Writes the pixels of `snapshot` back to the region they were captured
+    /// from. Clips and scissors are ignored. Rows outside the [`Stage`] are skipped.
+    pub fn restore(&mut self, snapshot: &StageSnapshot) {
+        let r = snapshot.rect;
+        if r.is_empty() { return; }
+
+        let Some(visible) = r.intersect(self.bounds()) else { return; };
+        for y in visible.y..visible.bottom() {
+            let src_row = &snapshot.pixels[(y - r.y) * r.width..(y - r.y + 1) * r.width];
+            let src = &src_row[visible.x - r.x..visible.right() - r.x];
+            self.row_mut(y)[visible.x..visible.right()].copy_from_slice(src);
+        }
+    }
+}
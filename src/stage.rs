@@ -1,17 +1,139 @@
-use crate::Color;
-use std::path::Path; 
+use crate::{Color, Error, Fill, Opacity, Paint, Path, Rect};
+use crate::clip::ClipMask;
+use crate::png_stream::png_err;
+use std::ops::{Deref, DerefMut};
+use std::ptr::NonNull;
+use std::path::Path as FsPath; 
+use std::thread::{self, JoinHandle};
 use image::{ColorType, ImageFormat, ImageResult}; 
 
 
+/// How drawing combines colors with the pixels already on a [`Stage`], set
+/// per stage with [`Stage::set_blend_mode`] or per draw with [`Style::with_blend`].
+///
+/// The separable modes mix the source and backdrop colors channel by channel,
+/// then composite the result source-over, so the effective alpha still
+/// controls their strength. [`Stage::clear`], scrolling and region copies
+/// always replace pixels.
+///
+/// [`Style::with_blend`]: crate::Style::with_blend
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlendMode {
+    /// Composites colors whose effective alpha is below 255 over the
+    /// framebuffer using source-over. Opaque colors are written directly.
+    #[default]
+    SourceOver,
+    /// Replaces the destination RGBA, alpha included. The fastest path.
+    Overwrite,
+    /// Multiplies the channels, darkening like stacked ink. Shading.
+    Multiply,
+    /// Inverse multiply, lightening like stacked projectors.
+    Screen,
+    /// Multiply on dark backdrops and screen on light ones.
+    Overlay,
+    /// Keeps the darker of each channel.
+    Darken,
+    /// Keeps the lighter of each channel.
+    Lighten,
+    /// Adds the channels, saturating at white. Glow.
+    Add,
+    /// Absolute difference of the channels.
+    Difference,
+}
+
+impl BlendMode {
+    /// Blends one backdrop channel `b` with a source channel `s`, both in
+    /// `[0.0, 1.0]`. Only meaningful for the separable modes.
+    #[inline(always)]
+    fn mix(self, b: f32, s: f32) -> f32 {
+        match self {
+            BlendMode::SourceOver | BlendMode::Overwrite => s,
+            BlendMode::Multiply => b * s,
+            BlendMode::Screen => b + s - b * s,
+            BlendMode::Overlay => {
+                if b <= 0.5 { 2.0 * b * s } else { 1.0 - 2.0 * (1.0 - b) * (1.0 - s) }
+            }
+            BlendMode::Darken => b.min(s),
+            BlendMode::Lighten => b.max(s),
+            BlendMode::Add => (b + s).min(1.0),
+            BlendMode::Difference => (b - s).abs(),
+        }
+    }
+}
+
 /// `Stage` struct containing a row major framebuffer
-/// of length `width * height` containing RGBA `[u8; 4]`
+/// of length `stride * height` containing RGBA `[u8; 4]`
 /// array for each pixel.
+///
+/// `stride` is the row pitch in pixels. It equals `width` unless the
+/// [`Stage`] was created with [`Stage::with_stride`], in which case each
+/// row is followed by `stride - width` padding pixels that are never drawn.
 pub struct Stage { 
     width: usize, 
     height: usize, 
-    framebuf: Vec<[u8; 4]> 
+    stride: usize, 
+    framebuf: Framebuf, 
+    clip: Option<ClipMask>,
+    scissors: Vec<Rect>,
+    origin: (f32, f32),
+    blend: BlendMode,
+    /// Subpixels per axis [`Path::render`] resolves edges with, `1` for crisp.
+    path_aa: usize,
+    /// Whether 1px strokes are drawn anti-aliased.
+    line_antialias: bool,
+}
+
+
+/// Returns the `stride * height` pixels a `width` x `height` framebuffer
+/// with rows `stride` pixels apart holds, checking that it fits in memory.
+fn framebuf_len(width: usize, height: usize, stride: usize) -> Result<usize, Error> {
+    if width == 0 || height == 0 { 
+        return Err(Error::ZeroDimension { width, height }); 
+    }
+    if stride < width { 
+        return Err(Error::StrideTooSmall { width, stride }); 
+    }
+    stride
+        .checked_mul(height)
+        .filter(|n| n.checked_mul(std::mem::size_of::<[u8; 4]>()).is_some_and(|b| b <= isize::MAX as usize))
+        .ok_or(Error::DimensionsOverflow { width, height })
+}
+
+/// Pixels of a [`Stage`], either its own or lent for one
+/// [`Stage::draw_into`] or [`FixedStage::draw`](crate::FixedStage::draw).
+enum Framebuf {
+    Owned(Vec<[u8; 4]>),
+    /// Borrowed for the lifetime of the [`Stage`], see [`Stage::lend`].
+    Lent(NonNull<[[u8; 4]]>),
+}
+
+// SAFETY: a lent framebuffer is an exclusive borrow, `&mut [[u8; 4]]`,
+// which is `Send` and `Sync`.
+unsafe impl Send for Framebuf {}
+unsafe impl Sync for Framebuf {}
+
+impl Deref for Framebuf {
+    type Target = [[u8; 4]];
+
+    fn deref(&self) -> &[[u8; 4]] {
+        match self {
+            Framebuf::Owned(v) => v,
+            // SAFETY: `Stage::lend` keeps the pixels borrowed while the
+            // stage lives and aborts if the stage could outlive them.
+            Framebuf::Lent(p) => unsafe { p.as_ref() },
+        }
+    }
 }
 
+impl DerefMut for Framebuf {
+    fn deref_mut(&mut self) -> &mut [[u8; 4]] {
+        match self {
+            Framebuf::Owned(v) => v,
+            // SAFETY: as in `deref`.
+            Framebuf::Lent(p) => unsafe { p.as_mut() },
+        }
+    }
+}
 
 /// Constructor and Getters.
 impl Stage { 
@@ -24,15 +146,189 @@ impl Stage {
     /// Returns: 
     /// [`Stage`] of size `(width, height)`. 
     pub fn new(width: usize, height: usize) -> Self {
-        assert!(width > 0 && height > 0, "Stage must be strictly positive in size"); 
-        let length = width
-            .checked_mul(height)
-            .expect("Stage dimensions overflow");
+        match Self::try_new(width, height) { 
+            Ok(stage) => stage, 
+            Err(e) => panic!("{e}"), 
+        }
+    }
+
+    /// Checked form of [`Stage::new`].
+    ///
+    /// Returns: 
+    /// - `Err(Error::ZeroDimension)`: if `width` or `height` is zero. 
+    /// - `Err(Error::DimensionsOverflow)`: if the framebuffer would exceed `isize::MAX` bytes. 
+    /// - `Err(Error::AllocationFailed)`: if the framebuffer could not be allocated. 
+    /// - `Ok(Stage)`: otherwise. 
+    pub fn try_new(width: usize, height: usize) -> Result<Self, Error> {
+        Self::try_with_stride(width, height, width)
+    }
+
+    /// Creates a `width` x `height` [`Stage`] whose rows are `stride` pixels apart,
+    /// matching framebuffers and staging buffers that require row alignment.
+    /// [`Stage::from_vec`] and [`Stage::draw_into`] use an existing buffer instead.
+    ///
+    /// [`Stage::pixels`] and [`Stage::as_bytes`] need tightly packed rows, so
+    /// a padded [`Stage`] is read with [`Stage::raw_pixels`] and [`Stage::raw_bytes`].
+    ///
+    /// Arguments: 
+    /// - width: [usize]: stage width. 
+    /// - height: [usize]: stage height.
+    /// - stride: [usize]: row pitch in pixels, at least `width`. 
+    pub fn with_stride(width: usize, height: usize, stride: usize) -> Self {
+        match Self::try_with_stride(width, height, stride) { 
+            Ok(stage) => stage, 
+            Err(e) => panic!("{e}"), 
+        }
+    }
+
+    /// Creates a `width` x `height` [`Stage`] that anti-aliases everything
+    /// drawn through [`Path`] by rasterizing each draw at `factor`x resolution
+    /// per axis in a scratch tile and box-downsampling it onto the stage.
+    ///
+    /// There is no stage-wide high resolution framebuffer: every path is
+    /// resolved on its own as it is drawn, so [`Stage::as_bytes`] and
+    /// [`Stage::save_png`] read the smoothed pixels at no extra cost, but
+    /// shapes meeting along a shared edge blend their partial coverage
+    /// separately and may leave a faint seam. Pixel, span and image
+    /// drawing stays aliased. Use
+    /// [`Path::render_with`] and [`RenderOptions::CRISP`](crate::RenderOptions::CRISP)
+    /// to keep single draws, e.g. grid lines, aliased.
+    ///
+    /// Arguments: 
+    /// - width: [usize]: stage width. 
+    /// - height: [usize]: stage height.
+    /// - factor: [usize]: subpixels per axis, clamped to `1..=16`. `1` is a plain [`Stage`].
+    pub fn new_with_path_aa(width: usize, height: usize, factor: usize) -> Self {
+        let mut stage = Self::new(width, height); 
+        stage.set_path_aa(factor); 
+        stage 
+    }
+
+    /// Checked form of [`Stage::with_stride`].
+    ///
+    /// Returns: 
+    /// - `Err(Error::ZeroDimension)`: if `width` or `height` is zero. 
+    /// - `Err(Error::StrideTooSmall)`: if `stride < width`. 
+    /// - `Err(Error::DimensionsOverflow)`: if the framebuffer would exceed `isize::MAX` bytes. 
+    /// - `Err(Error::AllocationFailed)`: if the framebuffer could not be allocated. 
+    /// - `Ok(Stage)`: otherwise. 
+    pub fn try_with_stride(width: usize, height: usize, stride: usize) -> Result<Self, Error> {
+        let length = framebuf_len(width, height, stride)?;
+
+        // report allocation failure instead of aborting the process
+        let mut framebuf = Vec::new();
+        framebuf
+            .try_reserve_exact(length)
+            .map_err(|_| Error::AllocationFailed { width, height })?;
+        framebuf.resize(length, [0, 0, 0, 0]);
+
+        Ok(Self::from_framebuf(width, height, stride, Framebuf::Owned(framebuf)))
+    }
+
+    /// Creates a `width` x `height` [`Stage`] that takes ownership of `pixels`,
+    /// a row major buffer whose rows are `stride` pixels apart, without
+    /// copying it. [`Stage::into_raw_pixels`] hands the buffer back.
+    ///
+    /// Arguments: 
+    /// - width: [usize]: stage width. 
+    /// - height: [usize]: stage height.
+    /// - stride: [usize]: row pitch in pixels, at least `width`. 
+    /// - pixels: Vec<[u8; 4]>: RGBA pixels, exactly `stride * height` of them.
+    ///
+    /// Returns: 
+    /// - `Err(Error::BufferLength)`: if `pixels` does not hold `stride * height` pixels. 
+    /// - `Err(_)`: for the dimension errors of [`Stage::try_with_stride`]. 
+    /// - `Ok(Stage)`: otherwise. 
+    pub fn from_vec(width: usize, height: usize, stride: usize, pixels: Vec<[u8; 4]>) -> Result<Self, Error> {
+        let length = framebuf_len(width, height, stride)?;
+        if pixels.len() != length { 
+            return Err(Error::BufferLength { len: pixels.len(), required: length }); 
+        }
+        Ok(Self::from_framebuf(width, height, stride, Framebuf::Owned(pixels)))
+    }
+
+    /// Runs `draw` against a `width` x `height` [`Stage`] that draws straight
+    /// into the caller's `pixels`, e.g. a memory-mapped display framebuffer
+    /// or a GPU staging buffer, with rows `stride` pixels apart.
+    ///
+    /// Pixels past the last of the `stride * height` used are left alone.
+    /// The lent [`Stage`] must stay where `draw` finds it: moving it out, e.g.
+    /// with [`std::mem::swap`], aborts the process once `draw` returns.
+    ///
+    /// Arguments: 
+    /// - width: [usize]: stage width. 
+    /// - height: [usize]: stage height.
+    /// - stride: [usize]: row pitch in pixels, at least `width`. 
+    /// - pixels: &mut [[u8; 4]]: RGBA pixels, at least `stride * height` of them.
+    /// - draw: `FnOnce(&mut Stage)` - draw calls, in world coords centered
+    ///   on the stage like a regular [`Stage`].
+    ///
+    /// Returns: 
+    /// - `Err(Error::BufferLength)`: if `pixels` holds fewer than `stride * height` pixels. 
+    /// - `Err(_)`: for the dimension errors of [`Stage::try_with_stride`]. 
+    /// - `Ok(())`: after running `draw`. 
+    pub fn draw_into<F: FnOnce(&mut Stage)>(
+        width: usize,
+        height: usize,
+        stride: usize,
+        pixels: &mut [[u8; 4]],
+        draw: F,
+    ) -> Result<(), Error> {
+        let length = framebuf_len(width, height, stride)?;
+        let len = pixels.len();
+        let Some(pixels) = pixels.get_mut(..length) else { 
+            return Err(Error::BufferLength { len, required: length }); 
+        };
+        Self::lend(width, height, stride, pixels, draw);
+        Ok(())
+    }
+
+    /// Runs `draw` against a [`Stage`] over `pixels`, which must hold
+    /// exactly `stride * height` pixels, aborting if the stage is moved out.
+    pub(crate) fn lend<F: FnOnce(&mut Stage)>(
+        width: usize,
+        height: usize,
+        stride: usize,
+        pixels: &mut [[u8; 4]],
+        draw: F,
+    ) {
+        /// Aborts if the lent stage was moved out of `stage`, since it could
+        /// then outlive the pixels it points at.
+        struct Lend<'a>(&'a mut Stage, *const [[u8; 4]]);
+
+        impl Drop for Lend<'_> {
+            fn drop(&mut self) {
+                let lent = matches!(self.0.framebuf, Framebuf::Lent(p) if std::ptr::eq(p.as_ptr(), self.1));
+                if !lent {
+                    std::process::abort();
+                }
+            }
+        }
 
+        debug_assert_eq!(Some(pixels.len()), stride.checked_mul(height));
+        let ptr: *const [[u8; 4]] = pixels;
+        // `pixels` stays borrowed until `stage` is dropped at the end of this
+        // call, and `Lend` ensures `stage` is still the lent stage then, also
+        // when `draw` panics.
+        let framebuf = Framebuf::Lent(NonNull::from(pixels));
+        let mut stage = Self::from_framebuf(width, height, stride, framebuf);
+        let lend = Lend(&mut stage, ptr);
+        draw(lend.0);
+    }
+
+    /// Wraps `framebuf` in a [`Stage`] with default settings.
+    fn from_framebuf(width: usize, height: usize, stride: usize, framebuf: Framebuf) -> Self {
         Self { 
             width, 
             height, 
-            framebuf: vec![[0, 0, 0, 0]; length], 
+            stride, 
+            framebuf, 
+            clip: None,
+            scissors: Vec::new(),
+            origin: ((width as f32 - 1.0) * 0.5, (height as f32 - 1.0) * 0.5),
+            blend: BlendMode::default(),
+            path_aa: 1,
+            line_antialias: false,
         }
     }
 
@@ -51,16 +347,50 @@ impl Stage {
         (self.width, self.height)
     }
 
-    /// Returns a reference to the [`Stage`] framebuffer.
+    /// Returns the row pitch of the [`Stage`] framebuffer in pixels.
+    pub fn stride(&self) -> usize { 
+        self.stride
+    }
+
+    /// Returns a reference to the [`Stage`] framebuffer, tightly packed
+    /// with `width` pixels per row.
+    ///
+    /// Panics if `self` has row padding, see [`Stage::raw_pixels`].
     pub fn pixels(&self) -> &[[u8; 4]] { 
-       &self.framebuf 
+        assert_eq!(self.stride, self.width, "Stage has row padding, use raw_pixels"); 
+        &self.framebuf 
     }
 
-    /// Returns a mutable reference to the [`Stage`] framebuffer.
+    /// Returns a mutable reference to the [`Stage`] framebuffer, tightly
+    /// packed with `width` pixels per row.
+    ///
+    /// Panics if `self` has row padding, see [`Stage::raw_pixels_mut`].
     pub fn pixels_mut(&mut self) -> &mut [[u8; 4]] { 
+        assert_eq!(self.stride, self.width, "Stage has row padding, use raw_pixels_mut"); 
+        &mut self.framebuf
+    }
+
+    /// Returns a reference to the whole [`Stage`] framebuffer, including
+    /// any row padding. Rows are [`Stage::stride`] pixels apart.
+    pub fn raw_pixels(&self) -> &[[u8; 4]] { 
+        &self.framebuf 
+    }
+
+    /// Returns a mutable reference to the whole [`Stage`] framebuffer,
+    /// including any row padding. Rows are [`Stage::stride`] pixels apart.
+    pub fn raw_pixels_mut(&mut self) -> &mut [[u8; 4]] { 
         &mut self.framebuf
     }
 
+    /// Returns the owned framebuffer of `self`, including any row padding,
+    /// or `None` if it draws into a borrowed buffer.
+    pub fn into_raw_pixels(self) -> Option<Vec<[u8; 4]>> { 
+        match self.framebuf { 
+            Framebuf::Owned(v) => Some(v), 
+            Framebuf::Lent(_) => None, 
+        }
+    }
+
     /// Gets the color value of a pixel at `(x, y)`.
     ///
     /// Returns `None` if out-of-bounds, otherwise `Some([u8; 4])`.
@@ -75,9 +405,28 @@ impl Stage {
         Some(self.framebuf[index])
     }
  
-    /// Returns the number of pixels in the [`Stage`].
+    /// Returns the [`Rect`] covering the whole [`Stage`].
+    pub fn bounds(&self) -> Rect { 
+        Rect::new(0, 0, self.width, self.height)
+    }
+
+    /// Returns a reference to the `width` pixels of row `y`.
+    pub(crate) fn row(&self, y: usize) -> &[[u8; 4]] { 
+        debug_assert!(y < self.height);
+        let start = y * self.stride; 
+        &self.framebuf[start .. start + self.width]
+    }
+
+    /// Returns a mutable reference to the `width` pixels of row `y`.
+    pub(crate) fn row_mut(&mut self, y: usize) -> &mut [[u8; 4]] { 
+        debug_assert!(y < self.height);
+        let start = y * self.stride; 
+        &mut self.framebuf[start .. start + self.width]
+    }
+ 
+    /// Returns the number of pixels in the [`Stage`], excluding row padding.
     pub fn len(&self) -> usize { 
-        self.framebuf.len()
+        self.width * self.height
     }
 
     /// Returns `true` if Stage is empty.
@@ -96,15 +445,68 @@ impl Stage {
         debug_assert!(x < self.width);
         debug_assert!(y < self.height);
 
-        y * self.width + x
+        y * self.stride + x
     }
 
 
-    /// Sets the [`Stage`] background to the provided `color`. 
+    /// Sets the [`Stage`] background to the provided `color`. Row padding
+    /// is left untouched.
     pub fn clear(&mut self, color: Color) { 
-        self.framebuf.fill(color.rgba()); 
+        let (rgba, width) = (color.rgba(), self.width); 
+        if self.stride == width { 
+            return self.framebuf.fill(rgba); 
+        }
+        for row in self.framebuf.chunks_mut(self.stride) { 
+            row[..width].fill(rgba); 
+        }
     } 
 
+    /// Sets how subsequent drawing combines colors with the framebuffer.
+    /// [`BlendMode::SourceOver`] by default.
+    pub fn set_blend_mode(&mut self, mode: BlendMode) { 
+        self.blend = mode; 
+    }
+
+    /// Sets the subpixels per axis each [`Path`] draw is anti-aliased with,
+    /// see [`Stage::new_with_path_aa`]. Clamped to `1..=16`, where `1` turns
+    /// anti-aliasing off.
+    pub fn set_path_aa(&mut self, factor: usize) { 
+        self.path_aa = factor.clamp(1, 16); 
+    }
+
+    /// Returns the subpixels per axis each [`Path`] draw is anti-aliased with,
+    /// `1` if it is off.
+    pub fn path_aa(&self) -> usize { 
+        self.path_aa 
+    }
+
+    /// Sets whether every 1px stroke is drawn with anti-aliased Xiaolin Wu
+    /// lines instead of Bresenham lines. Off by default. Individual strokes
+    /// can opt in with [`Stroke::with_antialias`](crate::Stroke::with_antialias).
+    pub fn set_line_antialias(&mut self, antialias: bool) { 
+        self.line_antialias = antialias; 
+    }
+
+    /// Returns `true` if every 1px stroke is drawn anti-aliased.
+    pub fn line_antialias(&self) -> bool { 
+        self.line_antialias 
+    }
+
+    /// Returns the active [`BlendMode`].
+    pub fn blend_mode(&self) -> BlendMode { 
+        self.blend 
+    }
+
+    /// Runs `draw` with the blend mode temporarily set to `mode`, or
+    /// unchanged if `None`, e.g. for a [`Style`](crate::Style) override.
+    pub(crate) fn with_blend<R, F: FnOnce(&mut Stage) -> R>(&mut self, mode: Option<BlendMode>, draw: F) -> R { 
+        let Some(mode) = mode else { return draw(self); }; 
+        let prev = std::mem::replace(&mut self.blend, mode); 
+        let out = draw(self); 
+        self.blend = prev; 
+        out 
+    }
+
 
     /// Sets the color value of a signed pixel at `(x, y)`.
     /// If the pixel is out-of-bounds, silently does nothing.
@@ -118,9 +520,232 @@ impl Stage {
 
         let color = color.rgba(); 
         let (xu, yu) = (x as usize, y as usize);
-        if xu < self.width && yu < self.height { 
-            let idx = yu * self.width + xu;
-            self.framebuf[idx] = color;
+        if self.in_draw_rect(xu, yu) { 
+            if let Some(clip) = &self.clip && !clip.contains(xu, yu) { 
+                return; 
+            }
+            let idx = yu * self.stride + xu;
+            write(&mut self.framebuf[idx], color, self.blend);
+        }
+    }
+}
+
+/// Cursors.
+impl Stage { 
+    /// Returns a [`PixelCursor`] positioned at pixel `(x, y)`.
+    ///
+    /// # Safety
+    /// Every pixel the cursor is at when [`PixelCursor::put`] is called must
+    /// lie inside [`Stage::draw_rect`], e.g. because the walk was clipped
+    /// to it, since writes skip the bounds check.
+    pub(crate) unsafe fn cursor(&mut self, x: usize, y: usize) -> PixelCursor<'_> { 
+        debug_assert!(self.in_draw_rect(x, y)); 
+        PixelCursor { 
+            index: y * self.stride + x, 
+            stride: self.stride, 
+            x, 
+            y, 
+            framebuf: &mut self.framebuf, 
+            clip: self.clip.as_ref(), 
+            blend: self.blend, 
+        }
+    }
+}
+
+/// Write cursor over a [`Stage`] framebuffer for incremental walks such as
+/// Bresenham lines.
+///
+/// Moving the cursor updates a precomputed framebuffer index instead of
+/// recomputing `y * stride + x`, and writes skip the draw rect test that
+/// [`Stage::plot_pxl`] does per pixel. The clip mask, if any, and the blend
+/// mode still apply.
+pub(crate) struct PixelCursor<'a> { 
+    framebuf: &'a mut [[u8; 4]], 
+    clip: Option<&'a ClipMask>, 
+    blend: BlendMode, 
+    stride: usize, 
+    index: usize, 
+    x: usize, 
+    y: usize, 
+}
+
+impl PixelCursor<'_> { 
+    /// Moves the cursor one pixel left (`-1`), right (`1`) or not at all (`0`).
+    #[inline(always)]
+    pub(crate) fn step_x(&mut self, sx: isize) { 
+        self.x = self.x.wrapping_add_signed(sx); 
+        self.index = self.index.wrapping_add_signed(sx); 
+    }
+
+    /// Moves the cursor one row up (`-1`), down (`1`) or not at all (`0`).
+    #[inline(always)]
+    pub(crate) fn step_y(&mut self, sy: isize) { 
+        self.y = self.y.wrapping_add_signed(sy); 
+        self.index = self.index.wrapping_add_signed(sy * self.stride as isize); 
+    }
+
+    /// Writes `rgba` at the cursor using the stage [`BlendMode`], unless the
+    /// clip mask excludes it.
+    #[inline(always)]
+    pub(crate) fn put(&mut self, rgba: [u8; 4]) { 
+        if let Some(clip) = self.clip && !clip.contains(self.x, self.y) { 
+            return; 
+        }
+        debug_assert!(self.index < self.framebuf.len()); 
+        debug_assert_eq!(self.index, self.y * self.stride + self.x); 
+        // SAFETY: 
+        // the `Stage::cursor` contract keeps writes inside the draw rect,
+        // which lies within the framebuffer, and `index` tracks `(x, y)`.
+        let px = unsafe { self.framebuf.get_unchecked_mut(self.index) }; 
+        write(px, rgba, self.blend); 
+    }
+}
+
+/// Clipping. 
+impl Stage { 
+    /// Restricts all subsequent drawing to the interior of `path`, intersected
+    /// with any clip already active. [`Stage::clear`] ignores the clip.
+    pub fn set_clip(&mut self, path: &Path) { 
+        let mask = ClipMask::from_path(path, self.width, self.height, self.origin); 
+        self.intersect_clip(mask); 
+    }
+
+    /// Intersects the active clip with `mask`, or makes it the clip if none is set.
+    pub(crate) fn intersect_clip(&mut self, mask: ClipMask) { 
+        match &mut self.clip { 
+            Some(clip) => clip.intersect(&mask), 
+            None => self.clip = Some(mask), 
+        }
+    }
+
+    /// Removes the active clip so the whole [`Stage`] is writable.
+    pub fn reset_clip(&mut self) { 
+        self.clip = None; 
+    }
+
+    /// Returns `true` if a clip is active.
+    pub fn has_clip(&self) -> bool { 
+        self.clip.is_some()
+    }
+
+    /// Runs `draw` with drawing additionally clipped to the interior of `path`,
+    /// then restores the previous clip.
+    ///
+    /// Arguments: 
+    /// - path: &[`Path`] - clip outline, treated as closed. 
+    /// - draw: `FnOnce(&mut Stage)` - draw calls to confine. 
+    pub fn with_clip<F: FnOnce(&mut Stage)>(&mut self, path: &Path, draw: F) { 
+        let saved = self.clip.clone(); 
+        self.set_clip(path); 
+        draw(self); 
+        self.clip = saved; 
+    }
+}
+
+/// Scissoring. 
+impl Stage { 
+    /// Pushes a scissor `rect` in pixel coords. Drawing is restricted to the
+    /// intersection of every pushed rect until it is popped.
+    /// [`Stage::clear`] ignores scissors.
+    pub fn push_scissor(&mut self, rect: Rect) { 
+        let current = self.draw_rect(); 
+        let next = current.intersect(rect).unwrap_or(Rect::new(0, 0, 0, 0)); 
+        self.scissors.push(next); 
+    }
+
+    /// Pops the most recently pushed scissor rect. Returns `None` if the
+    /// scissor stack is empty.
+    pub fn pop_scissor(&mut self) -> Option<Rect> { 
+        self.scissors.pop()
+    }
+
+    /// Returns the effective scissor rect, or `None` if no scissor is pushed.
+    pub fn scissor(&self) -> Option<Rect> { 
+        self.scissors.last().copied()
+    }
+
+    /// Returns the region drawing may touch: the effective scissor rect or
+    /// the whole [`Stage`].
+    #[inline(always)]
+    pub(crate) fn draw_rect(&self) -> Rect { 
+        match self.scissors.last() { 
+            Some(&r) => r, 
+            None => self.bounds(), 
+        }
+    }
+
+    #[inline(always)]
+    fn in_draw_rect(&self, x: usize, y: usize) -> bool { 
+        match self.scissors.last() { 
+            Some(r) => r.contains(x, y), 
+            None => x < self.width && y < self.height, 
+        }
+    }
+}
+
+/// Region copies. 
+impl Stage { 
+    /// Copies the pixels inside `src` so that its top-left corner lands on
+    /// `dest` in pixel coords. Overlapping source and destination are handled.
+    /// Parts falling outside the [`Stage`] are dropped. Clips and scissors are ignored.
+    ///
+    /// Arguments: 
+    /// - src: [`Rect`] - source region in pixel coords. 
+    /// - dest: ([isize], [isize]) - destination top-left in pixel coords. 
+    pub fn copy_within(&mut self, src: Rect, dest: (isize, isize)) { 
+        let Some(src) = src.intersect(self.bounds()) else { return; }; 
+
+        // clip the destination, shrinking the source to match
+        let (w, h) = (self.width as isize, self.height as isize); 
+        let (mut sx, mut sy) = (src.x as isize, src.y as isize); 
+        // offsets past a full stage drop everything, and clamping keeps the
+        // arithmetic below in range
+        let (mut dx, mut dy) = (dest.0.clamp(-w, w), dest.1.clamp(-h, h)); 
+        let (mut cw, mut ch) = (src.width as isize, src.height as isize); 
+
+        if dx < 0 { sx -= dx; cw += dx; dx = 0; } 
+        if dy < 0 { sy -= dy; ch += dy; dy = 0; } 
+        cw = cw.min(w - dx); 
+        ch = ch.min(h - dy); 
+        if cw <= 0 || ch <= 0 { return; } 
+
+        let (sx, sy, dx, dy) = (sx as usize, sy as usize, dx as usize, dy as usize); 
+        let (cw, ch) = (cw as usize, ch as usize); 
+
+        let mut copy_row = |r: usize| { 
+            let from = (sy + r) * self.stride + sx; 
+            let to = (dy + r) * self.stride + dx; 
+            self.framebuf.copy_within(from .. from + cw, to); 
+        };
+
+        // copy rows in the order that never reads an overwritten row
+        if dy > sy { 
+            (0..ch).rev().for_each(&mut copy_row); 
+        } else { 
+            (0..ch).for_each(&mut copy_row); 
+        }
+    }
+
+    /// Shifts the whole [`Stage`] by `(dx, dy)` pixels (`+x` right, `+y` down)
+    /// and fills the exposed edge with `fill_color`.
+    pub fn scroll(&mut self, dx: isize, dy: isize, fill_color: Color) { 
+        self.copy_within(self.bounds(), (dx, dy)); 
+
+        let (w, h) = (self.width as isize, self.height as isize); 
+        let fill = fill_color.rgba(); 
+
+        // exposed columns
+        let (x0, x1) = if dx >= 0 { (0, dx.min(w)) } else { ((w + dx).max(0), w) }; 
+        // exposed rows
+        let (y0, y1) = if dy >= 0 { (0, dy.min(h)) } else { ((h + dy).max(0), h) }; 
+
+        for y in 0..self.height { 
+            let row = self.row_mut(y); 
+            if (y as isize) >= y0 && (y as isize) < y1 { 
+                row.fill(fill); 
+            } else if x0 < x1 { 
+                row[x0 as usize .. x1 as usize].fill(fill); 
+            }
         }
     }
 }
@@ -128,22 +753,23 @@ impl Stage {
 /// Helpers. 
 impl Stage { 
     /// Returns the framebuffer as a contiguous `&[u8]` slice of RGBA bytes
-    /// in row major order suitable for rendering.
+    /// in row major order suitable for rendering, `width * 4` per row.
+    ///
+    /// Panics if `self` has row padding, see [`Stage::raw_bytes`].
     pub fn as_bytes(&self) -> &[u8] {
-        // SAFETY: 
-        // framebuf MUST remain Vec<[u8; 4]>. arrays of u8 have no padding. 
-        // so the data is tightly packed RGBA bytes.
-        unsafe { 
-            std::slice::from_raw_parts( 
-                self.framebuf.as_ptr() as *const u8, 
-                self.len() * std::mem::size_of::<[u8; 4]>()
-            )
-        }
+        self.pixels().as_flattened()
+    }
+
+    /// Returns the whole framebuffer as RGBA bytes, including any row
+    /// padding. Rows are `stride * 4` bytes apart.
+    pub fn raw_bytes(&self) -> &[u8] {
+        self.framebuf.as_flattened()
     }
 
     /// Converts world coordinates into pixel coordinates (origin top-left).
     ///
-    /// So far the world is fixed cartesian, no camera freedom. 
+    /// The world is cartesian with unit scale and its origin at the
+    /// [`Stage`] center, unless moved by tiling. 
     ///
     /// Returns 
     /// - `Some(isize, isize)`: if pixel coordinate is finite and representable
@@ -153,8 +779,7 @@ impl Stage {
             return None; 
         } 
 
-        let center_x = (self.width as f32 - 1.0) * 0.5; 
-        let center_y = (self.height as f32 - 1.0) * 0.5; 
+        let (center_x, center_y) = self.origin; 
 
         let px = (x + center_x).round(); 
         let py = (center_y - y).round();
@@ -165,44 +790,383 @@ impl Stage {
         Some((px as isize, py as isize))
     }
 
-    /// Fills contiguous pixels at row `y` from `x0` to `x1` inclusive with `color`.
-    /// `y`, `x0`, `x1` are in pixel coords. 
+    /// Converts world coordinates into fractional pixel coordinates, without
+    /// rounding. Inverse of [`Stage::pxl_to_world_f32`].
+    pub(crate) fn world_to_pxl_f32(&self, (x, y): (f32, f32)) -> (f32, f32) {
+        let (center_x, center_y) = self.origin; 
+
+        (x + center_x, center_y - y)
+    }
+
+    /// Sets the pixel position of the world origin `(0, 0)`. Defaults to the
+    /// [`Stage`] center.
+    ///
+    /// Strips or tiles of a larger canvas set this so shapes drawn in canvas
+    /// world coords land in the right place.
+    pub fn set_origin_pxl(&mut self, origin: (f32, f32)) {
+        self.origin = origin; 
+    }
+
+    /// Returns the pixel position of the world origin `(0, 0)`.
+    pub fn origin_pxl(&self) -> (f32, f32) {
+        self.origin
+    }
+
+    /// Returns the world coord corners `((xmin, ymin), (xmax, ymax))` of the
+    /// area covered by the [`Stage`] pixels, e.g. to fit a layout to it.
+    pub fn world_bounds(&self) -> ((f32, f32), (f32, f32)) {
+        let (x0, y1) = self.pxl_to_world_f32((-0.5, -0.5)); 
+        let (x1, y0) = self.pxl_to_world_f32((self.width as f32 - 0.5, self.height as f32 - 0.5)); 
+        ((x0, y0), (x1, y1))
+    }
+
+    /// Converts pixel coordinates into world coordinates. Inverse of [`Stage::world_to_pxl`].
+    pub(crate) fn pxl_to_world(&self, (px, py): (isize, isize)) -> (f32, f32) {
+        let (center_x, center_y) = self.origin; 
+
+        (px as f32 - center_x, center_y - py as f32)
+    }
+
+    /// Converts fractional pixel coordinates into world coordinates.
+    pub(crate) fn pxl_to_world_f32(&self, (px, py): (f32, f32)) -> (f32, f32) {
+        let (center_x, center_y) = self.origin; 
+
+        (px - center_x, center_y - py)
+    }
+
+    /// Composites `color` over the pixel at `(x, y)` using the stage
+    /// [`BlendMode`], source-over when it is [`BlendMode::Overwrite`], with the
+    /// effective alpha of `color` scaled by `coverage` in `[0.0, 1.0]`.
+    /// If the pixel is out-of-bounds, silently does nothing.
+    pub(crate) fn blend_pxl(&mut self, x: isize, y: isize, color: Color, coverage: f32) {
+        if x < 0 || y < 0 { 
+            return; 
+        } 
+
+        let (xu, yu) = (x as usize, y as usize);
+        if !self.in_draw_rect(xu, yu) { 
+            return; 
+        }
+
+        if let Some(clip) = &self.clip && !clip.contains(xu, yu) { 
+            return; 
+        }
+        let idx = yu * self.stride + xu;
+        self.framebuf[idx] = blend_with(self.blend, self.framebuf[idx], color.rgba(), coverage); 
+    }
+
+    /// Fills contiguous pixels at row `y` from `x0` to `x1` inclusive with `color`,
+    /// using the stage [`BlendMode`]. `y`, `x0`, `x1` are in pixel coords. 
     pub(crate) fn fill_span_pxl(&mut self, y: isize, x0: isize, x1: isize, color: Color) {
-        if y < 0 { return; } 
-        let y = y as usize; 
-        if y >= self.height { return; } 
+        self.write_span_pxl(y, x0, x1, color, self.blend); 
+    }
 
-        if x0 > x1 { return; }
+    /// Fills the span `x0..=x1` at row `y` with the paint of `fill`,
+    /// evaluating gradients at each pixel center.
+    pub(crate) fn fill_span_paint(&mut self, y: isize, x0: isize, x1: isize, fill: Fill) {
+        let g = match fill.paint {
+            Paint::Solid(_) => return self.fill_span_pxl(y, x0, x1, fill.rgba()),
+            Paint::Gradient(g) => g,
+        };
 
-        let mut a = x0; 
-        let mut b = x1; 
+        // world to pixel coords is a translation and flip, so offsets carry over
+        let a = self.world_to_pxl_f32(g.start);
+        let b = self.world_to_pxl_f32(g.end);
+        self.fill_span_with(y, x0, x1, |x| {
+            fill.with_opacity(g.sample(g.offset(a, b, (x as f32, y as f32))))
+        });
+    }
 
-        if b < 0 || a >= self.width as isize { return; } 
-        a = a.max(0); 
-        b = b.min(self.width as isize - 1);
-        if a > b { return; }
+    /// Like [`Stage::fill_span_pxl`], but always replaces the pixels, e.g. to
+    /// clear part of the stage.
+    pub(crate) fn replace_span_pxl(&mut self, y: isize, x0: isize, x1: isize, color: Color) {
+        self.write_span_pxl(y, x0, x1, color, BlendMode::Overwrite); 
+    }
 
-        let row = y * self.width; 
+    fn write_span_pxl(&mut self, y: isize, x0: isize, x1: isize, color: Color, mode: BlendMode) {
+        let Some((row, a, b)) = self.clip_span(y, x0, x1) else { return; };
         let color = color.rgba(); 
-        self.framebuf[row + a as usize .. row + b as usize + 1].fill(color); 
+
+        let fill = |span: &mut [[u8; 4]]| { 
+            if replaces(mode, color) { 
+                span.fill(color); 
+            } else { 
+                span.iter_mut().for_each(|px| *px = blend_with(mode, *px, color, 1.0)); 
+            }
+        };
+
+        match &self.clip { 
+            None => fill(&mut self.framebuf[row + a..=row + b]), 
+            Some(clip) => {
+                for (s, e) in clip.spans(y as usize, a, b) {
+                    fill(&mut self.framebuf[row + s..=row + e]);
+                }
+            }
+        }
+    }
+
+    /// Fills `rect` in pixel coords with `color`, one span per row. Scissors
+    /// and clips apply.
+    ///
+    /// Arguments:
+    /// - rect: [`Rect`] - region in pixel coords.
+    /// - color: [`Color`] - fill color, written with the stage [`BlendMode`].
+    pub fn fill_rect(&mut self, rect: Rect, color: Color) {
+        let Some(r) = rect.intersect(self.draw_rect()) else { return; };
+        let (x0, x1) = (r.x as isize, r.right() as isize - 1);
+        for y in r.y..r.bottom() {
+            self.fill_span_pxl(y as isize, x0, x1, color);
+        }
+    }
+
+    /// Sets pixels at row `y` from `x0` to `x1` inclusive to `shader(x)` using
+    /// the stage [`BlendMode`], where `x` is the pixel column. `y`, `x0`, `x1`
+    /// are in pixel coords.
+    ///
+    /// The span is first cut to the scissor rect and `shader` is only called
+    /// for pixels that pass the clip, so every fill (flat colors, gradients,
+    /// patterns, dithering) shares one clipped span loop.
+    ///
+    /// Arguments:
+    /// - y: [isize] - pixel row.
+    /// - x0, x1: [isize] - first and last pixel column.
+    /// - shader: `FnMut(isize) -> Color` - color of the pixel at column `x`.
+    pub fn fill_span_with<F: FnMut(isize) -> Color>(&mut self, y: isize, x0: isize, x1: isize, mut shader: F) {
+        let Some((row, a, b)) = self.clip_span(y, x0, x1) else { return; };
+
+        let blend = self.blend;
+        let mut shade = |s: usize, e: usize| {
+            for (x, px) in self.framebuf[row + s..=row + e].iter_mut().enumerate() {
+                write(px, shader((s + x) as isize).rgba(), blend);
+            }
+        };
+
+        match &self.clip {
+            None => shade(a, b),
+            Some(clip) => clip.spans(y as usize, a, b).for_each(|(s, e)| shade(s, e)),
+        }
+    }
+
+    /// Cuts the span `x0..=x1` at row `y` to the active draw rect, returning the
+    /// framebuffer row offset and the inclusive column range.
+    #[inline(always)]
+    fn clip_span(&self, y: isize, x0: isize, x1: isize) -> Option<(usize, usize, usize)> {
+        let r = self.draw_rect(); 
+        if y < r.y as isize || y >= r.bottom() as isize { return None; } 
+        if x0 > x1 { return None; }
+        if x1 < r.x as isize || x0 >= r.right() as isize { return None; } 
+
+        let a = x0.max(r.x as isize) as usize; 
+        let b = x1.min(r.right() as isize - 1) as usize;
+        Some((y as usize * self.stride, a, b))
+    }
+
+
+    /// Composites every pixel of `layer` over `self` using source-over, with
+    /// the alpha of `layer` additionally scaled by `opacity` in `[0.0, 1.0]`.
+    ///
+    /// The top-left pixel of `layer` lands on pixel `(x0, y0)` of `self`, and
+    /// only the part inside the draw rect of `self` is composited.
+    pub(crate) fn composite_layer(&mut self, layer: &Stage, (x0, y0): (usize, usize), opacity: f32) { 
+        let r = self.draw_rect(); 
+        let (x1, y1) = ((x0 + layer.width).min(r.right()), (y0 + layer.height).min(r.bottom())); 
+        let (xs, ys) = (x0.max(r.x), y0.max(r.y)); 
+        if xs >= x1 { 
+            return; 
+        }
+        for y in ys..y1 { 
+            let row = y * self.stride; 
+            let src_row = &layer.row(y - y0)[xs - x0..x1 - x0]; 
+            for (x, &src) in (xs..x1).zip(src_row) { 
+                if src[3] == 0 { 
+                    continue; 
+                }
+                if let Some(clip) = &self.clip && !clip.contains(x, y) { 
+                    continue; 
+                }
+                let idx = row + x; 
+                self.framebuf[idx] = blend_over(self.framebuf[idx], src, opacity); 
+            }
+        }
+    }
+
+    /// Runs `draw` on a transparent temporary layer and composites the result
+    /// onto `self` once with the given `opacity`.
+    ///
+    /// Overlapping shapes inside the group do not show internal seams, since the
+    /// group opacity is applied after they are flattened together. The layer
+    /// covers only the draw rect of `self` and shares its world coords, so
+    /// groups inside an [`InsetStage`](crate::InsetStage) land in the inset.
+    /// Nothing is drawn if the draw rect is empty.
+    ///
+    /// Arguments: 
+    /// - opacity: [`Opacity`] - group opacity. 
+    /// - draw: `FnOnce(&mut Stage)` - draw calls forming the group. 
+    pub fn group<F: FnOnce(&mut Stage)>(&mut self, opacity: Opacity, draw: F) { 
+        let r = self.draw_rect(); 
+        if r.is_empty() { 
+            return; 
+        }
+
+        let mut layer = Stage::new(r.width, r.height); 
+        layer.set_origin_pxl((self.origin.0 - r.x as f32, self.origin.1 - r.y as f32)); 
+        layer.path_aa = self.path_aa; 
+        layer.line_antialias = self.line_antialias; 
+        draw(&mut layer); 
+        self.composite_layer(&layer, (r.x, r.y), opacity.as_f32()); 
     }
 
 
     /// Saves a [`Stage`] as a `png`. 
-    pub fn save_png<P: AsRef<Path>>(&self, path: P) -> ImageResult<()> { 
+    pub fn save_png<P: AsRef<FsPath>>(&self, path: P) -> ImageResult<()> { 
         let (w, h) = self.dimensions(); 
 
-        let bytes = self.as_bytes(); 
+        let bytes = self.packed_bytes(); 
         assert_eq!(bytes.len(), w * h * 4); 
 
         image::save_buffer_with_format( 
             path, 
-            bytes, 
+            &bytes, 
             w as u32, 
             h as u32, 
             ColorType::Rgba8, 
             ImageFormat::Png, 
         )
     }
+
+    /// Saves a [`Stage`] as a `png` on a background thread.
+    ///
+    /// The framebuffer is copied before returning, so `self` can be drawn into
+    /// again immediately. Join the returned handle to get the encoding result.
+    pub fn save_png_async<P: AsRef<FsPath>>(&self, path: P) -> JoinHandle<ImageResult<()>> {
+        let (w, h) = self.dimensions();
+        let bytes = self.packed_bytes().into_owned();
+        let path = path.as_ref().to_path_buf();
+
+        thread::spawn(move || {
+            image::save_buffer_with_format(
+                path,
+                &bytes,
+                w as u32,
+                h as u32,
+                ColorType::Rgba8,
+                ImageFormat::Png,
+            )
+        })
+    }
+
+    /// Saves a [`Stage`] as a `png` with `metadata` stored as text chunks, e.g.
+    /// the title, software, or the seed and parameters a generative piece was
+    /// rendered with.
+    ///
+    /// Latin-1 values are written as `tEXt` chunks and anything else as UTF-8
+    /// `iTXt`. Keys must be 1 to 79 Latin-1 characters; otherwise an error is
+    /// returned and no file is written.
+    ///
+    /// Arguments:
+    /// - path: destination file.
+    /// - metadata: &[(&[str], &[str])] - `(key, value)` pairs, in order.
+    pub fn save_png_with_metadata<P: AsRef<FsPath>>(
+        &self,
+        path: P,
+        metadata: &[(&str, &str)],
+    ) -> ImageResult<()> {
+        let (w, h) = self.dimensions();
+        // encoded in memory first, so invalid keys leave `path` untouched
+        let mut png = Vec::new();
+
+        let mut encoder = png::Encoder::new(&mut png, w as u32, h as u32);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        for &(key, value) in metadata {
+            let (key, value) = (key.to_string(), value.to_string());
+            let added = if value.chars().all(|c| (c as u32) < 256) {
+                encoder.add_text_chunk(key, value)
+            } else {
+                encoder.add_itxt_chunk(key, value)
+            };
+            added.map_err(png_err)?;
+        }
+
+        let mut writer = encoder.write_header().map_err(png_err)?;
+        writer.write_image_data(&self.packed_bytes()).map_err(png_err)?;
+        writer.finish().map_err(png_err)?;
+
+        std::fs::write(path, png)?;
+        Ok(())
+    }
+
+    /// Returns the RGBA bytes of the [`Stage`] without row padding.
+    pub(crate) fn packed_bytes(&self) -> std::borrow::Cow<'_, [u8]> { 
+        if self.stride == self.width { 
+            return std::borrow::Cow::Borrowed(self.raw_bytes()); 
+        }
+
+        let mut out = Vec::with_capacity(self.len() * 4); 
+        for y in 0..self.height { 
+            out.extend(self.row(y).iter().flatten()); 
+        }
+        std::borrow::Cow::Owned(out)
+    }
 }
 
+
+/// Returns `true` if writing `src` under `mode` just replaces the pixel.
+#[inline(always)]
+fn replaces(mode: BlendMode, src: [u8; 4]) -> bool { 
+    mode == BlendMode::Overwrite || (mode == BlendMode::SourceOver && src[3] == 255) 
+}
+
+/// Writes `src` to `dst` under `mode`.
+#[inline(always)]
+fn write(dst: &mut [u8; 4], src: [u8; 4], mode: BlendMode) { 
+    if replaces(mode, src) { 
+        *dst = src; 
+    } else { 
+        *dst = blend_with(mode, *dst, src, 1.0); 
+    }
+}
+
+/// Blends `src` onto `dst` under `mode` with the alpha of `src` scaled by
+/// `coverage`. [`BlendMode::Overwrite`] composites source-over, since a
+/// partially covered pixel cannot be replaced.
+///
+/// Separable modes follow the W3C compositing model: the source color is
+/// mixed with its blend against the backdrop in proportion to the backdrop
+/// alpha, then composited source-over.
+#[inline(always)]
+fn blend_with(mode: BlendMode, dst: [u8; 4], src: [u8; 4], coverage: f32) -> [u8; 4] { 
+    if matches!(mode, BlendMode::SourceOver | BlendMode::Overwrite) || dst[3] == 0 { 
+        return blend_over(dst, src, coverage); 
+    }
+
+    let ab = dst[3] as f32 / 255.0; 
+    let mut mixed = src; 
+    for c in 0..3 { 
+        let (b, s) = (dst[c] as f32 / 255.0, src[c] as f32 / 255.0); 
+        let m = (1.0 - ab) * s + ab * mode.mix(b, s); 
+        mixed[c] = (m * 255.0).round().clamp(0.0, 255.0) as u8; 
+    }
+    blend_over(dst, mixed, coverage) 
+}
+
+/// Source-over composite of `src` onto `dst` with the alpha of `src` scaled by `coverage`.
+#[inline(always)]
+pub(crate) fn blend_over(dst: [u8; 4], src: [u8; 4], coverage: f32) -> [u8; 4] { 
+    let sa = src[3] as f32 / 255.0 * coverage.clamp(0.0, 1.0); 
+    if sa <= 0.0 { 
+        return dst; 
+    }
+
+    let da = dst[3] as f32 / 255.0; 
+    let out_a = sa + da * (1.0 - sa); 
+
+    let mut out = [0u8; 4]; 
+    for c in 0..3 { 
+        let s = src[c] as f32 * sa; 
+        let d = dst[c] as f32 * da * (1.0 - sa); 
+        out[c] = ((s + d) / out_a).round().clamp(0.0, 255.0) as u8; 
+    }
+    out[3] = (out_a * 255.0).round() as u8; 
+    out
+}
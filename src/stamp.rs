@@ -0,0 +1,284 @@
+//! Pre-rasterized shapes blitted at many positions.
+
+use crate::{Color, Opacity, Path, Stage, Style};
+use crate::shapes::batch::stroke_pad;
+
+/// A small shape rasterized once into a color tile, for stamping at many
+/// positions with [`Stage::stamp`].
+///
+/// Stamping skips per-instance path conversion and rasterization entirely,
+/// which makes it the fastest way to draw scatter plots and particles of
+/// identical shapes. Instances land on whole pixels.
+///
+/// Fields:
+/// - texels: Vec<(([usize], [usize]), [`Color`])> - non-transparent tile pixels.
+/// - width, height: [usize] - tile dimensions.
+/// - anchor: ([isize], [isize]) - tile pixel placed on each stamp position,
+///   outside the tile if the shape does not cover its local origin.
+/// - normals: Option<Vec<([f32], [f32], [f32])>> - unit normal per texel.
+pub struct Stamp {
+    texels: Vec<((usize, usize), Color)>,
+    width: usize,
+    height: usize,
+    anchor: (isize, isize),
+    normals: Option<Vec<(f32, f32, f32)>>,
+}
+
+/// Light for [`Stage::stamp_lit`].
+#[derive(Debug, Clone, Copy)]
+pub struct StampLight {
+    /// Direction towards the light in world coords. Only its angle matters.
+    pub direction: (f32, f32),
+    /// Height of the light above the stage plane in degrees.
+    pub elevation: f32,
+    /// Brightness of texels facing away from the light, in `[0.0, 1.0]`.
+    pub ambient: f32,
+}
+
+impl Default for StampLight {
+    fn default() -> Self {
+        Self { direction: (-1.0, 1.0), elevation: 45.0, ambient: 0.3 }
+    }
+}
+
+impl StampLight {
+    /// Returns the unit vector towards the light, `+z` out of the stage.
+    fn vector(self) -> (f32, f32, f32) {
+        let (dx, dy) = self.direction;
+        let len = dx.hypot(dy);
+        let (dx, dy) = if len.is_finite() && len > 0.0 { (dx / len, dy / len) } else { (0.0, 0.0) };
+        let (sin, cos) = self.elevation.to_radians().sin_cos();
+        (dx * cos, dy * cos, sin)
+    }
+}
+
+/// Lattice of marks laid by [`Stage::watermark`].
+#[derive(Debug, Clone, Copy)]
+pub struct Tiling {
+    /// World distance between marks along a row and between rows, at
+    /// least 1.
+    pub spacing: (f32, f32),
+    /// Angle of the rows in degrees, counterclockwise from `+x`.
+    pub angle: f32,
+    /// Whether every other row is shifted by half the spacing, like bricks.
+    pub stagger: bool,
+}
+
+impl Default for Tiling {
+    fn default() -> Self {
+        Self { spacing: (160.0, 100.0), angle: 30.0, stagger: true }
+    }
+}
+
+impl Stamp {
+    /// Rasterizes `path`, given in local coords around `(0, 0)`, with `style`.
+    /// The local origin is placed on each stamp position.
+    ///
+    /// Returns `None` if `path` has no nodes or non-finite nodes.
+    ///
+    /// Arguments:
+    /// - path: &[`Path`] - shape in local world coords.
+    /// - style: [`Style`] - struct containing style args.
+    pub fn from_path(path: &Path, style: Style) -> Option<Self> {
+        let finite = |p: &(f32, f32)| p.0.is_finite() && p.1.is_finite();
+        if !path.subpaths().all(|(nodes, _)| nodes.iter().all(finite)) {
+            return None;
+        }
+        let ((x0, y0), (x1, y1)) = path.bounds()?;
+
+        // one extra pixel for radii and vertices rounding outward
+        let pad = stroke_pad(style) + 1;
+        let (left, right) = (x0.floor() as isize - pad, x1.ceil() as isize + pad);
+        let (bottom, top) = (y0.floor() as isize - pad, y1.ceil() as isize + pad);
+        let width = (right - left + 1) as usize;
+        let height = (top - bottom + 1) as usize;
+        let anchor = (-left, top);
+
+        let mut tile = Stage::new(width, height);
+        tile.set_origin_pxl((anchor.0 as f32, anchor.1 as f32));
+        path.render(&mut tile, style);
+
+        let texels = tile
+            .pixels()
+            .iter()
+            .enumerate()
+            .filter(|(_, px)| px[3] != 0)
+            .map(|(i, &px)| ((i % width, i / width), Color::new(px)))
+            .collect();
+
+        Some(Self { texels, width, height, anchor, normals: None })
+    }
+
+    /// Attaches a normal map, sampled once per texel by `normal` at the
+    /// texel center in the local coords of [`Stamp::from_path`].
+    ///
+    /// Normals are `(x, y, z)` with `+y` up and `+z` out of the stage, and
+    /// are normalized here. Zero or non-finite normals face straight out.
+    /// The map only affects [`Stage::stamp_lit`].
+    ///
+    /// Arguments:
+    /// - normal: `Fn(f32, f32) -> (f32, f32, f32)` - surface normal at `(x, y)`.
+    pub fn with_normal_map<F: Fn(f32, f32) -> (f32, f32, f32)>(mut self, normal: F) -> Self {
+        let (ax, ay) = (self.anchor.0 as f32, self.anchor.1 as f32);
+        let normals = self
+            .texels
+            .iter()
+            .map(|&((x, y), _)| {
+                let (nx, ny, nz) = normal(x as f32 - ax, ay - y as f32);
+                let len = (nx * nx + ny * ny + nz * nz).sqrt();
+                if len.is_finite() && len > 0.0 { (nx / len, ny / len, nz / len) } else { (0.0, 0.0, 1.0) }
+            })
+            .collect();
+        self.normals = Some(normals);
+        self
+    }
+
+    /// Attaches the normal map of a dome of radius `radius` centered on the
+    /// local origin, which shades round stamps like spheres. Texels outside
+    /// the dome face straight out.
+    ///
+    /// Arguments:
+    /// - radius: [f32] - dome radius in local world coords.
+    pub fn with_dome_normals(self, radius: f32) -> Self {
+        self.with_normal_map(|x, y| {
+            let r2 = radius * radius;
+            let d2 = x * x + y * y;
+            if d2 < r2 { (x, y, (r2 - d2).sqrt()) } else { (0.0, 0.0, 1.0) }
+        })
+    }
+
+    /// Returns `true` if a normal map is attached.
+    pub fn has_normal_map(&self) -> bool {
+        self.normals.is_some()
+    }
+
+    /// Returns the tile dimensions `(width, height)`.
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+}
+
+impl Stage {
+    /// Blits `stamp` with its local origin on every world coord in `positions`,
+    /// compositing translucent tile pixels source-over.
+    ///
+    /// Arguments:
+    /// - stamp: &[`Stamp`] - pre-rasterized shape.
+    /// - positions: &[([f32], [f32])] - world coords of each instance.
+    pub fn stamp(&mut self, stamp: &Stamp, positions: &[(f32, f32)]) {
+        self.blit_stamp(stamp, positions, |_, color| color);
+    }
+
+    /// Blits `stamp` like [`Stage::stamp`], scaling each texel color by its
+    /// Lambert shading under `light`.
+    ///
+    /// Texels are shaded by the normal map attached with
+    /// [`Stamp::with_normal_map`]; without one the stamp is lit as flat.
+    ///
+    /// Arguments:
+    /// - stamp: &[`Stamp`] - pre-rasterized shape.
+    /// - positions: &[([f32], [f32])] - world coords of each instance.
+    /// - light: [`StampLight`] - light direction and ambient level.
+    pub fn stamp_lit(&mut self, stamp: &Stamp, positions: &[(f32, f32)], light: StampLight) {
+        let (lx, ly, lz) = light.vector();
+        let ambient = light.ambient.clamp(0.0, 1.0);
+        let shade = |(nx, ny, nz): (f32, f32, f32)| {
+            ambient + (1.0 - ambient) * (nx * lx + ny * ly + nz * lz).max(0.0)
+        };
+
+        // shade once per texel, not per instance
+        let shades: Vec<f32> = match &stamp.normals {
+            Some(normals) => normals.iter().map(|&n| shade(n)).collect(),
+            None => vec![shade((0.0, 0.0, 1.0)); stamp.texels.len()],
+        };
+        self.blit_stamp(stamp, positions, |i, color| {
+            let k = shades[i];
+            let scale = |c: u8| (c as f32 * k).round().clamp(0.0, 255.0) as u8;
+            Color::new([scale(color.r()), scale(color.g()), scale(color.b()), color.a()])
+        });
+    }
+
+    /// Repeats `stamp` across the drawable area on the rotated lattice
+    /// `tiling`, with its alpha scaled by `opacity`, e.g. to mark previews
+    /// and proofs.
+    ///
+    /// Only the lattice is rotated; rotate the mark itself by transforming
+    /// its path before [`Stamp::from_path`].
+    ///
+    /// Arguments:
+    /// - stamp: &[`Stamp`] - pre-rasterized mark.
+    /// - opacity: [`Opacity`] - multiplier for the mark alpha.
+    /// - tiling: [`Tiling`] - spacing, angle and stagger of the marks.
+    pub fn watermark(&mut self, stamp: &Stamp, opacity: Opacity, tiling: Tiling) {
+        let (sx, sy) = tiling.spacing;
+        if !(sx.is_finite() && sy.is_finite() && sx > 0.0 && sy > 0.0) { return; }
+        // closer marks only overlap, and would multiply the lattice size
+        let (sx, sy) = (sx.max(1.0), sy.max(1.0));
+        if !tiling.angle.is_finite() { return; }
+
+        let r = self.draw_rect();
+        if r.is_empty() { return; }
+
+        // row direction u and column direction v in world coords
+        let (sin, cos) = tiling.angle.to_radians().sin_cos();
+        let (u, v) = ((cos, sin), (-sin, cos));
+
+        // lattice extent covering the draw rect, padded by the tile size
+        let pad = stamp.width.max(stamp.height) as f32;
+        let corners = [
+            (r.x as f32 - pad, r.y as f32 - pad),
+            (r.right() as f32 + pad, r.y as f32 - pad),
+            (r.x as f32 - pad, r.bottom() as f32 + pad),
+            (r.right() as f32 + pad, r.bottom() as f32 + pad),
+        ]
+        .map(|p| self.pxl_to_world_f32(p));
+        let extent = |axis: (f32, f32), step: f32| {
+            let proj = corners.map(|p| p.0 * axis.0 + p.1 * axis.1);
+            let lo = proj.iter().copied().fold(f32::INFINITY, f32::min);
+            let hi = proj.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+            ((lo / step).floor() as i64 - 1, (hi / step).ceil() as i64 + 1)
+        };
+        let (i0, i1) = extent(u, sx);
+        let (j0, j1) = extent(v, sy);
+
+        // one lattice row at a time, so memory stays bounded by a row
+        let f = opacity.as_u8() as u16;
+        let mut positions = Vec::new();
+        for j in j0..=j1 {
+            let shift = if tiling.stagger && j.rem_euclid(2) == 1 { 0.5 } else { 0.0 };
+            positions.clear();
+            for i in i0..=i1 {
+                let (a, b) = ((i as f32 + shift) * sx, j as f32 * sy);
+                positions.push((a * u.0 + b * v.0, a * u.1 + b * v.1));
+            }
+            self.blit_stamp(stamp, &positions, |_, color| {
+                color.with_alpha(((color.a() as u16 * f + 127) / 255) as u8)
+            });
+        }
+    }
+
+    /// Blits `stamp` at every position, mapping the `i`-th texel color with
+    /// `texel(i, color)` first.
+    fn blit_stamp<F: Fn(usize, Color) -> Color>(&mut self, stamp: &Stamp, positions: &[(f32, f32)], texel: F) {
+        let draw = self.draw_rect();
+
+        for &p in positions {
+            let Some((cx, cy)) = self.world_to_pxl(p) else { continue; };
+            let (left, top) = (cx - stamp.anchor.0, cy - stamp.anchor.1);
+
+            if left >= draw.right() as isize || top >= draw.bottom() as isize { continue; }
+            if left + (stamp.width as isize) <= draw.x as isize { continue; }
+            if top + (stamp.height as isize) <= draw.y as isize { continue; }
+
+            for (i, &((x, y), color)) in stamp.texels.iter().enumerate() {
+                let (sx, sy) = (left + x as isize, top + y as isize);
+                let color = texel(i, color);
+                if color.a() == 255 {
+                    self.plot_pxl(sx, sy, color);
+                } else {
+                    self.blend_pxl(sx, sy, color, 1.0);
+                }
+            }
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use crate::{BlendMode, Error};
+
 /// [`Color`] struct containing an RGBA `[u8; 4]` array.
 #[derive(Debug, Clone, Copy)]
 pub struct Color([u8; 4]);
@@ -42,6 +44,176 @@ impl Color {
         rgba[3] = alpha;
         Self(rgba)
     }
+
+    /// Returns the red channel.
+    pub fn r(self) -> u8 { self.0[0] }
+
+    /// Returns the green channel.
+    pub fn g(self) -> u8 { self.0[1] }
+
+    /// Returns the blue channel.
+    pub fn b(self) -> u8 { self.0[2] }
+
+    /// Returns the intrinsic alpha channel.
+    pub fn a(self) -> u8 { self.0[3] }
+
+    /// Replaces the red channel of `self`.
+    pub fn with_red(self, red: u8) -> Self {
+        let mut rgba = self.0;
+        rgba[0] = red;
+        Self(rgba)
+    }
+
+    /// Replaces the green channel of `self`.
+    pub fn with_green(self, green: u8) -> Self {
+        let mut rgba = self.0;
+        rgba[1] = green;
+        Self(rgba)
+    }
+
+    /// Replaces the blue channel of `self`.
+    pub fn with_blue(self, blue: u8) -> Self {
+        let mut rgba = self.0;
+        rgba[2] = blue;
+        Self(rgba)
+    }
+
+    /// Lowers the HSL lightness of `self` by `amount` in `[0.0, 1.0]`. Alpha is kept.
+    pub fn darken(self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Raises the HSL lightness of `self` by `amount` in `[0.0, 1.0]`. Alpha is kept.
+    pub fn lighten(self, amount: f32) -> Self {
+        if !amount.is_finite() { return self; }
+        let (h, s, l) = self.to_hsl();
+        Self::from_hsl(h, s, l + amount).with_alpha(self.a())
+    }
+
+    /// Raises the HSL saturation of `self` by `amount` in `[0.0, 1.0]`. Alpha is kept.
+    pub fn saturate(self, amount: f32) -> Self {
+        if !amount.is_finite() { return self; }
+        let (h, s, l) = self.to_hsl();
+        Self::from_hsl(h, s + amount, l).with_alpha(self.a())
+    }
+
+    /// Lowers the HSL saturation of `self` by `amount` in `[0.0, 1.0]`. Alpha is kept.
+    pub fn desaturate(self, amount: f32) -> Self {
+        self.saturate(-amount)
+    }
+
+    /// Returns the WCAG relative luminance of `self` in `[0.0, 1.0]`. Alpha is ignored.
+    pub fn luminance(self) -> f32 {
+        let lin = |c: u8| {
+            let c = c as f32 / 255.0;
+            if c <= 0.04045 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+        };
+        0.2126 * lin(self.0[0]) + 0.7152 * lin(self.0[1]) + 0.0722 * lin(self.0[2])
+    }
+
+    /// Returns the WCAG contrast ratio between `self` and `other`, from `1.0`
+    /// (identical) to `21.0` (black on white). Text usually wants at least `4.5`.
+    pub fn contrast_ratio(self, other: Color) -> f32 {
+        let (a, b) = (self.luminance(), other.luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Returns [`Color::BLACK`] or [`Color::WHITE`], whichever contrasts more
+    /// with `background`. Either one reaches a ratio of at least `4.58`.
+    ///
+    /// Arguments:
+    /// - background: [`Color`] - color the label is drawn on.
+    pub fn readable_on(background: Color) -> Self {
+        if Color::BLACK.contrast_ratio(background) >= Color::WHITE.contrast_ratio(background) {
+            Color::BLACK
+        } else {
+            Color::WHITE
+        }
+    }
+
+    /// Returns `self` with its HSL lightness moved just far enough for a
+    /// contrast ratio of `min_ratio` against `background`, keeping hue,
+    /// saturation and alpha.
+    ///
+    /// `self` is returned unchanged if it already qualifies. If no lightness
+    /// qualifies, falls back to [`Color::readable_on`] with the alpha of `self`.
+    ///
+    /// Arguments:
+    /// - background: [`Color`] - color the label is drawn on.
+    /// - min_ratio: [f32] - WCAG contrast ratio to reach, e.g. `4.5` for text.
+    pub fn with_contrast_on(self, background: Color, min_ratio: f32) -> Self {
+        if self.contrast_ratio(background) >= min_ratio { return self; }
+
+        let (h, s, l) = self.to_hsl();
+        let at = |l: f32| Self::from_hsl(h, s, l).with_alpha(self.a());
+
+        // search the nearest qualifying lightness towards each extreme
+        let search = |target: f32| {
+            if at(target).contrast_ratio(background) < min_ratio { return None; }
+            let (mut near, mut far) = (l, target);
+            for _ in 0..16 {
+                let mid = (near + far) * 0.5;
+                if at(mid).contrast_ratio(background) >= min_ratio { far = mid; } else { near = mid; }
+            }
+            Some(far)
+        };
+
+        let best = match (search(0.0), search(1.0)) {
+            (Some(a), Some(b)) => Some(if l - a <= b - l { a } else { b }),
+            (a, b) => a.or(b),
+        };
+        match best {
+            Some(l) => at(l),
+            None => Self::readable_on(background).with_alpha(self.a()),
+        }
+    }
+
+    /// Creates an opaque [`Color`] from hue, saturation and lightness.
+    ///
+    /// Arguments:
+    /// - h: [f32] - hue in degrees, wrapped into `[0.0, 360.0)`.
+    /// - s: [f32] - saturation in `[0.0, 1.0]`.
+    /// - l: [f32] - lightness in `[0.0, 1.0]`.
+    pub fn from_hsl(h: f32, s: f32, l: f32) -> Self {
+        let h = if h.is_finite() { h.rem_euclid(360.0) / 60.0 } else { 0.0 };
+        let s = s.clamp(0.0, 1.0);
+        let l = l.clamp(0.0, 1.0);
+
+        let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+        let (r, g, b) = match h as u32 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+        let m = l - c * 0.5;
+        let to_u8 = |v: f32| ((v + m) * 255.0).round().clamp(0.0, 255.0) as u8;
+        Self([to_u8(r), to_u8(g), to_u8(b), 255])
+    }
+
+    /// Returns `(hue, saturation, lightness)` of `self`, with hue in degrees
+    /// `[0.0, 360.0)` and the others in `[0.0, 1.0]`. Alpha is ignored.
+    pub fn to_hsl(self) -> (f32, f32, f32) {
+        let [r, g, b, _] = self.0.map(|c| c as f32 / 255.0);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) * 0.5;
+        let d = max - min;
+        if d == 0.0 { return (0.0, 0.0, l); }
+
+        let s = d / (1.0 - (2.0 * l - 1.0).abs());
+        let h = if max == r {
+            ((g - b) / d).rem_euclid(6.0)
+        } else if max == g {
+            (b - r) / d + 2.0
+        } else {
+            (r - g) / d + 4.0
+        };
+        (h * 60.0, s.min(1.0), l)
+    }
 }
 
 
@@ -50,6 +222,7 @@ impl Color {
 /// Fields:
 /// - fill: Option<[`Fill`]> - if Some(fill), fills object interior with args from [`Fill`].
 /// - stroke: Option<[`Stroke`]> - if Some(stroke), draws object stroke with args from [`Stroke`].
+/// - blend: Option<[`BlendMode`]> - if Some(mode), overrides the [`Stage`](crate::Stage) blend mode.
 ///
 /// Opacity model:
 /// - [`Color`] contains an *intrinsic* alpha channel (RGBA `a`).
@@ -62,6 +235,9 @@ pub struct Style {
     pub fill: Option<Fill>,
     /// If `Some(stroke)`, draws the boundary using [`Stroke`] args.
     pub stroke: Option<Stroke>,
+    /// If `Some(mode)`, overrides the [`Stage`](crate::Stage) blend mode
+    /// while drawing with `self`, see [`Style::with_blend`].
+    pub blend: Option<BlendMode>,
 }
 
 impl Style {
@@ -85,7 +261,7 @@ impl Style {
             Some(color) => Some(Stroke::new(color, Opacity::OPAQUE, 1.0)),
             None        => None,
         };
-        Self { fill: f, stroke: s }
+        Self { fill: f, stroke: s, blend: None }
     }
 
     /// Creates a stroke-only [`Style`] with [`Opacity::OPAQUE`].
@@ -95,7 +271,8 @@ impl Style {
     pub const fn stroke_only(stroke_color: Color) -> Self {
         Self {
             fill: None,
-            stroke: Some(Stroke::new(stroke_color, Opacity::OPAQUE, 1.0))
+            stroke: Some(Stroke::new(stroke_color, Opacity::OPAQUE, 1.0)),
+            blend: None,
         }
     }
 
@@ -106,7 +283,8 @@ impl Style {
     pub const fn fill_only(fill_color: Color) -> Self {
         Self {
             fill: Some(Fill::new(fill_color, Opacity::OPAQUE)),
-            stroke: None
+            stroke: None,
+            blend: None,
         }
     }
 
@@ -132,6 +310,14 @@ impl Style {
         self.fill = Some(Fill::new(fill_color, Opacity::OPAQUE));
     }
 
+    /// Sets the fill of `self` to the given linear or radial gradient.
+    ///
+    /// Arguments:
+    /// - gradient: [`Gradient`]
+    pub fn set_fill_gradient(&mut self, gradient: Gradient) {
+        self.fill = Some(Fill::gradient(gradient, Opacity::OPAQUE));
+    }
+
     /// Sets the fill opacity of `self`. If `self.fill` is `None`, does nothing.
     ///
     /// Arguments:
@@ -164,13 +350,84 @@ impl Style {
             self.stroke = Some(s);
         }
     }
+
+    /// Sets whether the 1px stroke of `self` is anti-aliased. If `self.stroke`
+    /// is `None`, does nothing.
+    ///
+    /// Arguments:
+    /// - antialias: [bool]
+    pub fn set_stroke_antialias(&mut self, antialias: bool) {
+        if let Some(s) = self.stroke {
+            self.stroke = Some(s.with_antialias(antialias));
+        }
+    }
+
+    /// Sets whether the stroke of `self` is a 1 device pixel hairline, see
+    /// [`Stroke::with_hairline`]. If `self.stroke` is `None`, does nothing.
+    ///
+    /// Arguments:
+    /// - hairline: [bool]
+    pub fn set_stroke_hairline(&mut self, hairline: bool) {
+        if let Some(s) = self.stroke {
+            self.stroke = Some(s.with_hairline(hairline));
+        }
+    }
+
+    /// Returns `self` with a hairline stroke set to its 1 pixel width, so
+    /// rasterizers can read the stroke width directly.
+    pub(crate) fn resolve_hairline(mut self) -> Self {
+        if let Some(s) = self.stroke.as_mut().filter(|s| s.hairline) {
+            s.width = 1.0;
+        }
+        self
+    }
+
+    /// Sets the [`LineCap`] of the stroke of `self`. If `self.stroke` is
+    /// `None`, does nothing.
+    ///
+    /// Arguments:
+    /// - cap: [`LineCap`]
+    pub fn set_stroke_cap(&mut self, cap: LineCap) {
+        if let Some(s) = self.stroke {
+            self.stroke = Some(s.with_cap(cap));
+        }
+    }
+
+    /// Dashes the stroke of `self`, see [`Stroke::dash`]. If `self.stroke` is
+    /// `None`, does nothing.
+    ///
+    /// Arguments:
+    /// - pattern: &[[f32]] - on/off lengths.
+    /// - offset: [f32] - distance into the pattern at the start of each subpath.
+    pub fn set_stroke_dash(&mut self, pattern: &[f32], offset: f32) {
+        if let Some(s) = self.stroke {
+            self.stroke = Some(s.dash(pattern, offset));
+        }
+    }
+
+    /// Sets the [`BlendMode`] used when drawing with `self`, in place of the
+    /// [`Stage`](crate::Stage) blend mode.
+    ///
+    /// Arguments:
+    /// - blend: [`BlendMode`]
+    pub fn set_blend(&mut self, blend: BlendMode) {
+        self.blend = Some(blend);
+    }
+
+    /// Returns `self` drawn with `blend` in place of the [`Stage`](crate::Stage)
+    /// blend mode, see [`Style::set_blend`].
+    pub const fn with_blend(mut self, blend: BlendMode) -> Self {
+        self.blend = Some(blend);
+        self
+    }
 }
 
 
 /// Configures opacity for [`Style`] `fill/stroke_opacity` setters.
 ///
-/// Multiplier for RGBA's intrinsic alpha.
-#[derive(Clone, Copy)]
+/// Multiplier for RGBA's intrinsic alpha. Nested opacities compose with `*`.
+/// Defaults to [`Opacity::OPAQUE`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Opacity(u8);
 
 impl Opacity {
@@ -183,17 +440,197 @@ impl Opacity {
         Self(a)
     }
 
+    /// Checked form of [`Opacity::from_f32`] that rejects instead of clamping.
+    ///
+    /// Returns `Err(Error::OpacityOutOfRange)` if `x` is non-finite or outside [0.0, 1.0].
+    pub fn try_from_f32(x: f32) -> Result<Self, Error> {
+        if !x.is_finite() || !(0.0..=1.0).contains(&x) {
+            return Err(Error::OpacityOutOfRange(x));
+        }
+        Ok(Self::from_f32(x))
+    }
+
+    /// Constructs an [`Opacity`] from a percentage in [0, 100] (clamped).
+    pub fn from_percent(percent: u8) -> Self {
+        Self::from_f32(percent.min(100) as f32 / 100.0)
+    }
+
     /// Returns the opacity [`u8`] stored in `self` in [0, 255].
     pub const fn as_u8(self) -> u8 { self.0 }
+
+    /// Returns the opacity of `self` as a float in [0.0, 1.0].
+    pub fn as_f32(self) -> f32 { self.0 as f32 / 255.0 }
 }
 
+impl Default for Opacity {
+    fn default() -> Self {
+        Self::OPAQUE
+    }
+}
+
+impl std::ops::Mul for Opacity {
+    type Output = Opacity;
+
+    /// Composes two opacities, e.g. a layer inside a translucent group.
+    fn mul(self, rhs: Opacity) -> Opacity {
+        Opacity(((self.0 as u16 * rhs.0 as u16 + 127) / 255) as u8)
+    }
+}
+
+impl std::ops::MulAssign for Opacity {
+    fn mul_assign(&mut self, rhs: Opacity) {
+        *self = *self * rhs;
+    }
+}
+
+
+/// Color gradient between two world coords.
+///
+/// Colors are interpolated between stops like [`Colormap`](crate::Colormap),
+/// with `0.0` at `start` and `1.0` at `end`. Beyond either end the nearest
+/// stop color extends, and coincident ends paint the first stop.
+///
+/// A [`Gradient::linear`] varies along the line from `start` to `end`, a
+/// [`Gradient::radial`] with the distance from `start`, reaching `1.0` at
+/// the distance of `end`.
+///
+/// Holds up to [`Gradient::MAX_STOPS`] stops, so it stays `Copy` like [`Style`].
+#[derive(Debug, Clone, Copy)]
+pub struct Gradient {
+    pub(crate) start: (f32, f32),
+    pub(crate) end: (f32, f32),
+    radial: bool,
+    stops: [(f32, Color); Gradient::MAX_STOPS],
+    len: usize,
+}
+
+impl Gradient {
+    /// Maximum number of color stops.
+    pub const MAX_STOPS: usize = 8;
+
+    /// Creates a linear [`Gradient`] from `start` to `end`.
+    ///
+    /// Offsets are clamped to `[0.0, 1.0]` and sorted. Stops with non-finite
+    /// offsets and stops past [`Gradient::MAX_STOPS`] are ignored. Without
+    /// stops the gradient is transparent.
+    ///
+    /// Arguments:
+    /// - start: ([f32], [f32]) - world coord of offset `0.0`.
+    /// - end: ([f32], [f32]) - world coord of offset `1.0`.
+    /// - stops: &[([f32], [`Color`])] - `(offset, color)` pairs.
+    pub fn linear(start: (f32, f32), end: (f32, f32), stops: &[(f32, Color)]) -> Self {
+        let mut g = Self { start, end, radial: false, stops: [(0.0, Color::TRANSPARENT); Self::MAX_STOPS], len: 0 };
+        for &(t, color) in stops.iter().filter(|(t, _)| t.is_finite()).take(Self::MAX_STOPS) {
+            g.stops[g.len] = (t.clamp(0.0, 1.0), color);
+            g.len += 1;
+        }
+        g.stops[..g.len].sort_by(|a, b| a.0.total_cmp(&b.0));
+        g
+    }
+
+    /// Creates a radial [`Gradient`] fading from `center` out to `radius`.
+    ///
+    /// Arguments:
+    /// - center: ([f32], [f32]) - world coord of offset `0.0`.
+    /// - radius: [f32] - world distance of offset `1.0`.
+    /// - stops: &[([f32], [`Color`])] - `(offset, color)` pairs.
+    pub fn radial(center: (f32, f32), radius: f32, stops: &[(f32, Color)]) -> Self {
+        Self { radial: true, ..Self::linear(center, (center.0 + radius, center.1), stops) }
+    }
+
+    /// Returns `true` if `self` was created by [`Gradient::radial`].
+    pub fn is_radial(&self) -> bool {
+        self.radial
+    }
+
+    /// Returns the gradient color at the world coord `p`.
+    pub fn color_at(&self, p: (f32, f32)) -> Color {
+        self.sample(self.offset(self.start, self.end, p))
+    }
+
+    /// Returns the offset of `p` for `self` with its ends moved to `a` and
+    /// `b`, e.g. in pixel coords.
+    #[inline(always)]
+    pub(crate) fn offset(&self, a: (f32, f32), b: (f32, f32), p: (f32, f32)) -> f32 {
+        if self.radial { Self::radial_offset(a, b, p) } else { Self::linear_offset(a, b, p) }
+    }
+
+    /// Returns the gradient color at offset `t`. Non-finite `t` is transparent.
+    pub fn sample(&self, t: f32) -> Color {
+        let stops = &self.stops[..self.len];
+        let Some(&(first, c)) = stops.first() else { return Color::TRANSPARENT; };
+        if !t.is_finite() { return Color::TRANSPARENT; }
+        if t <= first { return c; }
+
+        for w in stops.windows(2) {
+            let ((t0, c0), (t1, c1)) = (w[0], w[1]);
+            if t <= t1 {
+                let f = if t1 > t0 { (t - t0) / (t1 - t0) } else { 1.0 };
+                let (a, b) = (c0.rgba(), c1.rgba());
+                let mix = |i: usize| (a[i] as f32 + (b[i] as f32 - a[i] as f32) * f).round() as u8;
+                return Color::new([mix(0), mix(1), mix(2), mix(3)]);
+            }
+        }
+        stops[self.len - 1].1
+    }
+
+    /// Returns the offset of `p` projected onto the line from `a` to `b`.
+    #[inline(always)]
+    fn linear_offset(a: (f32, f32), b: (f32, f32), p: (f32, f32)) -> f32 {
+        let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+        let len2 = dx * dx + dy * dy;
+        if len2 > 0.0 { ((p.0 - a.0) * dx + (p.1 - a.1) * dy) / len2 } else { 0.0 }
+    }
+
+    /// Returns the distance of `p` from `a` in units of the distance to `b`.
+    #[inline(always)]
+    fn radial_offset(a: (f32, f32), b: (f32, f32), p: (f32, f32)) -> f32 {
+        let r = (b.0 - a.0).hypot(b.1 - a.1);
+        if r > 0.0 { (p.0 - a.0).hypot(p.1 - a.1) / r } else { 0.0 }
+    }
+
+    /// Returns `self` with both ends scaled by `k` about the world origin.
+    pub(crate) fn scaled(mut self, k: f32) -> Self {
+        self.start = (self.start.0 * k, self.start.1 * k);
+        self.end = (self.end.0 * k, self.end.1 * k);
+        self
+    }
+}
+
+/// Source of the colors a [`Fill`] paints.
+#[derive(Debug, Clone, Copy)]
+pub enum Paint {
+    /// One color everywhere.
+    Solid(Color),
+    /// Colors of a linear or radial [`Gradient`], evaluated per pixel.
+    Gradient(Gradient),
+}
+
+impl Paint {
+    /// Returns the paint color at the world coord `p`.
+    pub fn color_at(self, p: (f32, f32)) -> Color {
+        match self {
+            Paint::Solid(color) => color,
+            Paint::Gradient(g) => g.color_at(p),
+        }
+    }
+
+    /// Returns the [`Gradient`] of `self`, if any.
+    pub(crate) fn gradient_mut(&mut self) -> Option<&mut Gradient> {
+        match self {
+            Paint::Solid(_) => None,
+            Paint::Gradient(g) => Some(g),
+        }
+    }
+}
 
 /// Configures fill options for a given shape.
 ///
-/// Can be constructed with given [`Color`] and [`Opacity`] using `Fill::new(..)`.
+/// Can be constructed with given [`Color`] and [`Opacity`] using `Fill::new(..)`,
+/// or with a linear or radial [`Gradient`] using `Fill::gradient(..)`.
 #[derive(Clone, Copy)]
 pub struct Fill {
-    pub(crate) color: Color,
+    pub(crate) paint: Paint,
     pub(crate) opacity: Opacity,
 }
 
@@ -205,6 +642,90 @@ pub struct Stroke {
     pub(crate) color: Color,
     pub(crate) opacity: Opacity,
     pub(crate) width: f32, 
+    pub(crate) antialias: bool,
+    pub(crate) dash: Dash,
+    pub(crate) cap: LineCap,
+    pub(crate) hairline: bool,
+}
+
+/// Shape of the ends of open strokes thicker than 1 pixel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineCap {
+    /// Ends flush with the endpoints.
+    Butt,
+    /// Ends in a half disc around the endpoints.
+    Round,
+    /// Ends extended past the endpoints by half the stroke width.
+    #[default]
+    Square,
+}
+
+/// Shape of the corners of a stroke outline built by
+/// [`Path::stroke_outline`](crate::Path::stroke_outline). Rasterized strokes
+/// always join round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineJoin {
+    /// Corners cut straight across between the two segment edges.
+    Bevel,
+    /// Corners extended to a point, beveled past 4 half widths like SVG.
+    Miter,
+    /// Corners rounded by an arc around the node.
+    #[default]
+    Round,
+}
+
+/// On/off pattern of a dashed [`Stroke`], in world units.
+///
+/// Holds up to [`Dash::MAX_LENGTHS`] lengths so [`Stroke`] stays `Copy`.
+/// An odd count is repeated once to make it even, like SVG.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Dash {
+    lengths: [f32; Dash::MAX_LENGTHS],
+    len: usize,
+    offset: f32,
+}
+
+impl Dash {
+    pub(crate) const MAX_LENGTHS: usize = 8;
+
+    /// A solid stroke.
+    pub(crate) const NONE: Self = Self { lengths: [0.0; Self::MAX_LENGTHS], len: 0, offset: 0.0 };
+
+    /// Returns the dash of `pattern` starting `offset` units into it, or
+    /// [`Dash::NONE`] if `pattern` is empty, too long, has negative or
+    /// non-finite lengths, or sums to zero.
+    fn new(pattern: &[f32], offset: f32) -> Self {
+        let valid = pattern.iter().all(|l| l.is_finite() && *l >= 0.0);
+        let n = if pattern.len() % 2 == 1 { 2 * pattern.len() } else { pattern.len() };
+        if !valid || n == 0 || n > Self::MAX_LENGTHS || pattern.iter().sum::<f32>() <= 0.0 {
+            return Self::NONE;
+        }
+
+        let mut dash = Self { len: n, offset: if offset.is_finite() { offset } else { 0.0 }, ..Self::NONE };
+        for i in 0..n {
+            dash.lengths[i] = pattern[i % pattern.len()];
+        }
+        dash
+    }
+
+    /// Returns `true` if the stroke is dashed.
+    pub(crate) fn is_dashed(&self) -> bool {
+        self.len > 0
+    }
+
+    /// Returns `(lengths, offset)` with the offset wrapped into one period.
+    pub(crate) fn pattern(&self) -> (&[f32], f32) {
+        let lengths = &self.lengths[..self.len];
+        let period: f32 = lengths.iter().sum();
+        (lengths, self.offset.rem_euclid(period))
+    }
+
+    /// Returns `self` with all lengths scaled by `k`.
+    pub(crate) fn scaled(mut self, k: f32) -> Self {
+        self.lengths.iter_mut().for_each(|l| *l *= k);
+        self.offset *= k;
+        self
+    }
 }
 
 impl Fill {
@@ -214,15 +735,46 @@ impl Fill {
     /// - color: [`Color`]: fill color.
     /// - opacity: [`Opacity`]: fill opacity.
     pub const fn new(color: Color, opacity: Opacity) -> Self {
-        Self { color, opacity }
+        Self { paint: Paint::Solid(color), opacity }
+    }
+
+    /// Creates a [`Fill`] painting `gradient` with the given opacity.
+    ///
+    /// Arguments:
+    /// - gradient: [`Gradient`]: fill colors, from [`Gradient::linear`] or [`Gradient::radial`].
+    /// - opacity: [`Opacity`]: fill opacity.
+    pub const fn gradient(gradient: Gradient, opacity: Opacity) -> Self {
+        Self { paint: Paint::Gradient(gradient), opacity }
+    }
+
+    /// Returns the [`Paint`] of `self`.
+    pub fn paint(self) -> Paint {
+        self.paint
     }
 
     /// Returns the effective [`Color`] of a [`Fill`]. The opacity
     /// uses both the intrinsic RGB**A** from provided [`Color`] and the
     /// extrinsic [`Opacity`].
+    ///
+    /// For a gradient this is its color halfway along, for swatches and
+    /// shapes that are painted in one color. See [`Fill::rgba_at`].
     #[inline(always)]
     pub fn rgba(self) -> Color {
-        let mut rgba = self.color.rgba();
+        match self.paint {
+            Paint::Solid(color) => self.with_opacity(color),
+            Paint::Gradient(g) => self.with_opacity(g.sample(0.5)),
+        }
+    }
+
+    /// Returns the effective [`Color`] of `self` at the world coord `p`.
+    pub fn rgba_at(self, p: (f32, f32)) -> Color {
+        self.with_opacity(self.paint.color_at(p))
+    }
+
+    /// Multiplies the intrinsic alpha of `color` by the opacity of `self`.
+    #[inline(always)]
+    pub(crate) fn with_opacity(self, color: Color) -> Color {
+        let mut rgba = color.rgba();
         let a = rgba[3] as u16;
         let f = self.opacity.as_u8() as u16;
 
@@ -239,7 +791,56 @@ impl Stroke {
     /// - opacity: [`Opacity`]: fill opacity.
     /// - width: [f32]: stroke width
     pub const fn new(color: Color, opacity: Opacity, width: f32) -> Self {
-        Self { color, opacity, width }
+        Self { color, opacity, width, antialias: false, dash: Dash::NONE, cap: LineCap::Square, hairline: false }
+    }
+
+    /// Returns `self` drawn exactly 1 device pixel wide if `hairline`, e.g.
+    /// for grid lines and axes. The width is then ignored and not scaled by
+    /// [`Scene`](crate::Scene) zoom, and the stroke stays crisp on supersampled
+    /// stages and [`Quality::Smooth`] draws unless anti-aliased with
+    /// [`Stroke::with_antialias`].
+    pub const fn with_hairline(mut self, hairline: bool) -> Self {
+        self.hairline = hairline;
+        self
+    }
+
+    /// Returns `self` with ends of open paths, and of each dash, shaped by `cap`.
+    pub const fn with_cap(mut self, cap: LineCap) -> Self {
+        self.cap = cap;
+        self
+    }
+
+    /// Returns `self` dashed with `pattern`, alternating on and off lengths
+    /// in world units starting with on, shifted `offset` units along the path.
+    ///
+    /// An odd `pattern` is repeated once, like SVG. Patterns that are empty,
+    /// longer than 8 after that, have negative or non-finite lengths, or sum
+    /// to zero draw a solid stroke.
+    ///
+    /// Arguments:
+    /// - pattern: &[[f32]] - on/off lengths.
+    /// - offset: [f32] - distance into the pattern at the start of each subpath.
+    pub fn dash(mut self, pattern: &[f32], offset: f32) -> Self {
+        self.dash = Dash::new(pattern, offset);
+        self
+    }
+
+    /// Returns `self` drawing anti-aliased 1px lines if `antialias`, e.g. for
+    /// data curves, regardless of [`Stage::set_line_antialias`](crate::Stage::set_line_antialias).
+    /// Thicker strokes are unaffected.
+    pub const fn with_antialias(mut self, antialias: bool) -> Self {
+        self.antialias = antialias;
+        self
+    }
+
+    /// Checked form of [`Stroke::new`].
+    ///
+    /// Returns `Err(Error::InvalidStrokeWidth)` if `width` is non-finite or not strictly positive.
+    pub fn try_new(color: Color, opacity: Opacity, width: f32) -> Result<Self, Error> {
+        if !width.is_finite() || width <= 0.0 {
+            return Err(Error::InvalidStrokeWidth(width));
+        }
+        Ok(Self::new(color, opacity, width))
     }
 
     /// Returns the effective [`Color`] of a [`Stroke`]. The opacity
@@ -256,3 +857,30 @@ impl Stroke {
     }
 }
 
+
+/// Edge quality of a single draw call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Quality {
+    /// Hard, aliased edges. Pixels are either fully written or untouched,
+    /// which keeps hairlines and grid lines crisp.
+    #[default]
+    Crisp,
+    /// Anti-aliased edges from 4x4 supersampled coverage, or the factor of
+    /// [`Stage::new_with_path_aa`](crate::Stage::new_with_path_aa) if higher. Edge pixels are
+    /// composited source-over with their partial coverage.
+    Smooth,
+}
+
+/// Per-draw rendering overrides, passed to [`Path::render_with`](crate::Path::render_with).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RenderOptions {
+    /// Edge quality for this draw call.
+    pub quality: Quality,
+}
+
+impl RenderOptions {
+    /// [`RenderOptions`] with [`Quality::Smooth`] edges.
+    pub const SMOOTH: Self = Self { quality: Quality::Smooth };
+    /// [`RenderOptions`] with [`Quality::Crisp`] edges.
+    pub const CRISP: Self = Self { quality: Quality::Crisp };
+}
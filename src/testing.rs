@@ -0,0 +1,144 @@
+//! Golden-image comparison for tests.
+//!
+//! [`assert_stage_matches!`](crate::assert_stage_matches) compares a [`Stage`] against a
+//! reference `png`. On mismatch it writes `<name>.actual.png` and a red
+//! `<name>.diff.png` heatmap next to the reference, then panics with stats.
+//!
+//! Set the `WAVE_BLESS` environment variable to (re)write references instead.
+
+use std::fmt;
+use std::path::{Path as FsPath, PathBuf};
+
+use crate::Stage;
+
+/// Env var that makes comparisons overwrite the reference with the actual render.
+pub const BLESS_ENV: &str = "WAVE_BLESS";
+
+/// Result of a failed golden-image comparison.
+#[derive(Debug)]
+pub struct Mismatch {
+    /// Pixels whose largest channel difference exceeds the tolerance.
+    pub differing_pixels: usize,
+    /// Total pixels compared.
+    pub total_pixels: usize,
+    /// Largest channel difference seen.
+    pub max_difference: u8,
+    /// Reason the comparison could not be performed, if any.
+    pub error: Option<String>,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(e) = &self.error {
+            return write!(f, "{e}");
+        }
+
+        let pct = 100.0 * self.differing_pixels as f64 / self.total_pixels.max(1) as f64;
+        write!(
+            f,
+            "{} of {} pixels differ ({:.3}%), max channel difference {}",
+            self.differing_pixels, self.total_pixels, pct, self.max_difference,
+        )
+    }
+}
+
+/// Compares `stage` with the reference `png` at `reference`.
+///
+/// Arguments:
+/// - stage: &[`Stage`] - rendered output.
+/// - reference: path to the reference `png`.
+/// - tolerance: [u8] - largest allowed per-channel difference.
+///
+/// On mismatch, writes failure artifacts next to `reference`.
+pub fn compare<P: AsRef<FsPath>>(stage: &Stage, reference: P, tolerance: u8) -> Result<(), Mismatch> {
+    let reference = reference.as_ref();
+    let fail = |e: String| Mismatch {
+        differing_pixels: 0,
+        total_pixels: stage.len(),
+        max_difference: 0,
+        error: Some(e),
+    };
+
+    if std::env::var_os(BLESS_ENV).is_some() {
+        if let Some(dir) = reference.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| fail(e.to_string()))?;
+        }
+        return stage.save_png(reference).map_err(|e| fail(e.to_string()));
+    }
+
+    let expected = match image::open(reference) {
+        Ok(img) => img.to_rgba8(),
+        Err(e) => {
+            let _ = stage.save_png(artifact_path(reference, "actual"));
+            return Err(fail(format!("could not load reference {}: {e}", reference.display())));
+        }
+    };
+
+    let (w, h) = stage.dimensions();
+    if (expected.width() as usize, expected.height() as usize) != (w, h) {
+        let _ = stage.save_png(artifact_path(reference, "actual"));
+        return Err(fail(format!(
+            "size mismatch: actual {}x{}, reference {}x{}",
+            w, h, expected.width(), expected.height(),
+        )));
+    }
+
+    let mut diff = Stage::new(w, h);
+    let mut differing_pixels = 0;
+    let mut max_difference = 0u8;
+
+    for y in 0..h {
+        for (x, actual) in stage.row(y).iter().enumerate() {
+            let expected = expected.get_pixel(x as u32, y as u32).0;
+            let d = (0..4).map(|c| actual[c].abs_diff(expected[c])).max().unwrap_or(0);
+            max_difference = max_difference.max(d);
+
+            if d > tolerance {
+                differing_pixels += 1;
+                diff.row_mut(y)[x] = [255, 0, 0, d.max(64)];
+            }
+        }
+    }
+
+    if differing_pixels == 0 { return Ok(()); }
+
+    let _ = stage.save_png(artifact_path(reference, "actual"));
+    let _ = diff.save_png(artifact_path(reference, "diff"));
+
+    Err(Mismatch { differing_pixels, total_pixels: stage.len(), max_difference, error: None })
+}
+
+/// Panicking form of [`compare`], used by [`assert_stage_matches!`](crate::assert_stage_matches).
+#[track_caller]
+pub fn assert_matches<P: AsRef<FsPath>>(stage: &Stage, reference: P, tolerance: u8) {
+    let reference = reference.as_ref();
+    if let Err(m) = compare(stage, reference, tolerance) {
+        panic!(
+            "stage does not match {}: {m}\n  actual: {}\n  diff:   {}",
+            reference.display(),
+            artifact_path(reference, "actual").display(),
+            artifact_path(reference, "diff").display(),
+        );
+    }
+}
+
+/// Returns `dir/name.<suffix>.png` for a reference at `dir/name.png`.
+fn artifact_path(reference: &FsPath, suffix: &str) -> PathBuf {
+    let stem = reference.file_stem().and_then(|s| s.to_str()).unwrap_or("stage");
+    reference.with_file_name(format!("{stem}.{suffix}.png"))
+}
+
+/// Asserts a [`Stage`] matches a reference `png` within a per-channel tolerance.
+///
+/// ```ignore
+/// assert_stage_matches!(stage, "fixtures/circle.png", 2);
+/// ```
+#[macro_export]
+macro_rules! assert_stage_matches {
+    ($stage:expr, $reference:expr, $tolerance:expr $(,)?) => {
+        $crate::testing::assert_matches(&$stage, $reference, $tolerance)
+    };
+    ($stage:expr, $reference:expr $(,)?) => {
+        $crate::testing::assert_matches(&$stage, $reference, 0)
+    };
+}
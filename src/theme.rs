@@ -0,0 +1,126 @@
+//! Themes mapping semantic roles to [`Style`]s.
+
+use crate::{Color, Stage, Style};
+
+/// Colors for the semantic roles of a chart or demo, so a whole scene can be
+/// restyled by swapping the [`Theme`].
+///
+/// Fields:
+/// - background: [`Color`] - canvas color.
+/// - foreground: [`Color`] - axes, outlines and labels.
+/// - accent: [`Color`] - highlights.
+/// - series: Vec<[`Color`]> - data series colors, cycled by index.
+/// - stroke_width: [f32] - line width used by the stroke styles.
+#[derive(Debug, Clone)]
+pub struct Theme {
+    pub background: Color,
+    pub foreground: Color,
+    pub accent: Color,
+    pub series: Vec<Color>,
+    pub stroke_width: f32,
+}
+
+impl Theme {
+    /// Dark lines on a white background.
+    pub fn light() -> Self {
+        Self {
+            background: Color::WHITE,
+            foreground: Color::new([34, 34, 34, 255]),
+            accent: Color::new([230, 85, 13, 255]),
+            series: vec![
+                Color::new([31, 119, 180, 255]),
+                Color::new([255, 127, 14, 255]),
+                Color::new([44, 160, 44, 255]),
+                Color::new([214, 39, 40, 255]),
+                Color::new([148, 103, 189, 255]),
+                Color::new([140, 86, 75, 255]),
+            ],
+            stroke_width: 1.0,
+        }
+    }
+
+    /// Light lines on a near-black background.
+    pub fn dark() -> Self {
+        Self {
+            background: Color::new([24, 24, 28, 255]),
+            foreground: Color::new([220, 220, 220, 255]),
+            accent: Color::new([255, 196, 0, 255]),
+            series: vec![
+                Color::new([102, 194, 255, 255]),
+                Color::new([255, 153, 102, 255]),
+                Color::new([128, 222, 128, 255]),
+                Color::new([255, 110, 130, 255]),
+                Color::new([200, 160, 255, 255]),
+                Color::new([240, 220, 120, 255]),
+            ],
+            stroke_width: 1.0,
+        }
+    }
+
+    /// Maximum contrast black and white with saturated, thick series lines.
+    pub fn high_contrast() -> Self {
+        Self {
+            background: Color::BLACK,
+            foreground: Color::WHITE,
+            accent: Color::new([255, 255, 0, 255]),
+            series: vec![
+                Color::new([0, 255, 255, 255]),
+                Color::new([255, 255, 0, 255]),
+                Color::new([255, 0, 255, 255]),
+                Color::new([0, 255, 0, 255]),
+            ],
+            stroke_width: 2.0,
+        }
+    }
+
+    /// Fills `stage` with the background color.
+    pub fn apply_background(&self, stage: &mut Stage) {
+        stage.clear(self.background);
+    }
+
+    /// Returns the color of series `index`, cycling through `series`. Falls back
+    /// to `foreground` if `series` is empty.
+    pub fn series_color(&self, index: usize) -> Color {
+        if self.series.is_empty() { return self.foreground; }
+        self.series[index % self.series.len()]
+    }
+
+    /// Fill-only background [`Style`].
+    pub fn background_style(&self) -> Style {
+        Style::fill_only(self.background)
+    }
+
+    /// Stroke-only foreground [`Style`] with `stroke_width`.
+    pub fn foreground_style(&self) -> Style {
+        self.stroke(self.foreground)
+    }
+
+    /// Stroke-only accent [`Style`] with `stroke_width`.
+    pub fn accent_style(&self) -> Style {
+        self.stroke(self.accent)
+    }
+
+    /// Stroke-only [`Style`] for series `index`, e.g. for line plots.
+    pub fn series_style(&self, index: usize) -> Style {
+        self.stroke(self.series_color(index))
+    }
+
+    /// Fill [`Style`] for series `index` outlined in `foreground`, e.g. for bars.
+    pub fn series_fill_style(&self, index: usize) -> Style {
+        let mut style = Style::new(Some(self.series_color(index)), Some(self.foreground));
+        style.set_stroke_width(self.stroke_width);
+        style
+    }
+
+    fn stroke(&self, color: Color) -> Style {
+        let mut style = Style::stroke_only(color);
+        style.set_stroke_width(self.stroke_width);
+        style
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::light()
+    }
+}
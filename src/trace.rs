@@ -0,0 +1,44 @@
+//! Vector outline recovery from rasterized [`Stage`] content.
+
+use crate::{Path, Stage};
+use crate::plot::contour::{march, chain_segments};
+
+impl Stage {
+    /// Traces the outlines of every region whose alpha is at least `threshold`
+    /// using marching squares over the alpha channel.
+    ///
+    /// Arguments:
+    /// - threshold: [u8] - minimum alpha considered inside.
+    ///
+    /// Returns:
+    /// One closed [`Path`] per traced contour in world coords. Holes are
+    /// returned as separate contours.
+    pub fn trace_outline(&self, threshold: u8) -> Vec<Path> {
+        let (w, h) = self.dimensions();
+
+        // pad by one transparent pixel so every contour closes
+        let (cols, rows) = (w + 2, h + 2);
+        let mut field = vec![0.0f32; cols * rows];
+        for y in 0..h {
+            for (x, px) in self.row(y).iter().enumerate() {
+                field[(y + 1) * cols + (x + 1)] = px[3] as f32;
+            }
+        }
+
+        let level = (threshold as f32).max(0.5);
+        let mut segments = Vec::new();
+        march(&field, (cols, rows), level, &mut segments);
+
+        chain_segments(&segments)
+            .into_iter()
+            .filter(|(nodes, _)| nodes.len() >= 3)
+            .map(|(nodes, closed)| {
+                let nodes = nodes
+                    .into_iter()
+                    .map(|(gx, gy)| self.pxl_to_world_f32((gx - 1.0, gy - 1.0)))
+                    .collect();
+                Path::new(nodes, closed)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,93 @@
+//! Fading, tapering trails following a moving point.
+
+use std::collections::VecDeque;
+
+use crate::{BlendMode, Color, LineCap, Opacity, Path, Stage, Stroke, Style};
+
+/// The last `capacity` positions of a moving point, rendered as a stroke that
+/// fades and thins from the newest position back to the oldest.
+///
+/// Push one position per frame and render the trail each frame.
+#[derive(Debug, Clone)]
+pub struct Trail {
+    points: VecDeque<(f32, f32)>,
+    capacity: usize,
+}
+
+impl Trail {
+    /// Creates an empty [`Trail`] remembering up to `capacity` positions.
+    /// A zero `capacity` is raised to two so a segment can form.
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(2);
+        Self { points: VecDeque::with_capacity(capacity), capacity }
+    }
+
+    /// Appends the newest position `pos` in world coords, dropping the oldest
+    /// once `capacity` is reached. Non-finite positions are ignored.
+    pub fn push(&mut self, pos: (f32, f32)) {
+        if !pos.0.is_finite() || !pos.1.is_finite() { return; }
+        if self.points.len() == self.capacity {
+            self.points.pop_front();
+        }
+        self.points.push_back(pos);
+    }
+
+    /// Forgets every position.
+    pub fn clear(&mut self) {
+        self.points.clear();
+    }
+
+    /// Returns the number of remembered positions.
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Returns `true` if no positions are remembered.
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Returns the newest position, if any.
+    pub fn head(&self) -> Option<(f32, f32)> {
+        self.points.back().copied()
+    }
+
+    /// Returns the remembered positions from oldest to newest.
+    pub fn points(&self) -> impl Iterator<Item = (f32, f32)> + '_ {
+        self.points.iter().copied()
+    }
+
+    /// Draws `self` as a stroke of `color` that is `width` wide and fully
+    /// opaque at the head, tapering linearly in both width and opacity toward
+    /// the tail.
+    ///
+    /// Segments are drawn with round caps onto a temporary layer that is
+    /// composited once, so translucent segments don't darken where they meet
+    /// and thick trails stay joined.
+    ///
+    /// Arguments:
+    /// - stage: &mut [`Stage`] - stage to draw onto.
+    /// - color: [`Color`] - stroke color at the head.
+    /// - width: [f32] - stroke width at the head in pixels.
+    pub fn render(&self, stage: &mut Stage, color: Color, width: f32) {
+        if !width.is_finite() || width <= 0.0 { return; }
+
+        let n = self.points.len();
+        if n < 2 { return; }
+
+        stage.group(Opacity::OPAQUE, |layer| {
+            // oldest first; newer segments are wider and more opaque, so
+            // overwriting keeps the strongest coverage of each pixel
+            for i in 1..n {
+                let t = i as f32 / (n - 1) as f32;
+                let stroke = Stroke::new(color, Opacity::from_f32(t), (width * t).max(1.0))
+                    .with_cap(LineCap::Round);
+                let style = Style { fill: None, stroke: Some(stroke), blend: None }
+                    .with_blend(BlendMode::Overwrite);
+
+                let segment = Vec::from([self.points[i - 1], self.points[i]]);
+                Path::new(segment, false).render(layer, style);
+            }
+        });
+    }
+}
@@ -0,0 +1,131 @@
+//! 2D projective transforms.
+
+/// A 3x3 projective transform acting on points `(x, y)` as `M * [x, y, 1]`.
+///
+/// Affine transforms (translate, scale, rotate, skew) keep the last row
+/// `[0, 0, 1]`; [`Transform::quad_to_quad`] builds full perspective maps.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform {
+    m: [[f32; 3]; 3],
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Transform {
+    /// Leaves every point in place.
+    pub const IDENTITY: Transform = Transform { m: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]] };
+
+    /// Creates a [`Transform`] from a row major 3x3 matrix.
+    pub fn from_matrix(m: [[f32; 3]; 3]) -> Self {
+        Self { m }
+    }
+
+    /// Creates the affine map `(x, y) -> (a x + b y + tx, c x + d y + ty)`.
+    pub fn affine(a: f32, b: f32, c: f32, d: f32, tx: f32, ty: f32) -> Self {
+        Self { m: [[a, b, tx], [c, d, ty], [0.0, 0.0, 1.0]] }
+    }
+
+    /// Translates by `(tx, ty)`.
+    pub fn translate(tx: f32, ty: f32) -> Self {
+        Self::affine(1.0, 0.0, 0.0, 1.0, tx, ty)
+    }
+
+    /// Scales by `(sx, sy)` about the origin.
+    pub fn scale(sx: f32, sy: f32) -> Self {
+        Self::affine(sx, 0.0, 0.0, sy, 0.0, 0.0)
+    }
+
+    /// Rotates by `degrees` about the origin, counter-clockwise when `+y` is up.
+    pub fn rotate(degrees: f32) -> Self {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        Self::affine(cos, -sin, sin, cos, 0.0, 0.0)
+    }
+
+    /// Shears `x` by `kx * y` and `y` by `ky * x`.
+    pub fn skew(kx: f32, ky: f32) -> Self {
+        Self::affine(1.0, kx, ky, 1.0, 0.0, 0.0)
+    }
+
+    /// Returns the matrix of `self`, row major.
+    pub fn matrix(&self) -> [[f32; 3]; 3] {
+        self.m
+    }
+
+    /// Returns the transform applying `self` first and then `next`.
+    pub fn then(&self, next: &Transform) -> Transform {
+        let (a, b) = (&next.m, &self.m);
+        let m = std::array::from_fn(|r| std::array::from_fn(|c| (0..3).map(|k| a[r][k] * b[k][c]).sum()));
+        Transform { m }
+    }
+
+    /// Returns the inverse of `self`, or `None` if it is singular.
+    pub fn inverse(&self) -> Option<Transform> {
+        let m = &self.m;
+        let cof = |r0: usize, r1: usize, c0: usize, c1: usize| m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0];
+
+        let det = m[0][0] * cof(1, 2, 1, 2) - m[0][1] * cof(1, 2, 0, 2) + m[0][2] * cof(1, 2, 0, 1);
+        if !det.is_finite() || det.abs() < 1e-12 { return None; }
+
+        let inv = [
+            [cof(1, 2, 1, 2), -cof(0, 2, 1, 2), cof(0, 1, 1, 2)],
+            [-cof(1, 2, 0, 2), cof(0, 2, 0, 2), -cof(0, 1, 0, 2)],
+            [cof(1, 2, 0, 1), -cof(0, 2, 0, 1), cof(0, 1, 0, 1)],
+        ];
+        Some(Transform { m: inv.map(|row| row.map(|v| v / det)) })
+    }
+
+    /// Maps `(x, y)` through `self`. Returns `None` for points sent to infinity
+    /// or behind the projection.
+    #[inline(always)]
+    pub fn apply(&self, (x, y): (f32, f32)) -> Option<(f32, f32)> {
+        let m = &self.m;
+        let w = m[2][0] * x + m[2][1] * y + m[2][2];
+        if w <= 1e-12 || !w.is_finite() { return None; }
+
+        Some((
+            (m[0][0] * x + m[0][1] * y + m[0][2]) / w,
+            (m[1][0] * x + m[1][1] * y + m[1][2]) / w,
+        ))
+    }
+
+    /// Returns the projective transform mapping the unit square corners
+    /// `(0, 0), (1, 0), (1, 1), (0, 1)` onto `quad`, in that order.
+    ///
+    /// Returns `None` if three of the corners are collinear.
+    pub fn square_to_quad(quad: [(f32, f32); 4]) -> Option<Transform> {
+        let [(x0, y0), (x1, y1), (x2, y2), (x3, y3)] = quad;
+
+        let sx = x0 - x1 + x2 - x3;
+        let sy = y0 - y1 + y2 - y3;
+
+        let (g, h) = if sx.abs() < 1e-12 && sy.abs() < 1e-12 {
+            (0.0, 0.0)
+        } else {
+            let (dx1, dx2) = (x1 - x2, x3 - x2);
+            let (dy1, dy2) = (y1 - y2, y3 - y2);
+            let den = dx1 * dy2 - dx2 * dy1;
+            if den.abs() < 1e-12 { return None; }
+            ((sx * dy2 - dx2 * sy) / den, (dx1 * sy - sx * dy1) / den)
+        };
+
+        let m = [
+            [x1 - x0 + g * x1, x3 - x0 + h * x3, x0],
+            [y1 - y0 + g * y1, y3 - y0 + h * y3, y0],
+            [g, h, 1.0],
+        ];
+        let t = Transform { m };
+        t.inverse().map(|_| t)
+    }
+
+    /// Returns the projective transform mapping the corners of `from` onto
+    /// the corners of `to`, in order. Returns `None` for degenerate quads.
+    pub fn quad_to_quad(from: [(f32, f32); 4], to: [(f32, f32); 4]) -> Option<Transform> {
+        let a = Self::square_to_quad(from)?.inverse()?;
+        let b = Self::square_to_quad(to)?;
+        Some(a.then(&b))
+    }
+}
@@ -0,0 +1,36 @@
+//! [`ratatui`](https://ratatui.rs) widget drawing a [`Stage`] into a
+//! terminal buffer. Requires the `ratatui` feature.
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::style::Color;
+use ratatui_core::widgets::Widget;
+
+use crate::Stage;
+use crate::preview::HALF_BLOCK;
+
+/// Draws the [`Stage`] stretched over `area` with truecolor half-block
+/// cells, two pixel rows per cell, composited over black.
+///
+/// Size the [`Stage`] to `area.width` x `2 * area.height` pixels to skip
+/// resampling, e.g. when redrawing a sparkline every frame.
+///
+/// ```ignore
+/// frame.render_widget(&stage, area);
+/// ```
+impl Widget for &Stage {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        let (cols, rows) = (area.width as usize, 2 * area.height as usize);
+
+        self.half_blocks(cols, rows, |col, line, top, bottom| {
+            let bottom = bottom.unwrap_or((0, 0, 0));
+            let position = (area.x + col as u16, area.y + line as u16);
+            if let Some(cell) = buf.cell_mut(position) {
+                cell.set_char(HALF_BLOCK)
+                    .set_fg(Color::Rgb(top.0, top.1, top.2))
+                    .set_bg(Color::Rgb(bottom.0, bottom.1, bottom.2));
+            }
+        });
+    }
+}
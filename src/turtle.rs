@@ -0,0 +1,219 @@
+//! Turtle graphics recording into [`Path`]s.
+
+use crate::{Path, Stage, Style};
+
+/// Position, heading and pen of a [`Turtle`], saved by [`Turtle::push`].
+#[derive(Debug, Clone, Copy)]
+struct TurtleState {
+    pos: (f32, f32),
+    heading: f32,
+    pen_down: bool,
+}
+
+/// A turtle that walks the world plane and records its trail as [`Path`]s.
+///
+/// Each pen-down stretch of movement becomes one open [`Path`]. Headings are
+/// in degrees, counter-clockwise from `+x`.
+///
+/// ```ignore
+/// let mut t = Turtle::new();
+/// for _ in 0..4 {
+///     t.forward(100.0);
+///     t.left(90.0);
+/// }
+/// t.render(&mut stage, Style::stroke_only(Color::BLACK));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Turtle {
+    state: TurtleState,
+    stack: Vec<TurtleState>,
+    current: Vec<(f32, f32)>,
+    trails: Vec<Vec<(f32, f32)>>,
+}
+
+impl Default for Turtle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Turtle {
+    /// Creates a [`Turtle`] at the world origin facing `+x` with the pen down.
+    pub fn new() -> Self {
+        Self::at((0.0, 0.0), 0.0)
+    }
+
+    /// Creates a [`Turtle`] at `pos` facing `heading` degrees with the pen down.
+    pub fn at(pos: (f32, f32), heading: f32) -> Self {
+        Self {
+            state: TurtleState { pos, heading, pen_down: true },
+            stack: Vec::new(),
+            current: Vec::new(),
+            trails: Vec::new(),
+        }
+    }
+
+    /// Returns the current world coord position.
+    pub fn position(&self) -> (f32, f32) {
+        self.state.pos
+    }
+
+    /// Returns the current heading in degrees.
+    pub fn heading(&self) -> f32 {
+        self.state.heading
+    }
+
+    /// Returns `true` if moving draws.
+    pub fn is_pen_down(&self) -> bool {
+        self.state.pen_down
+    }
+
+    /// Moves `distance` world units along the heading, drawing if the pen is down.
+    pub fn forward(&mut self, distance: f32) -> &mut Self {
+        let (sin, cos) = self.state.heading.to_radians().sin_cos();
+        let (x, y) = self.state.pos;
+        self.move_to((x + distance * cos, y + distance * sin))
+    }
+
+    /// Moves `distance` world units against the heading.
+    pub fn back(&mut self, distance: f32) -> &mut Self {
+        self.forward(-distance)
+    }
+
+    /// Moves straight to `pos` without changing the heading.
+    pub fn move_to(&mut self, pos: (f32, f32)) -> &mut Self {
+        if self.state.pen_down {
+            if self.current.is_empty() {
+                self.current.push(self.state.pos);
+            }
+            self.current.push(pos);
+        }
+        self.state.pos = pos;
+        self
+    }
+
+    /// Turns counter-clockwise by `degrees`.
+    pub fn left(&mut self, degrees: f32) -> &mut Self {
+        self.state.heading = (self.state.heading + degrees).rem_euclid(360.0);
+        self
+    }
+
+    /// Turns clockwise by `degrees`.
+    pub fn right(&mut self, degrees: f32) -> &mut Self {
+        self.left(-degrees)
+    }
+
+    /// Sets the heading to `degrees`.
+    pub fn set_heading(&mut self, degrees: f32) -> &mut Self {
+        self.state.heading = degrees.rem_euclid(360.0);
+        self
+    }
+
+    /// Lifts the pen, ending the current trail.
+    pub fn pen_up(&mut self) -> &mut Self {
+        self.flush();
+        self.state.pen_down = false;
+        self
+    }
+
+    /// Lowers the pen so movement draws again.
+    pub fn pen_down(&mut self) -> &mut Self {
+        self.state.pen_down = true;
+        self
+    }
+
+    /// Saves position, heading and pen state.
+    pub fn push(&mut self) -> &mut Self {
+        self.stack.push(self.state);
+        self
+    }
+
+    /// Restores the state saved by the matching [`push`](Self::push), jumping
+    /// back without drawing. Does nothing if the stack is empty.
+    pub fn pop(&mut self) -> &mut Self {
+        if let Some(state) = self.stack.pop() {
+            self.flush();
+            self.state = state;
+        }
+        self
+    }
+
+    /// Interprets an L-system string.
+    ///
+    /// - `F`, `G`: move forward `step`, drawing.
+    /// - `f`: move forward `step` without drawing.
+    /// - `+` / `-`: turn left / right by `angle` degrees.
+    /// - `[` / `]`: [`push`](Self::push) / [`pop`](Self::pop).
+    ///
+    /// Other characters are ignored.
+    pub fn run(&mut self, commands: &str, step: f32, angle: f32) -> &mut Self {
+        for c in commands.chars() {
+            match c {
+                'F' | 'G' => { self.forward(step); }
+                'f' => {
+                    let pen = self.state.pen_down;
+                    self.pen_up().forward(step);
+                    self.state.pen_down = pen;
+                }
+                '+' => { self.left(angle); }
+                '-' => { self.right(angle); }
+                '[' => { self.push(); }
+                ']' => { self.pop(); }
+                _ => {}
+            }
+        }
+        self
+    }
+
+    /// Returns the recorded trails, including the one in progress.
+    pub fn paths(&self) -> Vec<Path> {
+        let current = Some(&self.current).filter(|c| c.len() > 1);
+        self.trails
+            .iter()
+            .chain(current)
+            .map(|nodes| Path::new(nodes.clone(), false))
+            .collect()
+    }
+
+    /// Consumes `self` and returns the recorded trails.
+    pub fn into_paths(mut self) -> Vec<Path> {
+        self.flush();
+        self.trails.into_iter().map(|nodes| Path::new(nodes, false)).collect()
+    }
+
+    /// Renders every recorded trail onto `stage` with `style`.
+    pub fn render(&self, stage: &mut Stage, style: Style) {
+        for path in self.paths() {
+            path.render(stage, style);
+        }
+    }
+
+    /// Ends the trail in progress.
+    fn flush(&mut self) {
+        let nodes = std::mem::take(&mut self.current);
+        if nodes.len() > 1 {
+            self.trails.push(nodes);
+        }
+    }
+}
+
+/// Expands an L-system `axiom` by applying `rules` `iterations` times.
+///
+/// Arguments:
+/// - axiom: &[str] - starting string.
+/// - rules: &[([char], &[str])] - replacement for each symbol; others are kept.
+/// - iterations: [usize] - number of rewriting passes.
+pub fn lsystem(axiom: &str, rules: &[(char, &str)], iterations: usize) -> String {
+    let mut s = axiom.to_string();
+    for _ in 0..iterations {
+        let mut next = String::with_capacity(s.len() * 2);
+        for c in s.chars() {
+            match rules.iter().find(|(k, _)| *k == c) {
+                Some((_, r)) => next.push_str(r),
+                None => next.push(c),
+            }
+        }
+        s = next;
+    }
+    s
+}
@@ -0,0 +1,127 @@
+//! Drawing one [`Stage`] onto another through a [`Transform`].
+
+use crate::{Color, Stage, Transform};
+
+/// How a source image is sampled between pixel centers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Sampling {
+    /// Nearest source pixel. Crisp, suits pixel art and integer scales.
+    Nearest,
+    /// Bilinear interpolation of the four nearest pixels.
+    #[default]
+    Bilinear,
+}
+
+impl Stage {
+    /// Composites `src` onto `self` through `transform`, e.g. to place a rotated,
+    /// sheared, or perspective-projected image.
+    ///
+    /// `transform` maps `src` pixel coords to `self` pixel coords, both with `y`
+    /// pointing down and pixel `(x, y)` covering `[x, x + 1) x [y, y + 1)`.
+    /// Every destination pixel is mapped back through the inverse and sampled
+    /// with `sampling`; samples outside `src` are transparent, so the edges
+    /// fade out smoothly with [`Sampling::Bilinear`]. Clips and scissors apply.
+    ///
+    /// Arguments:
+    /// - src: &[`Stage`] - image to draw.
+    /// - transform: &[`Transform`] - source to destination pixel map.
+    /// - sampling: [`Sampling`] - reconstruction filter.
+    pub fn warp(&mut self, src: &Stage, transform: &Transform, sampling: Sampling) {
+        let Some(inverse) = transform.inverse() else { return; };
+
+        let (sw, sh) = (src.width() as f32, src.height() as f32);
+        let corners = [(0.0, 0.0), (sw, 0.0), (sw, sh), (0.0, sh)];
+        let r = self.draw_rect();
+
+        // destination bounding box; fall back to the whole draw rect if a
+        // corner projects to infinity
+        let (mut x0, mut y0, mut x1, mut y1) = (r.x, r.y, r.right(), r.bottom());
+        let mapped: Option<Vec<(f32, f32)>> = corners.iter().map(|&c| transform.apply(c)).collect();
+        if let Some(mapped) = mapped {
+            let clamp = |v: f32, lo: usize, hi: usize| (v.max(lo as f32).min(hi as f32)) as usize;
+            x0 = clamp(mapped.iter().map(|p| p.0).fold(f32::INFINITY, f32::min).floor(), r.x, r.right());
+            x1 = clamp(mapped.iter().map(|p| p.0).fold(f32::NEG_INFINITY, f32::max).ceil(), r.x, r.right());
+            y0 = clamp(mapped.iter().map(|p| p.1).fold(f32::INFINITY, f32::min).floor(), r.y, r.bottom());
+            y1 = clamp(mapped.iter().map(|p| p.1).fold(f32::NEG_INFINITY, f32::max).ceil(), r.y, r.bottom());
+        }
+
+        for y in y0..y1 {
+            for x in x0..x1 {
+                let Some((u, v)) = inverse.apply((x as f32 + 0.5, y as f32 + 0.5)) else { continue; };
+                let Some(px) = sample(src, u, v, sampling) else { continue; };
+                if px[3] == 0 { continue; }
+                self.blend_pxl(x as isize, y as isize, Color::new(px), 1.0);
+            }
+        }
+    }
+}
+
+impl Stage {
+    /// Draws `src` stretched onto the quadrilateral `quad` with a perspective
+    /// mapping, e.g. for pseudo-3D cards, floor textures, or screen mockups.
+    ///
+    /// `quad` holds the world coords that the top-left, top-right,
+    /// bottom-right and bottom-left corners of `src` land on, in that order.
+    /// Sampling is bilinear; use [`Stage::warp`] with
+    /// [`Transform::quad_to_quad`] for other filters. Degenerate quads draw
+    /// nothing.
+    ///
+    /// Arguments:
+    /// - src: &[`Stage`] - image to draw.
+    /// - quad: [([f32], [f32]); 4] - destination corners in world coords.
+    pub fn draw_image_quad(&mut self, src: &Stage, quad: [(f32, f32); 4]) {
+        let (sw, sh) = (src.width() as f32, src.height() as f32);
+        let from = [(0.0, 0.0), (sw, 0.0), (sw, sh), (0.0, sh)];
+
+        // world coords put pixel centers on integers, warp on half-integers
+        let to = quad.map(|p| {
+            let (px, py) = self.world_to_pxl_f32(p);
+            (px + 0.5, py + 0.5)
+        });
+
+        let Some(transform) = Transform::quad_to_quad(from, to) else { return; };
+        self.warp(src, &transform, Sampling::Bilinear);
+    }
+}
+
+/// Samples `src` at continuous pixel coord `(u, v)`.
+#[inline(always)]
+fn sample(src: &Stage, u: f32, v: f32, sampling: Sampling) -> Option<[u8; 4]> {
+    let (w, h) = (src.width() as isize, src.height() as isize);
+    let texel = |x: isize, y: isize| -> [f32; 4] {
+        if x < 0 || y < 0 || x >= w || y >= h { return [0.0; 4]; }
+        let p = src.row(y as usize)[x as usize];
+        let a = p[3] as f32 / 255.0;
+        [p[0] as f32 * a, p[1] as f32 * a, p[2] as f32 * a, p[3] as f32]
+    };
+
+    if !u.is_finite() || !v.is_finite() { return None; }
+
+    let premul = match sampling {
+        Sampling::Nearest => texel(u.floor() as isize, v.floor() as isize),
+        Sampling::Bilinear => {
+            let (fu, fv) = (u - 0.5, v - 0.5);
+            let (x, y) = (fu.floor(), fv.floor());
+            let (tx, ty) = (fu - x, fv - y);
+            let (x, y) = (x as isize, y as isize);
+            if x < -1 || y < -1 || x >= w || y >= h { return None; }
+
+            let (a, b, c, d) = (texel(x, y), texel(x + 1, y), texel(x, y + 1), texel(x + 1, y + 1));
+            std::array::from_fn(|i| {
+                let top = a[i] + (b[i] - a[i]) * tx;
+                let bot = c[i] + (d[i] - c[i]) * tx;
+                top + (bot - top) * ty
+            })
+        }
+    };
+
+    let alpha = premul[3];
+    if alpha <= 0.0 { return Some([0; 4]); }
+    let k = 255.0 / alpha;
+    Some([
+        (premul[0] * k).round().clamp(0.0, 255.0) as u8,
+        (premul[1] * k).round().clamp(0.0, 255.0) as u8,
+        (premul[2] * k).round().clamp(0.0, 255.0) as u8,
+        alpha.round().clamp(0.0, 255.0) as u8,
+    ])
+}
@@ -0,0 +1,663 @@
+//! Wireframe rendering of 3D line lists and flat shaded triangles.
+//!
+//! Points are right handed `(x, y, z)` with `+y` up. A [`Camera`] maps them
+//! into the drawable area of a [`Stage`], segments and triangles are clipped
+//! against the view volume and what is left is drawn with [`shapes::line`]
+//! and the scanline triangle fill. Triangles are depth sorted and painted
+//! back to front, so simple scenes need no depth buffer.
+//!
+//! ```ignore
+//! let camera = Camera::new((3.0, 2.0, 4.0), (0.0, 0.0, 0.0)).perspective(45.0);
+//! let spin = Mat4::rotate_y(30.0);
+//! let edges: Vec<_> = wire3d::cube((0.0, 0.0, 0.0), 2.0)
+//!     .into_iter()
+//!     .filter_map(|(a, b)| Some((spin.apply(a)?, spin.apply(b)?)))
+//!     .collect();
+//! wire3d::render(&mut stage, &camera, &edges, Style::stroke_only(Color::BLACK));
+//! ```
+
+use std::collections::HashSet;
+
+use crate::{shapes, Color, Path, Stage, Style};
+use crate::primitives::triangle::triangle_spans;
+
+/// A 3D point `(x, y, z)`.
+type Point3 = (f32, f32, f32);
+
+/// A 3D segment between two points.
+type Line3 = (Point3, Point3);
+
+/// A 3D triangle.
+type Triangle3 = [Point3; 3];
+
+/// A 4x4 projective transform acting on points `(x, y, z)` as
+/// `M * [x, y, z, 1]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mat4 {
+    m: [[f32; 4]; 4],
+}
+
+impl Default for Mat4 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Mat4 {
+    /// Leaves every point in place.
+    pub const IDENTITY: Mat4 = Mat4 {
+        m: [[1.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 1.0, 0.0], [0.0, 0.0, 0.0, 1.0]],
+    };
+
+    /// Creates a [`Mat4`] from a row major 4x4 matrix.
+    pub fn from_matrix(m: [[f32; 4]; 4]) -> Self {
+        Self { m }
+    }
+
+    /// Translates by `(tx, ty, tz)`.
+    pub fn translate(tx: f32, ty: f32, tz: f32) -> Self {
+        let mut m = Self::IDENTITY.m;
+        m[0][3] = tx;
+        m[1][3] = ty;
+        m[2][3] = tz;
+        Self { m }
+    }
+
+    /// Scales by `(sx, sy, sz)` about the origin.
+    pub fn scale(sx: f32, sy: f32, sz: f32) -> Self {
+        let mut m = Self::IDENTITY.m;
+        m[0][0] = sx;
+        m[1][1] = sy;
+        m[2][2] = sz;
+        Self { m }
+    }
+
+    /// Rotates by `degrees` about the `x` axis, counter-clockwise looking
+    /// down `+x` toward the origin.
+    pub fn rotate_x(degrees: f32) -> Self {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        let mut m = Self::IDENTITY.m;
+        m[1][1] = cos;
+        m[1][2] = -sin;
+        m[2][1] = sin;
+        m[2][2] = cos;
+        Self { m }
+    }
+
+    /// Rotates by `degrees` about the `y` axis, counter-clockwise looking
+    /// down `+y` toward the origin.
+    pub fn rotate_y(degrees: f32) -> Self {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        let mut m = Self::IDENTITY.m;
+        m[0][0] = cos;
+        m[0][2] = sin;
+        m[2][0] = -sin;
+        m[2][2] = cos;
+        Self { m }
+    }
+
+    /// Rotates by `degrees` about the `z` axis, counter-clockwise looking
+    /// down `+z` toward the origin.
+    pub fn rotate_z(degrees: f32) -> Self {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        let mut m = Self::IDENTITY.m;
+        m[0][0] = cos;
+        m[0][1] = -sin;
+        m[1][0] = sin;
+        m[1][1] = cos;
+        Self { m }
+    }
+
+    /// Returns the view transform of an eye at `eye` looking at `target`,
+    /// with `up` pointing up on screen. The eye ends up at the origin
+    /// looking down `-z`.
+    ///
+    /// Returns `None` if `eye == target` or `up` is parallel to the view
+    /// direction.
+    pub fn look_at(eye: Point3, target: Point3, up: Point3) -> Option<Self> {
+        let f = normalize(sub(target, eye))?;
+        let s = normalize(cross(f, up))?;
+        let u = cross(s, f);
+
+        Some(Self {
+            m: [
+                [s.0, s.1, s.2, -dot(s, eye)],
+                [u.0, u.1, u.2, -dot(u, eye)],
+                [-f.0, -f.1, -f.2, dot(f, eye)],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        })
+    }
+
+    /// Returns the perspective projection with a vertical field of view of
+    /// `fov` degrees, mapping the frustum between the `near` and `far`
+    /// planes in front of the eye onto the clip volume.
+    pub fn perspective(fov: f32, aspect: f32, near: f32, far: f32) -> Self {
+        let f = 1.0 / (0.5 * fov.to_radians()).tan();
+        Self {
+            m: [
+                [f / aspect, 0.0, 0.0, 0.0],
+                [0.0, f, 0.0, 0.0],
+                [0.0, 0.0, (far + near) / (near - far), 2.0 * far * near / (near - far)],
+                [0.0, 0.0, -1.0, 0.0],
+            ],
+        }
+    }
+
+    /// Returns the orthographic projection showing `height` world units
+    /// vertically, mapping the box between the `near` and `far` planes in
+    /// front of the eye onto the clip volume.
+    pub fn orthographic(height: f32, aspect: f32, near: f32, far: f32) -> Self {
+        let h = 0.5 * height;
+        Self {
+            m: [
+                [1.0 / (h * aspect), 0.0, 0.0, 0.0],
+                [0.0, 1.0 / h, 0.0, 0.0],
+                [0.0, 0.0, -2.0 / (far - near), -(far + near) / (far - near)],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    /// Returns the matrix of `self`, row major.
+    pub fn matrix(&self) -> [[f32; 4]; 4] {
+        self.m
+    }
+
+    /// Returns the transform applying `self` first and then `next`.
+    pub fn then(&self, next: &Mat4) -> Mat4 {
+        let (a, b) = (&next.m, &self.m);
+        let m = std::array::from_fn(|r| std::array::from_fn(|c| (0..4).map(|k| a[r][k] * b[k][c]).sum()));
+        Mat4 { m }
+    }
+
+    /// Maps `(x, y, z)` through `self`. Returns `None` for points sent to
+    /// infinity or behind the projection.
+    pub fn apply(&self, p: Point3) -> Option<Point3> {
+        let [x, y, z, w] = self.homogeneous(p);
+        if w <= 1e-12 || !w.is_finite() { return None; }
+
+        Some((x / w, y / w, z / w))
+    }
+
+    /// Maps `(x, y, z)` through `self` without the perspective divide.
+    fn homogeneous(&self, (x, y, z): Point3) -> [f32; 4] {
+        self.m.map(|row| row[0] * x + row[1] * y + row[2] * z + row[3])
+    }
+}
+
+/// Projection of a [`Camera`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Projection {
+    /// Vertical field of view in degrees.
+    Perspective(f32),
+    /// Visible height in world units.
+    Orthographic(f32),
+}
+
+/// Eye position, orientation and projection for [`render`].
+///
+/// The aspect ratio is taken from the drawable area at render time, so the
+/// same [`Camera`] works on any [`Stage`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Camera {
+    eye: Point3,
+    target: Point3,
+    up: Point3,
+    projection: Projection,
+    near: f32,
+    far: f32,
+}
+
+impl Camera {
+    /// Creates a [`Camera`] at `eye` looking at `target` with `+y` up, a 60
+    /// degree perspective and clip planes at `0.1` and `1000`.
+    pub fn new(eye: Point3, target: Point3) -> Self {
+        Self {
+            eye,
+            target,
+            up: (0.0, 1.0, 0.0),
+            projection: Projection::Perspective(60.0),
+            near: 0.1,
+            far: 1000.0,
+        }
+    }
+
+    /// Sets the direction pointing up on screen.
+    pub fn up(mut self, up: Point3) -> Self {
+        self.up = up;
+        self
+    }
+
+    /// Uses a perspective projection with a vertical field of view of `fov`
+    /// degrees.
+    pub fn perspective(mut self, fov: f32) -> Self {
+        self.projection = Projection::Perspective(fov);
+        self
+    }
+
+    /// Uses an orthographic projection showing `height` world units
+    /// vertically.
+    pub fn orthographic(mut self, height: f32) -> Self {
+        self.projection = Projection::Orthographic(height);
+        self
+    }
+
+    /// Sets the distances of the near and far clip planes from the eye.
+    pub fn clip(mut self, near: f32, far: f32) -> Self {
+        self.near = near;
+        self.far = far;
+        self
+    }
+
+    /// Returns the eye position.
+    pub fn eye(&self) -> Point3 {
+        self.eye
+    }
+
+    /// Returns the point looked at.
+    pub fn target(&self) -> Point3 {
+        self.target
+    }
+
+    /// Returns the transform from world space to clip space for a viewport
+    /// `aspect = width / height`, or `None` if the view is degenerate.
+    pub fn matrix(&self, aspect: f32) -> Option<Mat4> {
+        Some(self.view()?.then(&self.projection(aspect)))
+    }
+
+    /// Returns the transform from world space to eye space, or `None` if the
+    /// view is degenerate.
+    fn view(&self) -> Option<Mat4> {
+        Mat4::look_at(self.eye, self.target, self.up)
+    }
+
+    /// Returns the transform from eye space to clip space.
+    fn projection(&self, aspect: f32) -> Mat4 {
+        match self.projection {
+            Projection::Perspective(fov) => Mat4::perspective(fov, aspect, self.near, self.far),
+            Projection::Orthographic(height) => Mat4::orthographic(height, aspect, self.near, self.far),
+        }
+    }
+}
+
+/// Options for [`render_triangles`].
+#[derive(Debug, Clone, Copy)]
+pub struct ShadeOptions {
+    /// Direction toward the light in world space, or `None` to draw every
+    /// triangle in its own color.
+    pub light: Option<(f32, f32, f32)>,
+    /// Fraction of the color kept by faces turned away from the light, in
+    /// `[0.0, 1.0]`.
+    pub ambient: f32,
+    /// Color of a 1 pixel outline drawn around each triangle right after its
+    /// fill, so nearer faces hide the edges behind them.
+    pub outline: Option<Color>,
+}
+
+impl Default for ShadeOptions {
+    fn default() -> Self {
+        Self { light: Some((-0.4, 1.0, 0.6)), ambient: 0.35, outline: None }
+    }
+}
+
+/// Projects `point` into world coords of `stage` as seen by `camera`.
+///
+/// Returns `None` if the point is outside the view volume.
+///
+/// Arguments:
+/// - stage: &[`Stage`] - stage whose drawable area is the viewport.
+/// - camera: &[`Camera`] - eye and projection.
+/// - point: ([f32], [f32], [f32]) - 3D point.
+pub fn project(stage: &Stage, camera: &Camera, point: Point3) -> Option<(f32, f32)> {
+    let viewport = Viewport::new(stage, camera)?;
+    let c = viewport.m.homogeneous(point);
+    if planes(c).iter().any(|&d| d < 0.0) || c[3] <= 0.0 { return None; }
+
+    Some(viewport.to_world(stage, c))
+}
+
+/// Draws the 3D segments `lines` on `stage` as seen by `camera`.
+///
+/// Each segment is clipped against the near, far and side planes of the
+/// view volume, so geometry behind the eye or off screen is dropped
+/// rather than wrapped around. Only the stroke of `style` is used.
+///
+/// Arguments:
+/// - stage: &mut [`Stage`] - stage to draw onto.
+/// - camera: &[`Camera`] - eye and projection.
+/// - lines: &[(([f32], [f32], [f32]), ([f32], [f32], [f32]))] - 3D segments.
+/// - style: [`Style`] - struct containing style args.
+pub fn render(stage: &mut Stage, camera: &Camera, lines: &[Line3], style: Style) {
+    if style.stroke.is_none() { return; }
+    let Some(viewport) = Viewport::new(stage, camera) else { return; };
+    let style = Style { fill: None, ..style };
+
+    for &(a, b) in lines {
+        let Some((ca, cb)) = clip_segment(viewport.m.homogeneous(a), viewport.m.homogeneous(b)) else {
+            continue;
+        };
+        let (pa, pb) = (viewport.to_world(stage, ca), viewport.to_world(stage, cb));
+        shapes::line(stage, pa, pb, style);
+    }
+}
+
+/// Fills the 3D `triangles` on `stage` as seen by `camera`, farthest first.
+///
+/// Each triangle is flat shaded by the angle between its normal, turned to
+/// face the eye, and `options.light`. Triangles are sorted by the mean eye
+/// space depth of their vertices, which is exact for meshes without
+/// intersecting or cyclically overlapping faces. Parts outside the view
+/// volume are clipped away.
+///
+/// Arguments:
+/// - stage: &mut [`Stage`] - stage to draw onto.
+/// - camera: &[`Camera`] - eye and projection.
+/// - triangles: &[([[([f32], [f32], [f32])]; 3], [`Color`])] - vertices and base color of each triangle.
+/// - options: [`ShadeOptions`] - light, ambient level and outline.
+pub fn render_triangles(
+    stage: &mut Stage,
+    camera: &Camera,
+    triangles: &[(Triangle3, Color)],
+    options: ShadeOptions,
+) {
+    let Some(viewport) = Viewport::new(stage, camera) else { return; };
+    let Some(view) = camera.view() else { return; };
+    let light = options.light.and_then(normalize);
+    let ambient = options.ambient.clamp(0.0, 1.0);
+
+    // eye space looks down -z, so larger -z is farther away
+    let mut order: Vec<(f32, usize)> = triangles
+        .iter()
+        .enumerate()
+        .filter_map(|(i, (tri, _))| {
+            let depth = tri.iter().map(|&p| view.homogeneous(p)[2]).sum::<f32>() / -3.0;
+            depth.is_finite().then_some((depth, i))
+        })
+        .collect();
+    order.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+    let mut polygon = Vec::with_capacity(9);
+    for (_, i) in order {
+        let (tri, color) = triangles[i];
+        if !clip_triangle(tri.map(|p| viewport.m.homogeneous(p)), &mut polygon) { continue; }
+
+        let nodes_px: Vec<(isize, isize)> = polygon
+            .iter()
+            .map(|&c| {
+                let (px, py) = viewport.to_pxl(c);
+                (px.round() as isize, py.round() as isize)
+            })
+            .collect();
+
+        let shade = light.map_or(1.0, |l| {
+            let Some(n) = normalize(cross(sub(tri[1], tri[0]), sub(tri[2], tri[0]))) else { return ambient; };
+            let centroid = tri.iter().fold((0.0, 0.0, 0.0), |acc, &p| (acc.0 + p.0 / 3.0, acc.1 + p.1 / 3.0, acc.2 + p.2 / 3.0));
+            let facing = if dot(n, sub(camera.eye, centroid)) < 0.0 { -1.0 } else { 1.0 };
+            ambient + (1.0 - ambient) * (facing * dot(n, l)).max(0.0)
+        });
+        let [r, g, b, a] = color.rgba();
+        let scale = |c: u8| (c as f32 * shade).round() as u8;
+        let fill = Color::new([scale(r), scale(g), scale(b), a]);
+
+        // convex fan, whose shared edges tile without gaps
+        for k in 1..nodes_px.len() - 1 {
+            triangle_spans(nodes_px[0], nodes_px[k], nodes_px[k + 1], |y, x0, x1| {
+                stage.fill_span_pxl(y, x0, x1, fill);
+            });
+        }
+
+        if let Some(outline) = options.outline {
+            Path::render_pxl(&nodes_px, true, stage, Style::stroke_only(outline));
+        }
+    }
+}
+
+/// Returns the two triangles of each cell of the surface `y = f(x, z)`
+/// sampled on a `cols` x `rows` grid spanning `x_range` and `z_range`, for
+/// [`render_triangles`].
+///
+/// Cells touching a non-finite sample are skipped, leaving holes.
+///
+/// Arguments:
+/// - f: `Fn(f32, f32) -> f32` - height at `(x, z)`.
+/// - x_range: ([f32], [f32]) - `x` extent.
+/// - z_range: ([f32], [f32]) - `z` extent.
+/// - dims: ([usize], [usize]) - samples `(cols, rows)` along `x` and `z`.
+pub fn surface_triangles<F: Fn(f32, f32) -> f32>(
+    f: F,
+    x_range: (f32, f32),
+    z_range: (f32, f32),
+    dims: (usize, usize),
+) -> Vec<Triangle3> {
+    let (cols, rows) = dims;
+    let samples = sample_surface(f, x_range, z_range, dims);
+
+    let mut triangles = Vec::new();
+    for r in 0..rows.saturating_sub(1) {
+        for c in 0..cols.saturating_sub(1) {
+            let at = |r: usize, c: usize| samples[r * cols + c];
+            let (Some(p00), Some(p01), Some(p10), Some(p11)) = (at(r, c), at(r, c + 1), at(r + 1, c), at(r + 1, c + 1)) else {
+                continue;
+            };
+            triangles.push([p00, p10, p01]);
+            triangles.push([p01, p10, p11]);
+        }
+    }
+    triangles
+}
+
+/// Returns the 12 edges of the axis aligned cube of side `size` centered
+/// at `center`.
+pub fn cube(center: Point3, size: f32) -> Vec<Line3> {
+    let h = 0.5 * size;
+    let corner = |i: usize| {
+        let sign = |bit: usize| if i & bit != 0 { h } else { -h };
+        (center.0 + sign(1), center.1 + sign(2), center.2 + sign(4))
+    };
+
+    // corners differing in exactly one bit share an edge
+    let mut edges = Vec::with_capacity(12);
+    for i in 0..8 {
+        for bit in [1, 2, 4] {
+            if i & bit == 0 {
+                edges.push((corner(i), corner(i | bit)));
+            }
+        }
+    }
+    edges
+}
+
+/// Returns the edges of the triangle mesh over `vertices`, each edge shared
+/// by several triangles listed once. Triangles with an out of range index
+/// are skipped.
+///
+/// Arguments:
+/// - vertices: &[([f32], [f32], [f32])] - 3D vertex positions.
+/// - triangles: &[[[usize]; 3]] - vertex indices of each triangle.
+pub fn mesh(vertices: &[Point3], triangles: &[[usize; 3]]) -> Vec<Line3> {
+    let mut seen = HashSet::new();
+    let mut edges = Vec::new();
+    for tri in triangles {
+        if tri.iter().any(|&i| i >= vertices.len()) { continue; }
+        for k in 0..3 {
+            let (i, j) = (tri[k], tri[(k + 1) % 3]);
+            if seen.insert((i.min(j), i.max(j))) {
+                edges.push((vertices[i], vertices[j]));
+            }
+        }
+    }
+    edges
+}
+
+/// Returns the grid lines of the surface `y = f(x, z)` sampled on a
+/// `cols` x `rows` grid spanning `x_range` and `z_range`.
+///
+/// Segments touching a non-finite sample are skipped, leaving holes.
+///
+/// Arguments:
+/// - f: `Fn(f32, f32) -> f32` - height at `(x, z)`.
+/// - x_range: ([f32], [f32]) - `x` extent.
+/// - z_range: ([f32], [f32]) - `z` extent.
+/// - dims: ([usize], [usize]) - samples `(cols, rows)` along `x` and `z`.
+pub fn surface<F: Fn(f32, f32) -> f32>(
+    f: F,
+    x_range: (f32, f32),
+    z_range: (f32, f32),
+    dims: (usize, usize),
+) -> Vec<Line3> {
+    let (cols, rows) = dims;
+    let samples = sample_surface(f, x_range, z_range, dims);
+
+    let mut lines = Vec::new();
+    for r in 0..rows {
+        for c in 0..cols {
+            let Some(p) = samples[r * cols + c] else { continue; };
+            if c + 1 < cols && let Some(q) = samples[r * cols + c + 1] {
+                lines.push((p, q));
+            }
+            if r + 1 < rows && let Some(q) = samples[(r + 1) * cols + c] {
+                lines.push((p, q));
+            }
+        }
+    }
+    lines
+}
+
+/// Samples `y = f(x, z)` on a `cols` x `rows` grid spanning `x_range` and
+/// `z_range`, row major along `x`. Non-finite heights are `None`.
+fn sample_surface<F: Fn(f32, f32) -> f32>(
+    f: F,
+    x_range: (f32, f32),
+    z_range: (f32, f32),
+    (cols, rows): (usize, usize),
+) -> Vec<Option<Point3>> {
+    let at = |range: (f32, f32), i: usize, n: usize| {
+        if n > 1 { range.0 + (range.1 - range.0) * i as f32 / (n - 1) as f32 } else { range.0 }
+    };
+
+    let mut samples = Vec::with_capacity(cols * rows);
+    for r in 0..rows {
+        let z = at(z_range, r, rows);
+        for c in 0..cols {
+            let x = at(x_range, c, cols);
+            let y = f(x, z);
+            samples.push(y.is_finite().then_some((x, y, z)));
+        }
+    }
+    samples
+}
+
+/// Clip space transform of a [`Camera`] fitted to the drawable area of a
+/// [`Stage`].
+struct Viewport {
+    m: Mat4,
+    /// Drawable area as `(x, y, width, height)` in fractional pixels.
+    rect: (f32, f32, f32, f32),
+}
+
+impl Viewport {
+    fn new(stage: &Stage, camera: &Camera) -> Option<Self> {
+        let r = stage.draw_rect();
+        if r.is_empty() { return None; }
+
+        let rect = (r.x as f32, r.y as f32, r.width as f32, r.height as f32);
+        let m = camera.matrix(rect.2 / rect.3)?;
+        Some(Self { m, rect })
+    }
+
+    /// Maps the clip space point `c`, inside the view volume, to world coords.
+    fn to_world(&self, stage: &Stage, c: [f32; 4]) -> (f32, f32) {
+        stage.pxl_to_world_f32(self.to_pxl(c))
+    }
+
+    /// Maps the clip space point `c`, inside the view volume, to fractional
+    /// pixel coords.
+    fn to_pxl(&self, c: [f32; 4]) -> (f32, f32) {
+        let (x, y) = (c[0] / c[3], c[1] / c[3]);
+        let (rx, ry, w, h) = self.rect;
+
+        // normalized coords span pixel edges, pixel centers sit at +0.5
+        (rx - 0.5 + 0.5 * (x + 1.0) * w, ry - 0.5 + 0.5 * (1.0 - y) * h)
+    }
+}
+
+/// Returns the signed distances of the clip space point `c` to the six
+/// planes of the view volume, non-negative inside.
+fn planes([x, y, z, w]: [f32; 4]) -> [f32; 6] {
+    [w + x, w - x, w + y, w - y, w + z, w - z]
+}
+
+/// Clips the clip space segment `a`-`b` to the view volume, returning the
+/// part inside or `None` if there is none.
+fn clip_segment(a: [f32; 4], b: [f32; 4]) -> Option<([f32; 4], [f32; 4])> {
+    let (da, db) = (planes(a), planes(b));
+    let (mut t0, mut t1) = (0.0f32, 1.0f32);
+
+    for (d0, d1) in da.into_iter().zip(db) {
+        if !d0.is_finite() || !d1.is_finite() { return None; }
+        if d0 < 0.0 && d1 < 0.0 { return None; }
+        if d0 < 0.0 {
+            t0 = t0.max(d0 / (d0 - d1));
+        } else if d1 < 0.0 {
+            t1 = t1.min(d0 / (d0 - d1));
+        }
+    }
+    if t0 > t1 { return None; }
+
+    let lerp = |t: f32| std::array::from_fn(|i| a[i] + (b[i] - a[i]) * t);
+    let (ca, cb): ([f32; 4], [f32; 4]) = (lerp(t0), lerp(t1));
+    if ca[3] <= 0.0 || cb[3] <= 0.0 { return None; }
+
+    Some((ca, cb))
+}
+
+/// Clips the clip space triangle `tri` to the view volume, writing the
+/// convex polygon left into `out`. Returns `false` if less than a triangle
+/// remains.
+fn clip_triangle(tri: [[f32; 4]; 3], out: &mut Vec<[f32; 4]>) -> bool {
+    out.clear();
+    out.extend(tri);
+    if tri.iter().flatten().any(|v| !v.is_finite()) { return false; }
+
+    let mut input = Vec::with_capacity(9);
+    for plane in 0..6 {
+        std::mem::swap(out, &mut input);
+        out.clear();
+
+        let n = input.len();
+        for i in 0..n {
+            let (a, b) = (input[i], input[(i + 1) % n]);
+            let (da, db) = (planes(a)[plane], planes(b)[plane]);
+            if da >= 0.0 {
+                out.push(a);
+            }
+            if (da >= 0.0) != (db >= 0.0) {
+                let t = da / (da - db);
+                out.push(std::array::from_fn(|k| a[k] + (b[k] - a[k]) * t));
+            }
+        }
+        if out.len() < 3 { return false; }
+    }
+    out.iter().all(|c| c[3] > 0.0)
+}
+
+fn sub(a: Point3, b: Point3) -> Point3 {
+    (a.0 - b.0, a.1 - b.1, a.2 - b.2)
+}
+
+fn dot(a: Point3, b: Point3) -> f32 {
+    a.0 * b.0 + a.1 * b.1 + a.2 * b.2
+}
+
+fn cross(a: Point3, b: Point3) -> Point3 {
+    (a.1 * b.2 - a.2 * b.1, a.2 * b.0 - a.0 * b.2, a.0 * b.1 - a.1 * b.0)
+}
+
+/// Returns `v` scaled to unit length, or `None` if it is zero or not finite.
+fn normalize(v: Point3) -> Option<Point3> {
+    let len = dot(v, v).sqrt();
+    if !len.is_finite() || len < 1e-12 { return None; }
+
+    Some((v.0 / len, v.1 / len, v.2 / len))
+}